@@ -3,13 +3,15 @@
 //! Functions to convert Rust objects into Arrow arrays and back.
 //!
 #![deny(missing_docs)]
+use std::ops::Range;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
     _impl::arrow2::{array::Array, datatypes::Field as ArrowField},
     internal::{
         array_builder::ArrayBuilder,
-        arrow::Field,
+        arrow::{ArrayView, Field},
         deserializer::Deserializer,
         error::{fail, Result},
         schema::SerdeArrowSchema,
@@ -145,25 +147,77 @@ impl<'de> Deserializer<'de> {
     where
         A: AsRef<dyn Array>,
     {
-        use crate::internal::arrow::ArrayView;
-
-        if fields.len() != arrays.len() {
-            fail!(
-                "different number of fields ({}) and arrays ({})",
-                fields.len(),
-                arrays.len()
-            );
-        }
+        let (fields, views) = fields_and_views_from_arrow2(fields, arrays)?;
+        Deserializer::new(&fields, views)
+    }
 
-        let fields = fields
-            .iter()
-            .map(Field::try_from)
-            .collect::<Result<Vec<_>>>()?;
-        let views = arrays
-            .iter()
-            .map(|array| ArrayView::try_from(array.as_ref()))
-            .collect::<Result<Vec<_>>>()?;
+    /// Build a deserializer for the rows in `range` of `arrow2` arrays
+    /// (*requires one of the `arrow2-*` features*)
+    ///
+    /// The deserializer starts directly at `range.start` without processing
+    /// the previous rows, i.e., list offsets, union offsets and dictionary
+    /// indices are positioned up front.
+    ///
+    /// Usage:
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow2;
+    /// # let (_, arrays) = serde_arrow::_impl::docs::defs::example_arrow2_arrays();
+    /// use arrow2::datatypes::Field;
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_arrow::{Deserializer, schema::{SchemaLike, TracingOptions}};
+    ///
+    /// ##[derive(Deserialize, Serialize)]
+    /// struct Record {
+    ///     a: Option<f32>,
+    ///     b: u64,
+    /// }
+    ///
+    /// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+    ///
+    /// let deserializer = Deserializer::from_arrow2_range(&fields, &arrays, 0..1)?;
+    /// let items = Vec::<Record>::deserialize(deserializer)?;
+    /// assert_eq!(items.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_arrow2_range<A>(
+        fields: &[ArrowField],
+        arrays: &'de [A],
+        range: Range<usize>,
+    ) -> Result<Self>
+    where
+        A: AsRef<dyn Array>,
+    {
+        let (fields, views) = fields_and_views_from_arrow2(fields, arrays)?;
+        Deserializer::new_range(&fields, views, range)
+    }
+}
 
-        Deserializer::new(&fields, views)
+fn fields_and_views_from_arrow2<'de, A>(
+    fields: &[ArrowField],
+    arrays: &'de [A],
+) -> Result<(Vec<Field>, Vec<ArrayView<'de>>)>
+where
+    A: AsRef<dyn Array>,
+{
+    if fields.len() != arrays.len() {
+        fail!(
+            "different number of fields ({}) and arrays ({})",
+            fields.len(),
+            arrays.len()
+        );
     }
+
+    let fields = fields
+        .iter()
+        .map(Field::try_from)
+        .collect::<Result<Vec<_>>>()?;
+    let views = arrays
+        .iter()
+        .map(|array| ArrayView::try_from(array.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((fields, views))
 }
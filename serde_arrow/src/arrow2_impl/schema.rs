@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use crate::{
     _impl::arrow2::datatypes::{
         DataType as ArrowDataType, Field as ArrowField, IntegerType, TimeUnit as ArrowTimeUnit,
@@ -34,6 +36,7 @@ impl<'a> TryFrom<&'a [ArrowField]> for SerdeArrowSchema {
     fn try_from(fields: &'a [ArrowField]) -> std::prelude::v1::Result<Self, Self::Error> {
         Ok(Self {
             fields: fields.iter().map(Field::try_from).collect::<Result<_>>()?,
+            metadata: HashMap::new(),
         })
     }
 }
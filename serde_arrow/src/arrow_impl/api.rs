@@ -1,7 +1,7 @@
 #![deny(missing_docs)]
-use std::sync::Arc;
+use std::{collections::HashMap, ops::Range, sync::Arc};
 
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::{
     _impl::arrow::{
@@ -10,9 +10,10 @@ use crate::{
     },
     internal::{
         array_builder::ArrayBuilder,
-        deserializer::Deserializer,
-        error::{fail, Result},
-        schema::SerdeArrowSchema,
+        arrow::{ArrayView, Field},
+        deserializer::{deserialize_row, Deserializer},
+        error::{fail, Error, Result},
+        schema::{FieldNames, SerdeArrowSchema},
         serializer::Serializer,
     },
 };
@@ -110,6 +111,10 @@ where
 ///
 /// To build arrays record by record use [`ArrayBuilder`].
 ///
+/// The record batch carries the metadata of the individual fields, but no
+/// schema-level metadata. To keep schema-level metadata use
+/// [`to_record_batch_with_schema`].
+///
 /// Example:
 ///
 /// ```rust
@@ -146,6 +151,52 @@ pub fn to_record_batch<T: Serialize>(fields: &[FieldRef], items: &T) -> Result<R
         .to_record_batch()
 }
 
+/// Build a record batch with the given schema from the given items (*requires
+/// one of the `arrow-*` features*)
+///
+/// In contrast to [`to_record_batch`], the schema-level metadata is included in
+/// the schema of the record batch.
+///
+/// Example:
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Schema;
+/// use serde::{Serialize, Deserialize};
+/// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema, TracingOptions};
+///
+/// ##[derive(Serialize, Deserialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let items = vec![
+///     Record { a: Some(1.0), b: 2},
+///     // ...
+/// ];
+///
+/// let options = TracingOptions::default().schema_metadata("version", "1");
+/// let schema = SerdeArrowSchema::from_type::<Record>(options)?;
+/// let schema = Schema::try_from(&schema)?;
+/// let record_batch = serde_arrow::to_record_batch_with_schema(&schema, &items)?;
+///
+/// assert_eq!(record_batch.schema().metadata()["version"], "1");
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_record_batch_with_schema<T: Serialize>(
+    schema: &Schema,
+    items: &T,
+) -> Result<RecordBatch> {
+    let builder = ArrayBuilder::from_arrow_schema(schema)?;
+    items
+        .serialize(Serializer::new(builder))?
+        .into_inner()
+        .to_record_batch()
+}
+
 /// Deserialize items from a record batch (*requires one of the `arrow-*`
 /// features*)
 ///
@@ -174,13 +225,166 @@ pub fn from_record_batch<'de, T: Deserialize<'de>>(record_batch: &'de RecordBatc
     T::deserialize(Deserializer::from_record_batch(record_batch)?)
 }
 
+/// Deserialize items from multiple record batches as a single sequence
+/// (*requires one of the `arrow-*` features*)
+///
+/// All record batches must share the schema of the first batch. See
+/// [`iter_from_record_batches`] to deserialize the items lazily from an
+/// iterator of record batches, e.g., as returned by Parquet or IPC readers.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # let record_batch = serde_arrow::_impl::docs::defs::example_record_batch();
+/// #
+/// use serde::Deserialize;
+///
+/// ##[derive(Deserialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let batches = vec![record_batch.clone(), record_batch];
+/// let items: Vec<Record> = serde_arrow::from_record_batches(&batches)?;
+/// assert_eq!(items.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_record_batches<'de, T, I>(record_batches: I) -> Result<T>
+where
+    T: Deserialize<'de>,
+    I: IntoIterator<Item = &'de RecordBatch>,
+{
+    T::deserialize(Deserializer::from_record_batches(record_batches)?)
+}
+
+/// Lazily deserialize items from an iterator of record batches (*requires one
+/// of the `arrow-*` features*)
+///
+/// The batches are only pulled from the iterator once the items of the
+/// previous batch have been consumed. Items are deserialized one row at a
+/// time, so at most a single item is held in memory independent of the batch
+/// size. All record batches must share the schema of the first batch. After
+/// the first error no further items are returned.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # let record_batch = serde_arrow::_impl::docs::defs::example_record_batch();
+/// #
+/// use serde::Deserialize;
+///
+/// ##[derive(Deserialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// // e.g., a parquet::arrow::arrow_reader::ParquetRecordBatchReader
+/// let reader = vec![Ok(record_batch.clone()), Ok(record_batch)];
+///
+/// for item in serde_arrow::iter_from_record_batches::<Record, _, serde_arrow::Error>(reader) {
+///     let item = item?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn iter_from_record_batches<T, I, E>(record_batches: I) -> impl Iterator<Item = Result<T>>
+where
+    T: DeserializeOwned,
+    I: IntoIterator<Item = std::result::Result<RecordBatch, E>>,
+    Error: From<E>,
+{
+    let mut record_batches = record_batches.into_iter().enumerate();
+    let mut schema: Option<Arc<Schema>> = None;
+    let mut fields = Vec::<Field>::new();
+    let mut current: Option<(RecordBatch, usize)> = None;
+    let mut failed = false;
+
+    std::iter::from_fn(move || loop {
+        if failed {
+            return None;
+        }
+        if let Some((record_batch, row)) = current.as_mut() {
+            if *row < record_batch.num_rows() {
+                let res = deserialize_record_batch_row(&fields, record_batch, *row);
+                *row += 1;
+                failed = res.is_err();
+                return Some(res);
+            }
+        }
+
+        let (idx, record_batch) = record_batches.next()?;
+        let res = match record_batch {
+            Ok(record_batch) => check_schema(&mut schema, idx, &record_batch).and_then(|_| {
+                if idx == 0 {
+                    fields = fields_from_field_refs(record_batch.schema().fields())?;
+                }
+                Ok(record_batch)
+            }),
+            Err(err) => Err(Error::from(err)),
+        };
+        match res {
+            Ok(record_batch) => current = Some((record_batch, 0)),
+            Err(err) => {
+                failed = true;
+                return Some(Err(err));
+            }
+        }
+    })
+}
+
+fn deserialize_record_batch_row<T: DeserializeOwned>(
+    fields: &[Field],
+    record_batch: &RecordBatch,
+    row: usize,
+) -> Result<T> {
+    let mut views = Vec::new();
+    for array in record_batch.columns() {
+        views.push(ArrayView::try_from(array.as_ref())?);
+    }
+    deserialize_row(fields, &views, None, FieldNames::Unchanged, row)
+}
+
+fn check_schema(
+    schema: &mut Option<Arc<Schema>>,
+    idx: usize,
+    record_batch: &RecordBatch,
+) -> Result<()> {
+    let Some(schema) = schema.as_ref() else {
+        *schema = Some(record_batch.schema());
+        return Ok(());
+    };
+    if schema.fields() != record_batch.schema().fields() {
+        fail!("Cannot deserialize record batches with different schemas: the schema of batch {idx} does not match the schema of the first batch");
+    }
+    Ok(())
+}
+
 /// Support `arrow` (*requires one of the `arrow-*` features*)
 impl crate::internal::array_builder::ArrayBuilder {
     /// Build an ArrayBuilder from `arrow` fields (*requires one of the
     /// `arrow-*` features*)
+    ///
+    /// The resulting schema has no schema-level metadata. Use
+    /// [`ArrayBuilder::from_arrow_schema`] to build record batches that carry
+    /// it.
     pub fn from_arrow(fields: &[FieldRef]) -> Result<Self> {
         let fields = fields_from_field_refs(fields)?;
-        Self::new(SerdeArrowSchema { fields })
+        Self::new(SerdeArrowSchema {
+            fields,
+            metadata: HashMap::new(),
+        })
+    }
+
+    /// Build an ArrayBuilder from an `arrow` schema (*requires one of the
+    /// `arrow-*` features*)
+    ///
+    /// The schema-level metadata is included in the record batches built with
+    /// [`ArrayBuilder::to_record_batch`].
+    pub fn from_arrow_schema(schema: &Schema) -> Result<Self> {
+        Self::new(SerdeArrowSchema::try_from(schema)?)
     }
 
     /// Construct `arrow` arrays and reset the builder (*requires one of the
@@ -196,8 +400,7 @@ impl crate::internal::array_builder::ArrayBuilder {
     /// `arrow-*` features*)
     pub fn to_record_batch(&mut self) -> Result<RecordBatch> {
         let arrays = self.to_arrow()?;
-        let fields = Vec::<FieldRef>::try_from(&self.schema)?;
-        let schema = Schema::new(fields);
+        let schema = Schema::try_from(&self.schema)?;
         Ok(RecordBatch::try_new(Arc::new(schema), arrays)?)
     }
 }
@@ -232,26 +435,52 @@ impl<'de> Deserializer<'de> {
     where
         A: AsRef<dyn Array>,
     {
-        use crate::internal::arrow::ArrayView;
-
-        if fields.len() != arrays.len() {
-            fail!(
-                "different number of fields ({}) and arrays ({})",
-                fields.len(),
-                arrays.len()
-            );
-        }
-
-        let fields = fields_from_field_refs(fields)?;
-
-        let mut views = Vec::new();
-        for array in arrays {
-            views.push(ArrayView::try_from(array.as_ref())?);
-        }
-
+        let (fields, views) = fields_and_views_from_arrow(fields, arrays)?;
         Deserializer::new(&fields, views)
     }
 
+    /// Construct a new deserializer for the rows in `range` of `arrow` arrays
+    /// (*requires one of the `arrow-*` features*)
+    ///
+    /// The deserializer starts directly at `range.start` without processing
+    /// the previous rows, i.e., list offsets, union offsets and dictionary
+    /// indices are positioned up front.
+    ///
+    /// Usage
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # let (_, arrays) = serde_arrow::_impl::docs::defs::example_arrow_arrays();
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::datatypes::FieldRef;
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_arrow::{Deserializer, schema::{SchemaLike, TracingOptions}};
+    ///
+    /// ##[derive(Deserialize, Serialize)]
+    /// struct Record {
+    ///     a: Option<f32>,
+    ///     b: u64,
+    /// }
+    ///
+    /// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+    ///
+    /// let deserializer = Deserializer::from_arrow_range(&fields, &arrays, 0..1)?;
+    /// let items = Vec::<Record>::deserialize(deserializer)?;
+    /// assert_eq!(items.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_arrow_range<A>(
+        fields: &[FieldRef],
+        arrays: &'de [A],
+        range: Range<usize>,
+    ) -> Result<Self>
+    where
+        A: AsRef<dyn Array>,
+    {
+        let (fields, views) = fields_and_views_from_arrow(fields, arrays)?;
+        Deserializer::new_range(&fields, views, range)
+    }
+
     /// Construct a new deserializer from a record batch (*requires one of the
     /// `arrow-*` features*)
     ///
@@ -280,4 +509,117 @@ impl<'de> Deserializer<'de> {
         let schema = record_batch.schema();
         Deserializer::from_arrow(schema.fields(), record_batch.columns())
     }
+
+    /// Construct a new deserializer from multiple record batches (*requires
+    /// one of the `arrow-*` features*)
+    ///
+    /// The rows of all batches are deserialized as a single sequence. All
+    /// record batches must share the schema of the first batch.
+    ///
+    /// Usage:
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # let record_batch = serde_arrow::_impl::docs::defs::example_record_batch();
+    /// #
+    /// use serde::Deserialize;
+    /// use serde_arrow::Deserializer;
+    ///
+    /// ##[derive(Deserialize)]
+    /// struct Record {
+    ///     a: Option<f32>,
+    ///     b: u64,
+    /// }
+    ///
+    /// let batches = vec![record_batch.clone(), record_batch];
+    /// let deserializer = Deserializer::from_record_batches(&batches)?;
+    /// let items = Vec::<Record>::deserialize(deserializer)?;
+    /// assert_eq!(items.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn from_record_batches<I>(record_batches: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = &'de RecordBatch>,
+    {
+        let mut schema = None;
+        let mut fields = Vec::new();
+        let mut chunks = Vec::new();
+
+        for (idx, record_batch) in record_batches.into_iter().enumerate() {
+            check_schema(&mut schema, idx, record_batch)?;
+            let (batch_fields, views) = fields_and_views_from_arrow(
+                record_batch.schema().fields(),
+                record_batch.columns(),
+            )?;
+            fields = batch_fields;
+            chunks.push(views);
+        }
+
+        Deserializer::new_chunked(&fields, chunks)
+    }
+
+    /// Construct a new deserializer for the rows in `range` of a record batch
+    /// (*requires one of the `arrow-*` features*)
+    ///
+    /// See [`Deserializer::from_arrow_range`] for details.
+    ///
+    /// Usage:
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # let record_batch = serde_arrow::_impl::docs::defs::example_record_batch();
+    /// #
+    /// use serde::Deserialize;
+    /// use serde_arrow::Deserializer;
+    ///
+    /// ##[derive(Deserialize)]
+    /// struct Record {
+    ///     a: Option<f32>,
+    ///     b: u64,
+    /// }
+    ///
+    /// let deserializer = Deserializer::from_record_batch_range(&record_batch, 0..1)?;
+    /// let items = Vec::<Record>::deserialize(deserializer)?;
+    /// assert_eq!(items.len(), 1);
+    ///
+    /// let deserializer = Deserializer::from_record_batch(&record_batch)?;
+    /// let item: Record = deserializer.get(0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn from_record_batch_range(
+        record_batch: &'de RecordBatch,
+        range: Range<usize>,
+    ) -> Result<Self> {
+        let schema = record_batch.schema();
+        Deserializer::from_arrow_range(schema.fields(), record_batch.columns(), range)
+    }
+}
+
+fn fields_and_views_from_arrow<'de, A>(
+    fields: &[FieldRef],
+    arrays: &'de [A],
+) -> Result<(Vec<Field>, Vec<ArrayView<'de>>)>
+where
+    A: AsRef<dyn Array>,
+{
+    if fields.len() != arrays.len() {
+        fail!(
+            "different number of fields ({}) and arrays ({})",
+            fields.len(),
+            arrays.len()
+        );
+    }
+
+    let fields = fields_from_field_refs(fields)?;
+
+    let mut views = Vec::new();
+    for array in arrays {
+        views.push(ArrayView::try_from(array.as_ref())?);
+    }
+
+    Ok((fields, views))
 }
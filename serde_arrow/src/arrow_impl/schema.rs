@@ -1,11 +1,11 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use serde::{Deserialize, Serialize};
 
 use crate::{
     _impl::arrow::datatypes::{
-        DataType as ArrowDataType, Field as ArrowField, FieldRef, TimeUnit as ArrowTimeUnit,
-        UnionMode as ArrowUnionMode,
+        DataType as ArrowDataType, Field as ArrowField, FieldRef, Schema,
+        TimeUnit as ArrowTimeUnit, UnionMode as ArrowUnionMode,
     },
     internal::{
         arrow::{DataType, Field, TimeUnit, UnionMode},
@@ -50,12 +50,42 @@ impl<'a> TryFrom<&'a SerdeArrowSchema> for Vec<FieldRef> {
     }
 }
 
+impl TryFrom<SerdeArrowSchema> for Schema {
+    type Error = Error;
+
+    fn try_from(value: SerdeArrowSchema) -> Result<Self> {
+        (&value).try_into()
+    }
+}
+
+impl<'a> TryFrom<&'a SerdeArrowSchema> for Schema {
+    type Error = Error;
+
+    fn try_from(value: &'a SerdeArrowSchema) -> Result<Self> {
+        let fields = Vec::<FieldRef>::try_from(value)?;
+        Ok(Schema::new_with_metadata(fields, value.metadata.clone()))
+    }
+}
+
+impl<'a> TryFrom<&'a Schema> for SerdeArrowSchema {
+    type Error = Error;
+
+    fn try_from(schema: &'a Schema) -> Result<Self> {
+        let fields = SerdeArrowSchema::try_from(schema.fields().as_ref())?.fields;
+        Ok(Self {
+            fields,
+            metadata: schema.metadata().clone(),
+        })
+    }
+}
+
 impl<'a> TryFrom<&'a [ArrowField]> for SerdeArrowSchema {
     type Error = Error;
 
     fn try_from(fields: &'a [ArrowField]) -> Result<Self> {
         Ok(Self {
             fields: fields.iter().map(Field::try_from).collect::<Result<_>>()?,
+            metadata: HashMap::new(),
         })
     }
 }
@@ -69,6 +99,7 @@ impl<'a> TryFrom<&'a [FieldRef]> for SerdeArrowSchema {
                 .iter()
                 .map(|f| Field::try_from(f.as_ref()))
                 .collect::<Result<_>>()?,
+            metadata: HashMap::new(),
         })
     }
 }
@@ -1,7 +1,10 @@
 use serde::Serialize;
 
 use crate::internal::{
-    arrow::Array, error::Result, schema::SerdeArrowSchema, serialization::OuterSequenceBuilder,
+    arrow::Array,
+    error::{fail, Result},
+    schema::{FieldNames, SerdeArrowSchema},
+    serialization::OuterSequenceBuilder,
 };
 
 /// Construct arrays by pushing individual records
@@ -50,7 +53,6 @@ use crate::internal::{
 /// ```
 pub struct ArrayBuilder {
     pub(crate) builder: OuterSequenceBuilder,
-    #[allow(unused)]
     pub(crate) schema: SerdeArrowSchema,
 }
 
@@ -58,10 +60,52 @@ impl ArrayBuilder {
     /// Construct an array build from an [`SerdeArrowSchema`]
     pub fn new(schema: SerdeArrowSchema) -> Result<Self> {
         Ok(Self {
-            builder: OuterSequenceBuilder::new(&schema)?,
+            builder: OuterSequenceBuilder::new(&schema, FieldNames::Unchanged)?,
             schema,
         })
     }
+
+    /// Translate the serde field names into the Arrow field names of the
+    /// schema with the given policy
+    ///
+    /// Use the same policy as in
+    /// [`TracingOptions::field_names`][crate::schema::TracingOptions::field_names]
+    /// when tracing the schema. The policy must be set before any records are
+    /// pushed.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde::Serialize;
+    /// use serde_arrow::{
+    ///     schema::{FieldNames, SchemaLike, SerdeArrowSchema, TracingOptions},
+    ///     ArrayBuilder,
+    /// };
+    ///
+    /// ##[derive(Serialize)]
+    /// struct Record {
+    ///     user_id: u64,
+    /// }
+    ///
+    /// // the schema contains a single field `USER_ID`
+    /// let schema = SerdeArrowSchema::from_samples(
+    ///     &[Record { user_id: 1 }],
+    ///     TracingOptions::default().field_names(FieldNames::UpperCase),
+    /// )?;
+    ///
+    /// let mut builder = ArrayBuilder::new(schema)?.with_field_names(FieldNames::UpperCase)?;
+    /// builder.push(Record { user_id: 13 })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_field_names(self, field_names: FieldNames) -> Result<Self> {
+        if self.builder.len() != 0 {
+            fail!("The field names policy must be set before pushing records");
+        }
+        Ok(Self {
+            builder: OuterSequenceBuilder::new(&self.schema, field_names)?,
+            schema: self.schema,
+        })
+    }
 }
 
 impl std::fmt::Debug for ArrayBuilder {
@@ -105,6 +149,7 @@ impl std::convert::AsMut<ArrayBuilder> for ArrayBuilder {
 }
 
 const _: () = {
+    #[allow(dead_code)]
     trait AssertSendSync: Send + Sync {}
     impl AssertSendSync for ArrayBuilder {}
 };
@@ -148,7 +148,7 @@ mod parsing {
         fn into_result(self, output_type: &str) -> crate::internal::error::Result<Self::Output>;
     }
 
-    impl<'a, 'e, R> ParseResult for Result<(&'a str, R), &'e str> {
+    impl<R> ParseResult for Result<(&str, R), &str> {
         type Output = R;
 
         fn matches(&self) -> bool {
@@ -4,25 +4,29 @@ use serde::de::{Deserialize, DeserializeSeed, VariantAccess, Visitor};
 use crate::internal::{
     arrow::{ArrayView, FieldMeta, PrimitiveArrayView, TimeUnit},
     error::{fail, Context, Error, Result},
-    schema::{Strategy, STRATEGY_KEY},
-    utils::{ChildName, Mut},
+    schema::{FieldNames, Strategy, STRATEGY_KEY},
+    utils::{array_view_ext::ArrayViewExt, ChildName, Mut, Offset},
 };
 
+use super::enum_deserializer::verify_offsets;
 use super::{
     binary_deserializer::BinaryDeserializer, bool_deserializer::BoolDeserializer,
     date32_deserializer::Date32Deserializer, date64_deserializer::Date64Deserializer,
-    decimal_deserializer::DecimalDeserializer, dictionary_deserializer::DictionaryDeserializer,
-    duration_deserializer::DurationDeserializer, enum_deserializer::EnumDeserializer,
+    decimal_deserializer::DecimalDeserializer, default_deserializer::DefaultDeserializer,
+    dictionary_deserializer::DictionaryDeserializer, duration_deserializer::DurationDeserializer,
+    enum_deserializer::EnumDeserializer,
     fixed_size_binary_deserializer::FixedSizeBinaryDeserializer,
     fixed_size_list_deserializer::FixedSizeListDeserializer, float_deserializer::FloatDeserializer,
-    integer_deserializer::IntegerDeserializer, list_deserializer::ListDeserializer,
-    map_deserializer::MapDeserializer, null_deserializer::NullDeserializer,
-    simple_deserializer::SimpleDeserializer, string_deserializer::StringDeserializer,
-    struct_deserializer::StructDeserializer, time_deserializer::TimeDeserializer,
+    integer_deserializer::IntegerDeserializer, json_deserializer::JsonDeserializer,
+    list_deserializer::ListDeserializer, map_deserializer::MapDeserializer,
+    null_deserializer::NullDeserializer, simple_deserializer::SimpleDeserializer,
+    string_deserializer::StringDeserializer, struct_deserializer::StructDeserializer,
+    time_deserializer::TimeDeserializer,
 };
 
 pub enum ArrayDeserializer<'a> {
     Null(NullDeserializer),
+    Default(DefaultDeserializer),
     Bool(BoolDeserializer<'a>),
     U8(IntegerDeserializer<'a, u8>),
     U16(IntegerDeserializer<'a, u16>),
@@ -68,25 +72,52 @@ pub enum ArrayDeserializer<'a> {
     FixedSizeBinary(FixedSizeBinaryDeserializer<'a>),
     Map(MapDeserializer<'a>),
     Enum(EnumDeserializer<'a>),
+    Json(JsonDeserializer<'a, i32>),
+    LargeJson(JsonDeserializer<'a, i64>),
 }
 
 impl<'a> ArrayDeserializer<'a> {
-    pub fn new(path: String, strategy: Option<&Strategy>, array: ArrayView<'a>) -> Result<Self> {
+    pub fn new(
+        path: String,
+        strategy: Option<&Strategy>,
+        array: ArrayView<'a>,
+        field_names: FieldNames,
+    ) -> Result<Self> {
         use {ArrayDeserializer as D, ArrayView as V};
         match array {
             ArrayView::Null(_) => Ok(Self::Null(NullDeserializer::new(path))),
             V::Boolean(view) => Ok(D::Bool(BoolDeserializer::new(path, view))),
-            V::Int8(view) => Ok(D::I8(IntegerDeserializer::new(path, view))),
-            V::Int16(view) => Ok(D::I16(IntegerDeserializer::new(path, view))),
-            V::Int32(view) => Ok(D::I32(IntegerDeserializer::new(path, view))),
-            V::Int64(view) => Ok(D::I64(IntegerDeserializer::new(path, view))),
-            V::UInt8(view) => Ok(D::U8(IntegerDeserializer::new(path, view))),
-            V::UInt16(view) => Ok(D::U16(IntegerDeserializer::new(path, view))),
-            V::UInt32(view) => Ok(D::U32(IntegerDeserializer::new(path, view))),
-            V::UInt64(view) => Ok(D::U64(IntegerDeserializer::new(path, view))),
+            V::Int8(view) => Ok(D::I8(
+                IntegerDeserializer::new(path, view).with_strategy(strategy),
+            )),
+            V::Int16(view) => Ok(D::I16(
+                IntegerDeserializer::new(path, view).with_strategy(strategy),
+            )),
+            V::Int32(view) => Ok(D::I32(
+                IntegerDeserializer::new(path, view).with_strategy(strategy),
+            )),
+            V::Int64(view) => Ok(D::I64(
+                IntegerDeserializer::new(path, view).with_strategy(strategy),
+            )),
+            V::UInt8(view) => Ok(D::U8(
+                IntegerDeserializer::new(path, view).with_strategy(strategy),
+            )),
+            V::UInt16(view) => Ok(D::U16(
+                IntegerDeserializer::new(path, view).with_strategy(strategy),
+            )),
+            V::UInt32(view) => Ok(D::U32(
+                IntegerDeserializer::new(path, view).with_strategy(strategy),
+            )),
+            V::UInt64(view) => Ok(D::U64(
+                IntegerDeserializer::new(path, view).with_strategy(strategy),
+            )),
             V::Float16(view) => Ok(D::F16(FloatDeserializer::new(path, view))),
-            V::Float32(view) => Ok(D::F32(FloatDeserializer::new(path, view))),
-            V::Float64(view) => Ok(D::F64(FloatDeserializer::new(path, view))),
+            V::Float32(view) => Ok(D::F32(
+                FloatDeserializer::new(path, view).with_strategy(strategy),
+            )),
+            V::Float64(view) => Ok(D::F64(
+                FloatDeserializer::new(path, view).with_strategy(strategy),
+            )),
             V::Decimal128(view) => Ok(D::Decimal128(DecimalDeserializer::new(path, view))),
             ArrayView::Date32(view) => Ok(Self::Date32(Date32Deserializer::new(
                 path,
@@ -131,13 +162,25 @@ impl<'a> ArrayDeserializer<'a> {
                     validity: view.validity,
                 },
             ))),
+            V::Utf8(view) if matches!(strategy, Some(Strategy::ValueAsJson)) => {
+                Ok(D::Json(JsonDeserializer::new(path, view, "Utf8")))
+            }
+            V::LargeUtf8(view) if matches!(strategy, Some(Strategy::ValueAsJson)) => {
+                Ok(D::LargeJson(JsonDeserializer::new(path, view, "LargeUtf8")))
+            }
+            V::Binary(view) if matches!(strategy, Some(Strategy::ValueAsJson)) => {
+                Ok(D::Json(JsonDeserializer::new(path, view, "Binary")))
+            }
+            V::LargeBinary(view) if matches!(strategy, Some(Strategy::ValueAsJson)) => Ok(
+                D::LargeJson(JsonDeserializer::new(path, view, "LargeBinary")),
+            ),
             V::Utf8(view) => Ok(D::Utf8(StringDeserializer::new(path, view))),
             V::LargeUtf8(view) => Ok(D::LargeUtf8(StringDeserializer::new(path, view))),
             V::Binary(view) => Ok(D::Binary(BinaryDeserializer::new(path, view))),
             V::LargeBinary(view) => Ok(D::LargeBinary(BinaryDeserializer::new(path, view))),
-            V::FixedSizeBinary(view) => Ok(D::FixedSizeBinary(FixedSizeBinaryDeserializer::new(
-                path, view,
-            )?)),
+            V::FixedSizeBinary(view) => Ok(D::FixedSizeBinary(
+                FixedSizeBinaryDeserializer::new(path, view)?.with_strategy(strategy),
+            )),
             V::List(view) => {
                 let child_path = format!("{path}.{child}", child = ChildName(&view.meta.name));
                 Ok(D::List(ListDeserializer::new(
@@ -145,7 +188,8 @@ impl<'a> ArrayDeserializer<'a> {
                     ArrayDeserializer::new(
                         child_path,
                         get_strategy(&view.meta)?.as_ref(),
-                        *view.element,
+                        slice_to_offsets(&view.element, view.offsets)?,
+                        field_names,
                     )?,
                     view.offsets,
                     view.validity,
@@ -158,7 +202,8 @@ impl<'a> ArrayDeserializer<'a> {
                     ArrayDeserializer::new(
                         child_path,
                         get_strategy(&view.meta)?.as_ref(),
-                        *view.element,
+                        slice_to_offsets(&view.element, view.offsets)?,
+                        field_names,
                     )?,
                     view.offsets,
                     view.validity,
@@ -172,6 +217,7 @@ impl<'a> ArrayDeserializer<'a> {
                         child_path,
                         get_strategy(&view.meta)?.as_ref(),
                         *view.element,
+                        field_names,
                     )?,
                     view.validity,
                     view.n.try_into()?,
@@ -186,18 +232,17 @@ impl<'a> ArrayDeserializer<'a> {
                         child_path,
                         get_strategy(&field_meta)?.as_ref(),
                         field_view,
+                        field_names,
                     )?;
                     let field_name = field_meta.name;
 
                     fields.push((field_name, field_deserializer));
                 }
 
-                Ok(D::Struct(StructDeserializer::new(
-                    path,
-                    fields,
-                    view.validity,
-                    view.len,
-                )))
+                Ok(D::Struct(
+                    StructDeserializer::new(path, fields, view.validity, view.len)
+                        .with_field_names(field_names),
+                ))
             }
             V::Map(view) => {
                 let ArrayView::Struct(entries_view) = *view.element else {
@@ -207,11 +252,15 @@ impl<'a> ArrayDeserializer<'a> {
                     fail!("Invalid entries field in map array")
                 };
                 let [(keys_view, keys_meta), (values_view, values_meta)] = entries_fields;
+                let keys_view = slice_to_offsets(&keys_view, view.offsets)?;
+                let values_view = slice_to_offsets(&values_view, view.offsets)?;
+
                 let keys_path = format!("{path}.{child}", child = ChildName(&keys_meta.name));
                 let keys = ArrayDeserializer::new(
                     keys_path,
                     get_strategy(&keys_meta)?.as_ref(),
                     keys_view,
+                    field_names,
                 )?;
 
                 let values_path = format!("{path}.{child}", child = ChildName(&values_meta.name));
@@ -219,6 +268,7 @@ impl<'a> ArrayDeserializer<'a> {
                     values_path,
                     get_strategy(&values_meta)?.as_ref(),
                     values_view,
+                    field_names,
                 )?;
 
                 Ok(D::Map(MapDeserializer::new(
@@ -281,27 +331,34 @@ impl<'a> ArrayDeserializer<'a> {
                 _ => fail!("Unsupported dictionary array type"),
             },
             ArrayView::DenseUnion(view) => {
+                let initial_offsets = verify_offsets(view.types, view.offsets, view.fields.len())?;
+
                 let mut fields = Vec::new();
                 for (idx, (type_id, field_view, field_meta)) in view.fields.into_iter().enumerate()
                 {
                     if usize::try_from(type_id) != Ok(idx) {
                         fail!("Only unions with consecutive type ids are currently supported");
                     }
+                    let initial_offset = match initial_offsets.get(&type_id) {
+                        Some(&offset) => usize::try_from(offset)?,
+                        None => 0,
+                    };
+                    let field_view = field_view.slice(
+                        initial_offset,
+                        field_view.len().saturating_sub(initial_offset),
+                    )?;
+
                     let child_path = format!("{path}.{child}", child = ChildName(&field_meta.name));
                     let field_deserializer = ArrayDeserializer::new(
                         child_path,
                         get_strategy(&field_meta)?.as_ref(),
                         field_view,
+                        field_names,
                     )?;
                     fields.push((field_meta.name, field_deserializer))
                 }
 
-                Ok(Self::Enum(EnumDeserializer::new(
-                    path,
-                    view.types,
-                    view.offsets,
-                    fields,
-                )?))
+                Ok(Self::Enum(EnumDeserializer::new(path, view.types, fields)))
             }
         }
     }
@@ -325,6 +382,21 @@ fn is_utc_date64(strategy: Option<&Strategy>) -> Result<bool> {
     }
 }
 
+/// Restrict the child of an offset based array to the range referenced by the
+/// offsets, so that the child deserializer starts at the first referenced
+/// element
+fn slice_to_offsets<'a, O: Offset>(child: &ArrayView<'a>, offsets: &[O]) -> Result<ArrayView<'a>> {
+    let (Some(start), Some(end)) = (offsets.first(), offsets.last()) else {
+        fail!("Unsupported: list offsets must be non empty");
+    };
+    let start = start.try_into_usize()?;
+    let end = end.try_into_usize()?;
+    if end < start {
+        fail!("Unsupported: list offsets are assumed to be monotonically increasing");
+    }
+    child.slice(start, end - start)
+}
+
 fn get_strategy(meta: &FieldMeta) -> Result<Option<Strategy>> {
     let Some(strategy) = meta.metadata.get(STRATEGY_KEY) else {
         return Ok(None);
@@ -336,6 +408,7 @@ macro_rules! dispatch {
     ($obj:expr, $wrapper:ident($name:ident) => $expr:expr) => {
         match $obj {
             $wrapper::Null($name) => $expr,
+            $wrapper::Default($name) => $expr,
             $wrapper::Bool($name) => $expr,
             $wrapper::U8($name) => $expr,
             $wrapper::U16($name) => $expr,
@@ -365,6 +438,8 @@ macro_rules! dispatch {
             $wrapper::FixedSizeBinary($name) => $expr,
             $wrapper::Map($name) => $expr,
             $wrapper::Enum($name) => $expr,
+            $wrapper::Json($name) => $expr,
+            $wrapper::LargeJson($name) => $expr,
             $wrapper::DictionaryU8I32($name) => $expr,
             $wrapper::DictionaryU16I32($name) => $expr,
             $wrapper::DictionaryU32I32($name) => $expr,
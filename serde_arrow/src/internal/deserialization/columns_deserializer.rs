@@ -0,0 +1,176 @@
+use serde::de::{value::StrDeserializer, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+
+use crate::internal::{
+    arrow::{ArrayView, BitsWithOffset},
+    error::{fail, Error, Result},
+    utils::{
+        array_view_ext::ArrayViewExt,
+        slice::{SlicePrimitive, SLICE_NAME_PREFIX},
+        Mut,
+    },
+};
+
+use super::{array_deserializer::ArrayDeserializer, utils::bitset_is_set};
+
+/// Deserialize the columns of arrays as a struct of sequences
+pub struct ColumnsDeserializer<'de> {
+    columns: std::vec::IntoIter<ColumnDeserializer<'de>>,
+    next: Option<ColumnDeserializer<'de>>,
+}
+
+impl<'de> ColumnsDeserializer<'de> {
+    pub fn new(columns: Vec<ColumnDeserializer<'de>>) -> Self {
+        Self {
+            columns: columns.into_iter(),
+            next: None,
+        }
+    }
+}
+
+impl<'de> serde::de::Deserializer<'de> for ColumnsDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_map(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> MapAccess<'de> for ColumnsDeserializer<'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some(column) = self.columns.next() else {
+            return Ok(None);
+        };
+        let key = seed.deserialize(StrDeserializer::<Error>::new(&column.name))?;
+        self.next = Some(column);
+        Ok(Some(key))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let Some(column) = self.next.take() else {
+            fail!("Invalid state in columns deserializer: no column to deserialize");
+        };
+        seed.deserialize(column)
+    }
+}
+
+/// Deserialize the values of a single column, potentially split into chunks,
+/// as a sequence
+pub struct ColumnDeserializer<'de> {
+    pub name: String,
+    pub path: String,
+    pub chunks: Vec<(ArrayDeserializer<'de>, usize)>,
+    /// The views of the column if present in the input, used for zero-copy
+    /// slices
+    pub views: Vec<ArrayView<'de>>,
+}
+
+impl<'de> serde::de::Deserializer<'de> for ColumnDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(ColumnSeqAccess {
+            chunks: self.chunks,
+            chunk: 0,
+            next: 0,
+        })
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        if !name.starts_with(SLICE_NAME_PREFIX) {
+            return visitor.visit_newtype_struct(self);
+        }
+
+        let path = self.path;
+        let [view] = self.views.as_slice() else {
+            fail!("Cannot borrow column {path}: zero-copy slices require a single chunk of arrays");
+        };
+        let Some((slice_name, bytes, validity)) = get_primitive_bytes(view) else {
+            fail!("Cannot borrow column {path}: zero-copy slices require a primitive column");
+        };
+        if slice_name != name {
+            fail!("Cannot borrow column {path} as {name}: the column is of type {slice_name}");
+        }
+        if let Some(validity) = validity {
+            for idx in 0..view.len() {
+                if !bitset_is_set(&validity, idx)? {
+                    fail!("Cannot borrow column {path}: the column contains nulls");
+                }
+            }
+        }
+        visitor.visit_borrowed_bytes(bytes)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct ColumnSeqAccess<'de> {
+    chunks: Vec<(ArrayDeserializer<'de>, usize)>,
+    chunk: usize,
+    next: usize,
+}
+
+impl<'de> SeqAccess<'de> for ColumnSeqAccess<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        loop {
+            let Some((deserializer, len)) = self.chunks.get_mut(self.chunk) else {
+                return Ok(None);
+            };
+            if self.next >= *len {
+                self.chunk += 1;
+                self.next = 0;
+                continue;
+            }
+            self.next += 1;
+
+            return Ok(Some(seed.deserialize(Mut(deserializer))?));
+        }
+    }
+}
+
+type PrimitiveBytes<'a> = (&'static str, &'a [u8], Option<BitsWithOffset<'a>>);
+
+fn get_primitive_bytes<'a>(view: &ArrayView<'a>) -> Option<PrimitiveBytes<'a>> {
+    fn bytes<'a, T: SlicePrimitive + bytemuck::Pod>(
+        values: &'a [T],
+        validity: Option<BitsWithOffset<'a>>,
+    ) -> Option<PrimitiveBytes<'a>> {
+        Some((T::SLICE_NAME, bytemuck::cast_slice(values), validity))
+    }
+
+    match view {
+        ArrayView::Int8(view) => bytes(view.values, view.validity),
+        ArrayView::Int16(view) => bytes(view.values, view.validity),
+        ArrayView::Int32(view) => bytes(view.values, view.validity),
+        ArrayView::Int64(view) => bytes(view.values, view.validity),
+        ArrayView::UInt8(view) => bytes(view.values, view.validity),
+        ArrayView::UInt16(view) => bytes(view.values, view.validity),
+        ArrayView::UInt32(view) => bytes(view.values, view.validity),
+        ArrayView::UInt64(view) => bytes(view.values, view.validity),
+        ArrayView::Float16(view) => bytes(view.values, view.validity),
+        ArrayView::Float32(view) => bytes(view.values, view.validity),
+        ArrayView::Float64(view) => bytes(view.values, view.validity),
+        _ => None,
+    }
+}
@@ -66,4 +66,8 @@ impl<'de> SimpleDeserializer<'de> for DecimalDeserializer<'de> {
         })
         .ctx(self)
     }
+
+    fn deserialize_string<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
 }
@@ -0,0 +1,367 @@
+use serde::{
+    de::{Deserializer, Visitor},
+    Deserialize,
+};
+
+use crate::internal::{
+    arrow::{
+        Array, ArrayView, DataType, DecimalArrayView, Field, PrimitiveArrayView, TimeArrayView,
+        TimestampArrayView,
+    },
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    schema::{get_strategy_from_metadata, DataTypeDisplay, FieldNames, DEFAULT_VALUE_KEY},
+    serialization::{outer_sequence_builder::build_builder, simple_serializer::SimpleSerializer},
+    utils::{
+        value::{Value, ValueDeserializer, ValueSerializer},
+        Mut,
+    },
+};
+
+use super::{array_deserializer::ArrayDeserializer, simple_deserializer::SimpleDeserializer};
+
+/// A deserializer for a virtual column that contains the same value in every
+/// row, e.g., a column missing from the input that is filled with its default
+pub struct DefaultDeserializer {
+    path: String,
+    value: Value,
+    /// The string representation of temporal and decimal values
+    repr: Option<Value>,
+}
+
+impl DefaultDeserializer {
+    /// Construct the deserializer filling a missing column of the given field
+    pub fn new(path: String, field: &Field) -> Result<Self> {
+        if let Some(value) = field.metadata.get(DEFAULT_VALUE_KEY) {
+            if has_physical_repr(&field.data_type) {
+                let (value, repr) = parse_physical_value(&path, field, value)?;
+                return Ok(Self { path, value, repr });
+            }
+        }
+        Ok(Self {
+            value: default_value(&path, field)?,
+            path,
+            repr: None,
+        })
+    }
+}
+
+impl Context for DefaultDeserializer {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", "Default");
+    }
+}
+
+macro_rules! forward_to_value {
+    ($($method:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+                ValueDeserializer::new(&self.value).$method(visitor).ctx(self)
+            }
+        )*
+    };
+}
+
+macro_rules! forward_to_repr {
+    ($($method:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+                let value = self.repr.as_ref().unwrap_or(&self.value);
+                ValueDeserializer::new(value).$method(visitor).ctx(self)
+            }
+        )*
+    };
+}
+
+impl<'de> SimpleDeserializer<'de> for DefaultDeserializer {
+    forward_to_value!(
+        deserialize_any,
+        deserialize_ignored_any,
+        deserialize_bool,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_char,
+        deserialize_map,
+        deserialize_identifier,
+        deserialize_option,
+        deserialize_seq,
+        deserialize_unit,
+    );
+
+    forward_to_repr!(
+        deserialize_str,
+        deserialize_string,
+        deserialize_bytes,
+        deserialize_byte_buf,
+    );
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        &mut self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        ValueDeserializer::new(&self.value)
+            .deserialize_struct(name, fields, visitor)
+            .ctx(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        &mut self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        ValueDeserializer::new(&self.value)
+            .deserialize_enum(name, variants, visitor)
+            .ctx(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        &mut self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        ValueDeserializer::new(&self.value)
+            .deserialize_newtype_struct(name, visitor)
+            .ctx(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(&mut self, len: usize, visitor: V) -> Result<V::Value> {
+        ValueDeserializer::new(&self.value)
+            .deserialize_tuple(len, visitor)
+            .ctx(self)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        &mut self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        ValueDeserializer::new(&self.value)
+            .deserialize_tuple_struct(name, len, visitor)
+            .ctx(self)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        &mut self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        ValueDeserializer::new(&self.value)
+            .deserialize_unit_struct(name, visitor)
+            .ctx(self)
+    }
+}
+
+/// Determine the value used to fill a missing column of the given field
+///
+/// A value stored under [`DEFAULT_VALUE_KEY`] in the field metadata takes
+/// precedence. Otherwise, nullable fields are filled with nulls and
+/// non-nullable fields with the zero value of their data type.
+fn default_value(path: &str, field: &Field) -> Result<Value> {
+    if let Some(value) = field.metadata.get(DEFAULT_VALUE_KEY) {
+        if has_physical_repr(&field.data_type) {
+            return Ok(parse_physical_value(path, field, value)?.0);
+        }
+        return parse_default_value(&field.data_type, value);
+    }
+    if field.nullable {
+        return Ok(Value::None);
+    }
+    zero_value(path, &field.data_type)
+}
+
+fn zero_value(path: &str, data_type: &DataType) -> Result<Value> {
+    use DataType as T;
+    Ok(match data_type {
+        T::Null => Value::Unit,
+        T::Boolean => Value::Bool(false),
+        T::Int8 => Value::I8(0),
+        T::Int16 => Value::I16(0),
+        T::Int32 => Value::I32(0),
+        T::Int64 => Value::I64(0),
+        T::UInt8 => Value::U8(0),
+        T::UInt16 => Value::U16(0),
+        T::UInt32 => Value::U32(0),
+        T::UInt64 => Value::U64(0),
+        T::Float16 | T::Float32 => serde::Serialize::serialize(&0.0_f32, ValueSerializer)?,
+        T::Float64 => serde::Serialize::serialize(&0.0_f64, ValueSerializer)?,
+        T::Utf8 | T::LargeUtf8 => Value::String(String::new()),
+        T::Binary | T::LargeBinary => Value::Bytes(Vec::new()),
+        T::FixedSizeBinary(n) => Value::Bytes(vec![0; usize::try_from(*n)?]),
+        T::List(_) | T::LargeList(_) => Value::Seq(Vec::new()),
+        T::FixedSizeList(element, n) => {
+            Value::Seq(vec![default_value(path, element)?; usize::try_from(*n)?])
+        }
+        T::Map(_, _) => Value::Map(Vec::new()),
+        T::Struct(fields) => {
+            let mut entries = Vec::new();
+            for field in fields {
+                entries.push((
+                    Value::String(field.name.clone()),
+                    default_value(path, field)?,
+                ));
+            }
+            Value::Map(entries)
+        }
+        T::Dictionary(_, values, _) => zero_value(path, values)?,
+        dt => fail!(
+            "No default value for data type {dt}: mark the field as nullable or set a default value via the `{DEFAULT_VALUE_KEY}` metadata",
+            dt = DataTypeDisplay(dt),
+        ),
+    })
+}
+
+fn parse_default_value(data_type: &DataType, value: &str) -> Result<Value> {
+    use DataType as T;
+    Ok(match data_type {
+        T::Boolean => Value::Bool(value.parse()?),
+        T::Int8 => Value::I8(value.parse()?),
+        T::Int16 => Value::I16(value.parse()?),
+        T::Int32 => Value::I32(value.parse()?),
+        T::Int64 => Value::I64(value.parse()?),
+        T::UInt8 => Value::U8(value.parse()?),
+        T::UInt16 => Value::U16(value.parse()?),
+        T::UInt32 => Value::U32(value.parse()?),
+        T::UInt64 => Value::U64(value.parse()?),
+        T::Float16 | T::Float32 => {
+            serde::Serialize::serialize(&value.parse::<f32>()?, ValueSerializer)?
+        }
+        T::Float64 => serde::Serialize::serialize(&value.parse::<f64>()?, ValueSerializer)?,
+        T::Utf8 | T::LargeUtf8 => Value::String(value.to_owned()),
+        T::Dictionary(_, values, _) => parse_default_value(values, value)?,
+        dt => fail!(
+            "Default values via metadata are not supported for data type {dt}",
+            dt = DataTypeDisplay(dt),
+        ),
+    })
+}
+
+/// Whether the column deserializer distinguishes between the stored value and
+/// its string representation
+fn has_physical_repr(data_type: &DataType) -> bool {
+    use DataType as T;
+    matches!(
+        data_type,
+        T::Date32
+            | T::Date64
+            | T::Timestamp(_, _)
+            | T::Time32(_)
+            | T::Time64(_)
+            | T::Duration(_)
+            | T::Decimal128(_, _)
+    )
+}
+
+/// Parse the default as the array builder would and determine the values the
+/// column deserializer would produce, i.e., the stored value and its string
+/// representation
+fn parse_physical_value(path: &str, field: &Field, value: &str) -> Result<(Value, Option<Value>)> {
+    try_(|| {
+        let mut builder = build_builder(path.to_owned(), field, FieldNames::Unchanged)?;
+        builder.serialize_str(value)?;
+        let array = builder.into_array()?;
+
+        let (physical, view) = match &array {
+            Array::Date32(array) => (
+                array.values.first().copied().map(Value::I32),
+                ArrayView::Date32(PrimitiveArrayView {
+                    validity: None,
+                    values: &array.values,
+                }),
+            ),
+            Array::Date64(array) => (
+                array.values.first().copied().map(Value::I64),
+                ArrayView::Date64(PrimitiveArrayView {
+                    validity: None,
+                    values: &array.values,
+                }),
+            ),
+            Array::Timestamp(array) => (
+                array.values.first().copied().map(Value::I64),
+                ArrayView::Timestamp(TimestampArrayView {
+                    unit: array.unit,
+                    timezone: array.timezone.clone(),
+                    validity: None,
+                    values: &array.values,
+                }),
+            ),
+            Array::Time32(array) => (
+                array.values.first().copied().map(Value::I32),
+                ArrayView::Time32(TimeArrayView {
+                    unit: array.unit,
+                    validity: None,
+                    values: &array.values,
+                }),
+            ),
+            Array::Time64(array) => (
+                array.values.first().copied().map(Value::I64),
+                ArrayView::Time64(TimeArrayView {
+                    unit: array.unit,
+                    validity: None,
+                    values: &array.values,
+                }),
+            ),
+            Array::Duration(array) => (
+                array.values.first().copied().map(Value::I64),
+                ArrayView::Duration(TimeArrayView {
+                    unit: array.unit,
+                    validity: None,
+                    values: &array.values,
+                }),
+            ),
+            // decimals are deserialized as strings
+            Array::Decimal128(array) => (
+                None,
+                ArrayView::Decimal128(DecimalArrayView {
+                    precision: array.precision,
+                    scale: array.scale,
+                    validity: None,
+                    values: &array.values,
+                }),
+            ),
+            _ => fail!(
+                "Default values via metadata are not supported for data type {dt}",
+                dt = DataTypeDisplay(&field.data_type),
+            ),
+        };
+
+        let strategy = get_strategy_from_metadata(&field.metadata)?;
+        let mut deserializer = ArrayDeserializer::new(
+            path.to_owned(),
+            strategy.as_ref(),
+            view,
+            FieldNames::Unchanged,
+        )?;
+        // not all columns support a string representation, e.g., `Date64` without strategy
+        let repr = String::deserialize(Mut(&mut deserializer))
+            .ok()
+            .map(Value::String);
+
+        match (physical, repr) {
+            (Some(physical), repr) => Ok((physical, repr)),
+            (None, Some(repr)) => Ok((repr, None)),
+            (None, None) => fail!("Cannot determine the default value from {value:?}"),
+        }
+    })
+    .ctx(&DefaultContext(path))
+}
+
+struct DefaultContext<'a>(&'a str);
+
+impl<'a> Context for DefaultContext<'a> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", self.0);
+        set_default(annotations, "data_type", "Default");
+    }
+}
@@ -4,6 +4,7 @@ use serde::de::{DeserializeSeed, Deserializer, EnumAccess, Visitor};
 
 use crate::internal::{
     error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
+    schema::UNION_NULL_VARIANT,
     utils::Mut,
 };
 
@@ -20,28 +21,23 @@ impl<'a> EnumDeserializer<'a> {
     pub fn new(
         path: String,
         type_ids: &'a [i8],
-        offsets: &'a [i32],
-        mut variants: Vec<(String, ArrayDeserializer<'a>)>,
-    ) -> Result<Self> {
-        let initial_offsets = verify_offsets(type_ids, offsets, variants.len())?;
-
-        for (type_id, initial_offset) in initial_offsets {
-            let Some((_, variant)) = variants.get_mut(type_id as usize) else {
-                fail!("Unexpected error: could not retrieve variant {type_id}");
-            };
-            variant.skip(initial_offset as usize)?;
-        }
-
-        Ok(Self {
+        variants: Vec<(String, ArrayDeserializer<'a>)>,
+    ) -> Self {
+        Self {
             path,
             type_ids,
             variants,
             next: 0,
-        })
+        }
     }
 }
 
-fn verify_offsets(type_ids: &[i8], offsets: &[i32], num_fields: usize) -> Result<HashMap<i8, i32>> {
+/// Verify the offsets of a dense union and return the initial offset of each variant
+pub fn verify_offsets(
+    type_ids: &[i8],
+    offsets: &[i32],
+    num_fields: usize,
+) -> Result<HashMap<i8, i32>> {
     if type_ids.len() != offsets.len() {
         fail!("Offsets and type ids must have the same length")
     }
@@ -104,6 +100,22 @@ impl<'de> Context for EnumDeserializer<'de> {
 }
 
 impl<'de> SimpleDeserializer<'de> for EnumDeserializer<'de> {
+    fn deserialize_option<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            let Some(&type_id) = self.type_ids.get(self.next) else {
+                fail!("Exhausted deserializer");
+            };
+            match self.variants.get(type_id as usize) {
+                Some((name, ArrayDeserializer::Null(_))) if name == UNION_NULL_VARIANT => {
+                    self.next += 1;
+                    visitor.visit_none()
+                }
+                _ => visitor.visit_some(Mut(&mut *self)),
+            }
+        })
+        .ctx(self)
+    }
+
     fn deserialize_enum<V: Visitor<'de>>(
         &mut self,
         _: &'static str,
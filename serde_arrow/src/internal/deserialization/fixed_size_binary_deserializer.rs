@@ -3,7 +3,8 @@ use serde::de::{SeqAccess, Visitor};
 use crate::internal::{
     arrow::FixedSizeBinaryArrayView,
     error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
-    utils::Mut,
+    schema::Strategy,
+    utils::{str_formats, Mut},
 };
 
 use super::{simple_deserializer::SimpleDeserializer, utils::bitset_is_set};
@@ -13,6 +14,7 @@ pub struct FixedSizeBinaryDeserializer<'a> {
     pub view: FixedSizeBinaryArrayView<'a>,
     pub next: (usize, usize),
     pub shape: (usize, usize),
+    pub strategy: Option<Strategy>,
 }
 
 impl<'a> FixedSizeBinaryDeserializer<'a> {
@@ -35,9 +37,15 @@ impl<'a> FixedSizeBinaryDeserializer<'a> {
             view,
             shape,
             next: (0, 0),
+            strategy: None,
         })
     }
 
+    pub fn with_strategy(mut self, strategy: Option<&Strategy>) -> Self {
+        self.strategy = strategy.cloned();
+        self
+    }
+
     pub fn peek_next(&self) -> Result<bool> {
         if self.next.0 >= self.shape.0 {
             fail!("Exhausted deserializer")
@@ -75,7 +83,11 @@ impl<'a> SimpleDeserializer<'a> for FixedSizeBinaryDeserializer<'a> {
     fn deserialize_any<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| {
             if self.peek_next()? {
-                self.deserialize_bytes(visitor)
+                if self.strategy.is_some() {
+                    self.deserialize_string(visitor)
+                } else {
+                    self.deserialize_bytes(visitor)
+                }
             } else {
                 self.consume_next();
                 visitor.visit_none()
@@ -107,6 +119,23 @@ impl<'a> SimpleDeserializer<'a> for FixedSizeBinaryDeserializer<'a> {
     fn deserialize_byte_buf<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| visitor.visit_borrowed_bytes(self.next_slice()?)).ctx(self)
     }
+
+    fn deserialize_str<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            let bytes = self.next_slice()?;
+            let formatted = match self.strategy {
+                Some(Strategy::UuidStrAsFixedSizeBinary) => str_formats::format_uuid(bytes)?,
+                Some(Strategy::IpStrAsFixedSizeBinary) => str_formats::format_ip_address(bytes)?,
+                _ => fail!("deserialize_string is only supported for FixedSizeBinary with a string strategy"),
+            };
+            visitor.visit_string(formatted)
+        })
+        .ctx(self)
+    }
 }
 
 impl<'de> SeqAccess<'de> for FixedSizeBinaryDeserializer<'de> {
@@ -3,6 +3,7 @@ use serde::de::Visitor;
 use crate::internal::{
     arrow::PrimitiveArrayView,
     error::{set_default, try_, Context, ContextSupport, Result},
+    schema::Strategy,
     utils::{Mut, NamedType},
 };
 
@@ -21,6 +22,7 @@ pub trait Float: Copy {
 pub struct FloatDeserializer<'a, F: Float> {
     path: String,
     array: ArrayBufferIterator<'a, F>,
+    str_as_number: bool,
 }
 
 impl<'a, F: Float> FloatDeserializer<'a, F> {
@@ -28,8 +30,14 @@ impl<'a, F: Float> FloatDeserializer<'a, F> {
         Self {
             path,
             array: ArrayBufferIterator::new(view.values, view.validity),
+            str_as_number: false,
         }
     }
+
+    pub fn with_strategy(mut self, strategy: Option<&Strategy>) -> Self {
+        self.str_as_number = matches!(strategy, Some(Strategy::StrAsNumber));
+        self
+    }
 }
 
 impl<'de, F: NamedType + Float> Context for FloatDeserializer<'de, F> {
@@ -48,11 +56,17 @@ impl<'de, F: NamedType + Float> Context for FloatDeserializer<'de, F> {
     }
 }
 
-impl<'de, F: NamedType + Float> SimpleDeserializer<'de> for FloatDeserializer<'de, F> {
+impl<'de, F: NamedType + Float + std::fmt::Display> SimpleDeserializer<'de>
+    for FloatDeserializer<'de, F>
+{
     fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| {
             if self.array.peek_next()? {
-                F::deserialize_any(&mut *self, visitor)
+                if self.str_as_number {
+                    self.deserialize_string(visitor)
+                } else {
+                    F::deserialize_any(&mut *self, visitor)
+                }
             } else {
                 self.array.consume_next();
                 visitor.visit_none()
@@ -80,4 +94,12 @@ impl<'de, F: NamedType + Float> SimpleDeserializer<'de> for FloatDeserializer<'d
     fn deserialize_f64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| visitor.visit_f64(self.array.next_required()?.into_f64()?)).ctx(self)
     }
+
+    fn deserialize_str<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_string(self.array.next_required()?.to_string())).ctx(self)
+    }
 }
@@ -3,6 +3,7 @@ use serde::de::Visitor;
 use crate::internal::{
     arrow::PrimitiveArrayView,
     error::{set_default, try_, Context, ContextSupport, Result},
+    schema::Strategy,
     utils::{Mut, NamedType},
 };
 
@@ -30,6 +31,7 @@ pub trait Integer: Sized + Copy {
 pub struct IntegerDeserializer<'a, T: Integer> {
     path: String,
     array: ArrayBufferIterator<'a, T>,
+    str_as_number: bool,
 }
 
 impl<'a, T: Integer> IntegerDeserializer<'a, T> {
@@ -37,8 +39,14 @@ impl<'a, T: Integer> IntegerDeserializer<'a, T> {
         Self {
             path,
             array: ArrayBufferIterator::new(view.values, view.validity),
+            str_as_number: false,
         }
     }
+
+    pub fn with_strategy(mut self, strategy: Option<&Strategy>) -> Self {
+        self.str_as_number = matches!(strategy, Some(Strategy::StrAsNumber));
+        self
+    }
 }
 
 impl<'de, T: NamedType + Integer> Context for IntegerDeserializer<'de, T> {
@@ -62,11 +70,17 @@ impl<'de, T: NamedType + Integer> Context for IntegerDeserializer<'de, T> {
     }
 }
 
-impl<'de, T: NamedType + Integer> SimpleDeserializer<'de> for IntegerDeserializer<'de, T> {
+impl<'de, T: NamedType + Integer + std::fmt::Display> SimpleDeserializer<'de>
+    for IntegerDeserializer<'de, T>
+{
     fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| {
             if self.array.peek_next()? {
-                T::deserialize_any(&mut *self, visitor)
+                if self.str_as_number {
+                    self.deserialize_string(visitor)
+                } else {
+                    T::deserialize_any(&mut *self, visitor)
+                }
             } else {
                 self.array.consume_next();
                 visitor.visit_none()
@@ -126,4 +140,12 @@ impl<'de, T: NamedType + Integer> SimpleDeserializer<'de> for IntegerDeserialize
     fn deserialize_i64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| visitor.visit_i64(self.array.next_required()?.into_i64()?)).ctx(self)
     }
+
+    fn deserialize_str<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_string(self.array.next_required()?.to_string())).ctx(self)
+    }
 }
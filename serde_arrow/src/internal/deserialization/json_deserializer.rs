@@ -0,0 +1,149 @@
+use serde::{de::Visitor, Deserializer};
+
+use crate::internal::{
+    arrow::BytesArrayView,
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    utils::{
+        json::{parse_json, JsonValue, JsonValueDeserializer},
+        Offset,
+    },
+};
+
+use super::{simple_deserializer::SimpleDeserializer, utils::bitset_is_set};
+
+/// Deserialize values stored as JSON text in a string or binary array
+pub struct JsonDeserializer<'a, O: Offset> {
+    pub path: String,
+    pub view: BytesArrayView<'a, O>,
+    pub next: usize,
+    pub data_type: &'static str,
+}
+
+impl<'a, O: Offset> JsonDeserializer<'a, O> {
+    pub fn new(path: String, view: BytesArrayView<'a, O>, data_type: &'static str) -> Self {
+        Self {
+            path,
+            view,
+            next: 0,
+            data_type,
+        }
+    }
+
+    pub fn next(&mut self) -> Result<JsonValue> {
+        if self.next + 1 > self.view.offsets.len() {
+            fail!("Exhausted deserializer: tried to deserialize a value from an exhausted JsonDeserializer");
+        }
+
+        if let Some(validity) = &self.view.validity {
+            if !bitset_is_set(validity, self.next)? {
+                self.next += 1;
+                return Ok(JsonValue::Null);
+            }
+        }
+
+        let start = self.view.offsets[self.next].try_into_usize()?;
+        let end = self.view.offsets[self.next + 1].try_into_usize()?;
+        let s = std::str::from_utf8(&self.view.data[start..end])?;
+
+        self.next += 1;
+
+        parse_json(s)
+    }
+}
+
+impl<'a, O: Offset> Context for JsonDeserializer<'a, O> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", self.data_type);
+    }
+}
+
+macro_rules! forward_to_json {
+    ($($method:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+                try_(|| JsonValueDeserializer(&self.next()?).$method(visitor)).ctx(self)
+            }
+        )*
+    };
+}
+
+impl<'a, O: Offset> SimpleDeserializer<'a> for JsonDeserializer<'a, O> {
+    forward_to_json!(
+        deserialize_any,
+        deserialize_ignored_any,
+        deserialize_bool,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_char,
+        deserialize_str,
+        deserialize_string,
+        deserialize_map,
+        deserialize_byte_buf,
+        deserialize_bytes,
+        deserialize_identifier,
+        deserialize_option,
+        deserialize_seq,
+        deserialize_unit,
+    );
+
+    fn deserialize_struct<V: Visitor<'a>>(
+        &mut self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        try_(|| JsonValueDeserializer(&self.next()?).deserialize_struct(name, fields, visitor))
+            .ctx(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'a>>(
+        &mut self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        try_(|| JsonValueDeserializer(&self.next()?).deserialize_enum(name, variants, visitor))
+            .ctx(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'a>>(
+        &mut self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        try_(|| JsonValueDeserializer(&self.next()?).deserialize_newtype_struct(name, visitor))
+            .ctx(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'a>>(&mut self, len: usize, visitor: V) -> Result<V::Value> {
+        try_(|| JsonValueDeserializer(&self.next()?).deserialize_tuple(len, visitor)).ctx(self)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'a>>(
+        &mut self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        try_(|| JsonValueDeserializer(&self.next()?).deserialize_tuple_struct(name, len, visitor))
+            .ctx(self)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'a>>(
+        &mut self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        try_(|| JsonValueDeserializer(&self.next()?).deserialize_unit_struct(name, visitor))
+            .ctx(self)
+    }
+}
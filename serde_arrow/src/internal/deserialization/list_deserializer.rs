@@ -23,12 +23,11 @@ pub struct ListDeserializer<'a, O: Offset> {
 impl<'a, O: Offset> ListDeserializer<'a, O> {
     pub fn new(
         path: String,
-        mut item: ArrayDeserializer<'a>,
+        item: ArrayDeserializer<'a>,
         offsets: &'a [O],
         validity: Option<BitsWithOffset<'a>>,
     ) -> Result<Self> {
         check_supported_list_layout(validity, offsets)?;
-        item.skip(offsets[0].try_into_usize()?)?;
 
         Ok(Self {
             path,
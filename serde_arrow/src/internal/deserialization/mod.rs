@@ -1,9 +1,11 @@
 pub mod array_deserializer;
 pub mod binary_deserializer;
 pub mod bool_deserializer;
+pub mod columns_deserializer;
 pub mod date32_deserializer;
 pub mod date64_deserializer;
 pub mod decimal_deserializer;
+pub mod default_deserializer;
 pub mod dictionary_deserializer;
 pub mod duration_deserializer;
 pub mod enum_deserializer;
@@ -14,6 +16,7 @@ pub mod float_deserializer;
 pub mod float_impls;
 pub mod integer_deserializer;
 pub mod integer_impls;
+pub mod json_deserializer;
 pub mod list_deserializer;
 pub mod map_deserializer;
 pub mod null_deserializer;
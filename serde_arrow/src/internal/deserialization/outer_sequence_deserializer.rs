@@ -5,23 +5,21 @@ use crate::internal::{
     utils::Mut,
 };
 
-use super::{
-    array_deserializer::ArrayDeserializer, simple_deserializer::SimpleDeserializer,
-    struct_deserializer::StructDeserializer,
-};
+use super::{simple_deserializer::SimpleDeserializer, struct_deserializer::StructDeserializer};
 
 pub struct OuterSequenceDeserializer<'a> {
-    pub item: StructDeserializer<'a>,
+    pub chunks: Vec<StructDeserializer<'a>>,
+    pub chunk: usize,
     pub next: usize,
-    pub len: usize,
 }
 
 impl<'a> OuterSequenceDeserializer<'a> {
-    pub fn new(fields: Vec<(String, ArrayDeserializer<'a>)>, len: usize) -> Self {
+    /// Deserialize the items of all chunks as a single sequence
+    pub fn from_chunks(chunks: Vec<StructDeserializer<'a>>) -> Self {
         Self {
-            item: StructDeserializer::new(String::from("$"), fields, None, len),
+            chunks,
+            chunk: 0,
             next: 0,
-            len,
         }
     }
 }
@@ -68,12 +66,19 @@ impl<'de> SeqAccess<'de> for OuterSequenceDeserializer<'de> {
         &mut self,
         seed: T,
     ) -> Result<Option<T::Value>, Self::Error> {
-        if self.next >= self.len {
-            return Ok(None);
-        }
-        self.next += 1;
+        loop {
+            let Some(chunk) = self.chunks.get_mut(self.chunk) else {
+                return Ok(None);
+            };
+            if self.next >= chunk.len {
+                self.chunk += 1;
+                self.next = 0;
+                continue;
+            }
+            self.next += 1;
 
-        let item = seed.deserialize(Mut(&mut self.item))?;
-        Ok(Some(item))
+            let item = seed.deserialize(Mut(chunk))?;
+            return Ok(Some(item));
+        }
     }
 }
@@ -5,6 +5,7 @@ use serde::de::{
 use crate::internal::{
     arrow::BitsWithOffset,
     error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
+    schema::FieldNames,
     utils::Mut,
 };
 
@@ -19,6 +20,10 @@ pub struct StructDeserializer<'a> {
     pub validity: Option<BitsWithOffset<'a>>,
     pub next: (usize, usize),
     pub len: usize,
+    pub field_names: FieldNames,
+    /// The serde names of the fields, resolved on first use if the field names
+    /// are translated
+    pub keys: Option<Vec<String>>,
 }
 
 impl<'a> StructDeserializer<'a> {
@@ -34,6 +39,46 @@ impl<'a> StructDeserializer<'a> {
             validity,
             len,
             next: (0, 0),
+            field_names: FieldNames::Unchanged,
+            keys: None,
+        }
+    }
+
+    /// Translate the Arrow field names into serde field names with the given
+    /// policy
+    pub fn with_field_names(mut self, field_names: FieldNames) -> Self {
+        self.field_names = field_names;
+        self
+    }
+
+    /// Determine the serde names of the fields
+    ///
+    /// If the serde field names are known, e.g., for structs, the Arrow field
+    /// names are matched against their translated names. Otherwise, the Arrow
+    /// field names are converted back into snake case.
+    fn resolve_keys(&mut self, serde_fields: &[&str]) {
+        if self.field_names == FieldNames::Unchanged || self.keys.is_some() {
+            return;
+        }
+        let keys = self
+            .fields
+            .iter()
+            .map(|(name, _)| {
+                for serde_field in serde_fields {
+                    if self.field_names.to_arrow(serde_field) == name.as_str() {
+                        return serde_field.to_string();
+                    }
+                }
+                self.field_names.to_serde(name).into_owned()
+            })
+            .collect();
+        self.keys = Some(keys);
+    }
+
+    fn key(&self, idx: usize) -> &str {
+        match &self.keys {
+            Some(keys) => &keys[idx],
+            None => &self.fields[idx].0,
         }
     }
 
@@ -64,6 +109,7 @@ impl<'de> SimpleDeserializer<'de> for StructDeserializer<'de> {
     fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| {
             if self.peek_next()? {
+                self.resolve_keys(&[]);
                 visitor.visit_map(&mut *self)
             } else {
                 self.consume_next();
@@ -92,16 +138,24 @@ impl<'de> SimpleDeserializer<'de> for StructDeserializer<'de> {
     }
 
     fn deserialize_map<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_map(&mut *self)).ctx(self)
+        try_(|| {
+            self.resolve_keys(&[]);
+            visitor.visit_map(&mut *self)
+        })
+        .ctx(self)
     }
 
     fn deserialize_struct<V: Visitor<'de>>(
         &mut self,
         _: &'static str,
-        _: &'static [&'static str],
+        fields: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value> {
-        try_(|| visitor.visit_map(&mut *self)).ctx(self)
+        try_(|| {
+            self.resolve_keys(fields);
+            visitor.visit_map(&mut *self)
+        })
+        .ctx(self)
     }
 
     fn deserialize_tuple<V: Visitor<'de>>(&mut self, _: usize, visitor: V) -> Result<V::Value> {
@@ -145,7 +199,7 @@ impl<'de> MapAccess<'de> for StructDeserializer<'de> {
             return Ok(None);
         }
 
-        let key = seed.deserialize(StrDeserializer::<Error>::new(&self.fields[field].0))?;
+        let key = seed.deserialize(StrDeserializer::<Error>::new(self.key(field)))?;
         Ok(Some(key))
     }
 
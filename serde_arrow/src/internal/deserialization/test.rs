@@ -8,11 +8,14 @@ use crate::internal::{
     utils::Mut,
 };
 
-use super::outer_sequence_deserializer::OuterSequenceDeserializer;
+use super::{
+    outer_sequence_deserializer::OuterSequenceDeserializer, struct_deserializer::StructDeserializer,
+};
 
 #[test]
 fn example() {
-    let mut deser = OuterSequenceDeserializer::new(
+    let mut deser = OuterSequenceDeserializer::from_chunks(vec![StructDeserializer::new(
+        String::from("$"),
         vec![
             (
                 String::from("a"),
@@ -35,8 +38,9 @@ fn example() {
                 )),
             ),
         ],
+        None,
         3,
-    );
+    )]);
 
     #[derive(Debug, PartialEq, Deserialize)]
     struct Record {
@@ -1,52 +1,538 @@
-use serde::de::Visitor;
+use std::{collections::HashSet, ops::Range};
+
+use serde::{de::Visitor, Deserialize};
 
 use crate::internal::{
-    arrow::{ArrayView, Field},
+    arrow::{ArrayView, DataType, Field, FieldMeta},
     deserialization::{
         array_deserializer::ArrayDeserializer,
+        columns_deserializer::{ColumnDeserializer, ColumnsDeserializer},
+        default_deserializer::DefaultDeserializer,
+        null_deserializer::NullDeserializer,
         outer_sequence_deserializer::OuterSequenceDeserializer,
+        struct_deserializer::StructDeserializer,
     },
     error::{fail, Error, Result},
-    schema::get_strategy_from_metadata,
-    utils::array_view_ext::ArrayViewExt,
+    schema::{
+        get_strategy_from_metadata, tracer::Tracer, FieldNames, SerdeArrowSchema, TracingOptions,
+        DEFAULT_VALUE_KEY,
+    },
+    utils::{array_view_ext::ArrayViewExt, ChildName, Mut},
 };
 
-use super::utils::ChildName;
-
 /// A structure to deserialize Arrow arrays into Rust objects
 ///
 #[cfg_attr(any(has_arrow, has_arrow2), doc = r"It can be constructed via")]
 #[cfg_attr(any(has_arrow, has_arrow2), doc = r"")]
 #[cfg_attr(has_arrow, doc = r"- [`Deserializer::from_record_batch`]")]
+#[cfg_attr(has_arrow, doc = r"- [`Deserializer::from_record_batch_range`]")]
+#[cfg_attr(has_arrow, doc = r"- [`Deserializer::from_record_batches`]")]
 #[cfg_attr(has_arrow, doc = r"- [`Deserializer::from_arrow`]")]
+#[cfg_attr(has_arrow, doc = r"- [`Deserializer::from_arrow_range`]")]
 #[cfg_attr(has_arrow2, doc = r"- [`Deserializer::from_arrow2`]")]
-pub struct Deserializer<'de>(pub(crate) OuterSequenceDeserializer<'de>);
+#[cfg_attr(has_arrow2, doc = r"- [`Deserializer::from_arrow2_range`]")]
+///
+/// Single rows can be deserialized with [`Deserializer::get`]. To skip the
+/// columns not used by the record type, use [`Deserializer::project`]. To
+/// deserialize the arrays column-wise, use [`Deserializer::deserialize_columns`].
+pub struct Deserializer<'de> {
+    pub(crate) fields: Vec<Field>,
+    pub(crate) chunks: Vec<Vec<ArrayView<'de>>>,
+    /// The expected fields, if given, used to fill missing columns
+    pub(crate) expected: Option<Vec<Field>>,
+    /// How to translate the Arrow field names into serde field names
+    pub(crate) field_names: FieldNames,
+}
 
 impl<'de> Deserializer<'de> {
     pub(crate) fn new(fields: &[Field], views: Vec<ArrayView<'de>>) -> Result<Self> {
-        let len = match views.first() {
-            Some(view) => view.len(),
-            None => 0,
-        };
+        Self::new_chunked(fields, vec![views])
+    }
+
+    /// Construct a deserializer that deserializes the chunks of arrays one
+    /// after the other as a single sequence
+    pub(crate) fn new_chunked(fields: &[Field], chunks: Vec<Vec<ArrayView<'de>>>) -> Result<Self> {
+        for views in &chunks {
+            get_common_len(views)?;
+        }
+
+        Ok(Deserializer {
+            fields: fields.to_vec(),
+            chunks,
+            expected: None,
+            field_names: FieldNames::Unchanged,
+        })
+    }
+
+    /// Deserialize the arrays as if they followed the given schema
+    ///
+    /// Fields of the schema that are missing from the arrays, including
+    /// children of struct fields, are filled with virtual columns: nullable
+    /// fields are filled with nulls, non-nullable fields with the zero value of
+    /// their data type (`0`, `""`, empty lists, ...). A custom default can be
+    /// stored in the field metadata under
+    /// [`DEFAULT_VALUE_KEY`][crate::schema::DEFAULT_VALUE_KEY]. This way
+    /// arrays written with an older schema can be read into newer record
+    /// types. Columns not part of the schema are kept as is.
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # let record_batch = serde_arrow::_impl::docs::defs::example_record_batch();
+    /// #
+    /// use serde::Deserialize;
+    /// use serde_arrow::{
+    ///     Deserializer,
+    ///     schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    /// };
+    ///
+    /// // the record batch only contains the columns `a` and `b`
+    /// ##[derive(Deserialize)]
+    /// struct Record {
+    ///     a: Option<f32>,
+    ///     b: u64,
+    ///     c: Option<String>,
+    ///     d: u32,
+    /// }
+    ///
+    /// let schema = SerdeArrowSchema::from_type::<Record>(TracingOptions::default())?;
+    /// let deserializer = Deserializer::from_record_batch(&record_batch)?.with_schema(&schema)?;
+    /// let items = Vec::<Record>::deserialize(deserializer)?;
+    ///
+    /// assert_eq!(items[0].c, None);
+    /// assert_eq!(items[0].d, 0);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub fn with_schema(self, schema: &SerdeArrowSchema) -> Result<Self> {
+        Ok(Deserializer {
+            expected: Some(schema.fields.clone()),
+            ..self
+        })
+    }
+
+    /// Translate the Arrow field names into serde field names with the given
+    /// policy
+    ///
+    /// Use the same policy as in
+    /// [`TracingOptions::field_names`][crate::schema::TracingOptions::field_names]
+    /// when tracing the schema. For structs, the Arrow field names are matched
+    /// against the translated serde field names. For maps, the Arrow field
+    /// names are converted back into snake case.
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::datatypes::FieldRef;
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_arrow::{
+    ///     schema::{FieldNames, SchemaLike, TracingOptions},
+    ///     ArrayBuilder, Deserializer,
+    /// };
+    ///
+    /// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+    /// struct Record {
+    ///     user_id: u64,
+    /// }
+    ///
+    /// let options = TracingOptions::default().field_names(FieldNames::CamelCase);
+    /// let fields = Vec::<FieldRef>::from_type::<Record>(options)?;
+    ///
+    /// let mut builder = ArrayBuilder::from_arrow(&fields)?.with_field_names(FieldNames::CamelCase)?;
+    /// builder.push(Record { user_id: 42 })?;
+    /// let batch = builder.to_record_batch()?;
+    /// assert_eq!(batch.schema().field(0).name(), "userId");
+    ///
+    /// let deserializer =
+    ///     Deserializer::from_record_batch(&batch)?.with_field_names(FieldNames::CamelCase);
+    /// let items = Vec::<Record>::deserialize(deserializer)?;
+    /// assert_eq!(items, vec![Record { user_id: 42 }]);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub fn with_field_names(self, field_names: FieldNames) -> Self {
+        Deserializer {
+            field_names,
+            ..self
+        }
+    }
 
-        let mut deserializers = Vec::new();
-        for (field, view) in std::iter::zip(fields, views) {
-            if view.len() != len {
-                fail!("Cannot deserialize from arrays with different lengths");
+    /// Restrict the deserializer to the columns used by the record type `T`
+    ///
+    /// The columns of `T` are determined by tracing the type as in
+    /// [`SchemaLike::from_type`][crate::schema::SchemaLike::from_type]. Only
+    /// the matching columns are deserialized, all other columns are skipped
+    /// without any per-row overhead. Columns of `T` that are not present in
+    /// the arrays are ignored, e.g., to be filled by `#[serde(default)]` or as
+    /// `None` for optional fields.
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # let record_batch = serde_arrow::_impl::docs::defs::example_record_batch();
+    /// #
+    /// use serde::Deserialize;
+    /// use serde_arrow::{Deserializer, schema::TracingOptions};
+    ///
+    /// // only use the column `b` of the record batch
+    /// ##[derive(Deserialize)]
+    /// struct Record {
+    ///     b: u64,
+    /// }
+    ///
+    /// let deserializer = Deserializer::from_record_batch(&record_batch)?
+    ///     .project::<Record>(TracingOptions::default())?;
+    /// let items = Vec::<Record>::deserialize(deserializer)?;
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub fn project<T: Deserialize<'de>>(self, options: TracingOptions) -> Result<Self> {
+        let tracer = Tracer::from_type::<T>(options.clone())?;
+        let Tracer::Struct(tracer) = tracer else {
+            fail!(
+                "Cannot project columns for non-struct records of type {ty}",
+                ty = tracer.get_type().unwrap_or("unknown"),
+            );
+        };
+        let columns = tracer
+            .fields
+            .iter()
+            .map(|field| options.field_names.to_arrow(&field.name).into_owned())
+            .collect::<HashSet<_>>();
+
+        let selected = self
+            .fields
+            .iter()
+            .map(|field| columns.contains(field.name.as_str()))
+            .collect::<Vec<_>>();
+
+        let fields = std::iter::zip(self.fields, &selected)
+            .filter(|(_, selected)| **selected)
+            .map(|(field, _)| field)
+            .collect();
+        let chunks = self
+            .chunks
+            .into_iter()
+            .map(|views| {
+                std::iter::zip(views, &selected)
+                    .filter(|(_, selected)| **selected)
+                    .map(|(view, _)| view)
+                    .collect()
+            })
+            .collect();
+
+        let expected = self.expected.map(|expected| {
+            expected
+                .into_iter()
+                .filter(|field| columns.contains(field.name.as_str()))
+                .collect()
+        });
+
+        Ok(Deserializer {
+            fields,
+            chunks,
+            expected,
+            field_names: self.field_names,
+        })
+    }
+
+    /// Deserialize the arrays column-wise into a struct of sequences
+    ///
+    /// Each field of `T` is filled with the values of the column of the same
+    /// name, e.g., `struct Columns { a: Vec<f32>, b: Vec<i32> }`. Columns can
+    /// be skipped and missing columns are handled as for structs, e.g., by
+    /// `#[serde(default)]`. Non-nullable primitive columns can be borrowed
+    /// without copying via [`Slice`][crate::utils::Slice].
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # let record_batch = serde_arrow::_impl::docs::defs::example_record_batch();
+    /// #
+    /// use serde::Deserialize;
+    /// use serde_arrow::Deserializer;
+    ///
+    /// ##[derive(Deserialize)]
+    /// struct Columns {
+    ///     a: Vec<Option<f32>>,
+    ///     b: Vec<u64>,
+    /// }
+    ///
+    /// let columns: Columns = Deserializer::from_record_batch(&record_batch)?.deserialize_columns()?;
+    /// assert_eq!(columns.a.len(), columns.b.len());
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub fn deserialize_columns<T: Deserialize<'de>>(self) -> Result<T> {
+        let mut names = Vec::new();
+        for field in self.expected.iter().flatten().chain(&self.fields) {
+            if !names.contains(&field.name) {
+                names.push(field.name.clone());
             }
-            let strategy = get_strategy_from_metadata(&field.metadata)?;
-            let deserializer = ArrayDeserializer::new(
-                format!("$.{child}", child = ChildName(&field.name)),
-                strategy.as_ref(),
-                view,
+        }
+
+        let mut columns = Vec::new();
+        for name in names {
+            let views = match self.fields.iter().position(|field| field.name == name) {
+                Some(pos) => self.chunks.iter().map(|views| views[pos].clone()).collect(),
+                None => Vec::new(),
+            };
+            columns.push(ColumnDeserializer {
+                path: format!("$.{child}", child = ChildName(&name)),
+                name: self.field_names.to_serde(&name).into_owned(),
+                chunks: Vec::new(),
+                views,
+            });
+        }
+
+        for views in self.chunks {
+            let deserializer = build_struct_deserializer(
+                &self.fields,
+                views,
+                self.expected.as_deref(),
+                self.field_names,
             )?;
-            deserializers.push((field.name.clone(), deserializer));
+            for (column, (_, field)) in std::iter::zip(&mut columns, deserializer.fields) {
+                column.chunks.push((field, deserializer.len));
+            }
         }
 
-        let deserializer = OuterSequenceDeserializer::new(deserializers, len);
-        let deserializer = Deserializer(deserializer);
+        T::deserialize(ColumnsDeserializer::new(columns))
+    }
+
+    /// Construct a deserializer for the rows in `range`
+    ///
+    /// The array deserializers are positioned at the start of the range
+    /// without deserializing any of the preceding rows.
+    pub(crate) fn new_range(
+        fields: &[Field],
+        views: Vec<ArrayView<'de>>,
+        range: Range<usize>,
+    ) -> Result<Self> {
+        let len = get_common_len(&views)?;
+        if range.start > range.end || range.end > len {
+            fail!(
+                "Invalid range: {start}..{end} is out of bounds for arrays of length {len}",
+                start = range.start,
+                end = range.end,
+            );
+        }
+
+        let views = views
+            .iter()
+            .map(|view| view.slice(range.start, range.end - range.start))
+            .collect::<Result<Vec<_>>>()?;
+        Self::new(fields, views)
+    }
+
+    /// Deserialize the single row `idx`
+    ///
+    /// Only the requested row is deserialized, independent of how many rows
+    /// have been deserialized before. Each row is deserialized as a struct.
+    /// To deserialize rows encoding a single value, consider the
+    /// [`Item`][crate::utils::Item] wrapper.
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::datatypes::FieldRef;
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_arrow::{Deserializer, schema::{SchemaLike, TracingOptions}};
+    ///
+    /// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+    /// struct Record {
+    ///     a: Option<f32>,
+    ///     b: u64,
+    /// }
+    ///
+    /// let items = vec![
+    ///     Record { a: Some(1.0), b: 2 },
+    ///     Record { a: None, b: 4 },
+    ///     Record { a: Some(5.0), b: 6 },
+    /// ];
+    ///
+    /// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+    /// let arrays = serde_arrow::to_arrow(&fields, &items)?;
+    ///
+    /// let deserializer = Deserializer::from_arrow(&fields, &arrays)?;
+    /// let item: Record = deserializer.get(1)?;
+    /// assert_eq!(item, Record { a: None, b: 4 });
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub fn get<T: Deserialize<'de>>(&self, idx: usize) -> Result<T> {
+        let mut offset = idx;
+        for views in &self.chunks {
+            let len = get_common_len(views)?;
+            if offset >= len {
+                offset -= len;
+                continue;
+            }
 
-        Ok(deserializer)
+            return deserialize_row(
+                &self.fields,
+                views,
+                self.expected.as_deref(),
+                self.field_names,
+                offset,
+            );
+        }
+        fail!(
+            "Invalid index: row {idx} is out of bounds for arrays of length {len}",
+            len = idx - offset,
+        );
+    }
+}
+
+/// Deserialize the single row `idx` of the given views
+pub(crate) fn deserialize_row<'de, T: Deserialize<'de>>(
+    fields: &[Field],
+    views: &[ArrayView<'de>],
+    expected: Option<&[Field]>,
+    field_names: FieldNames,
+    idx: usize,
+) -> Result<T> {
+    let views = views
+        .iter()
+        .map(|view| view.slice(idx, 1))
+        .collect::<Result<Vec<_>>>()?;
+    let mut deserializer = build_struct_deserializer(fields, views, expected, field_names)?;
+    T::deserialize(Mut(&mut deserializer))
+}
+
+fn get_common_len(views: &[ArrayView<'_>]) -> Result<usize> {
+    let len = match views.first() {
+        Some(view) => view.len(),
+        None => 0,
+    };
+    for view in views {
+        if view.len() != len {
+            fail!("Cannot deserialize from arrays with different lengths");
+        }
+    }
+    Ok(len)
+}
+
+fn build_sequence_deserializer(
+    deserializer: Deserializer<'_>,
+) -> Result<OuterSequenceDeserializer<'_>> {
+    let mut chunks = Vec::new();
+    for views in deserializer.chunks {
+        chunks.push(build_struct_deserializer(
+            &deserializer.fields,
+            views,
+            deserializer.expected.as_deref(),
+            deserializer.field_names,
+        )?);
+    }
+    Ok(OuterSequenceDeserializer::from_chunks(chunks))
+}
+
+fn build_struct_deserializer<'de>(
+    fields: &[Field],
+    views: Vec<ArrayView<'de>>,
+    expected: Option<&[Field]>,
+    field_names: FieldNames,
+) -> Result<StructDeserializer<'de>> {
+    let len = get_common_len(&views)?;
+    let children = std::iter::zip(views, fields)
+        .map(|(view, field)| {
+            let meta = FieldMeta {
+                name: field.name.clone(),
+                nullable: field.nullable,
+                metadata: field.metadata.clone(),
+            };
+            (view, meta)
+        })
+        .collect();
+
+    Ok(StructDeserializer::new(
+        String::from("$"),
+        build_field_deserializers("$", children, expected, field_names)?,
+        None,
+        len,
+    )
+    .with_field_names(field_names))
+}
+
+/// Build the deserializers of struct fields, filling the fields missing from
+/// `children` if the `expected` fields are given
+fn build_field_deserializers<'de>(
+    path: &str,
+    children: Vec<(ArrayView<'de>, FieldMeta)>,
+    expected: Option<&[Field]>,
+    field_names: FieldNames,
+) -> Result<Vec<(String, ArrayDeserializer<'de>)>> {
+    let mut children = children.into_iter().map(Some).collect::<Vec<_>>();
+    let mut deserializers = Vec::new();
+
+    for expected_field in expected.unwrap_or_default() {
+        let child_path = format!("{path}.{child}", child = ChildName(&expected_field.name));
+        let pos = children
+            .iter()
+            .position(|child| matches!(child, Some((_, meta)) if meta.name == expected_field.name));
+
+        let deserializer = match pos.and_then(|pos| children[pos].take()) {
+            Some((view, meta)) => {
+                build_evolved_deserializer(child_path, expected_field, view, &meta, field_names)?
+            }
+            None if expected_field.nullable
+                && !expected_field.metadata.contains_key(DEFAULT_VALUE_KEY) =>
+            {
+                ArrayDeserializer::Null(NullDeserializer::new(child_path))
+            }
+            None => {
+                ArrayDeserializer::Default(DefaultDeserializer::new(child_path, expected_field)?)
+            }
+        };
+        deserializers.push((expected_field.name.clone(), deserializer));
+    }
+
+    for (view, meta) in children.into_iter().flatten() {
+        let child_path = format!("{path}.{child}", child = ChildName(&meta.name));
+        let strategy = get_strategy_from_metadata(&meta.metadata)?;
+        let deserializer =
+            ArrayDeserializer::new(child_path, strategy.as_ref(), view, field_names)?;
+        deserializers.push((meta.name, deserializer));
+    }
+
+    Ok(deserializers)
+}
+
+fn build_evolved_deserializer<'de>(
+    path: String,
+    expected: &Field,
+    view: ArrayView<'de>,
+    meta: &FieldMeta,
+    field_names: FieldNames,
+) -> Result<ArrayDeserializer<'de>> {
+    let strategy = get_strategy_from_metadata(&meta.metadata)?;
+    match (&expected.data_type, view) {
+        (DataType::Struct(expected_children), ArrayView::Struct(view)) if strategy.is_none() => {
+            let fields = build_field_deserializers(
+                &path,
+                view.fields,
+                Some(expected_children),
+                field_names,
+            )?;
+            Ok(ArrayDeserializer::Struct(
+                StructDeserializer::new(path, fields, view.validity, view.len)
+                    .with_field_names(field_names),
+            ))
+        }
+        (_, view) => ArrayDeserializer::new(path, strategy.as_ref(), view, field_names),
     }
 }
 
@@ -62,11 +548,11 @@ impl<'de> serde::de::Deserializer<'de> for Deserializer<'de> {
     }
 
     fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        visitor.visit_seq(self.0)
+        visitor.visit_seq(build_sequence_deserializer(self)?)
     }
 
     fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
-        visitor.visit_seq(self.0)
+        visitor.visit_seq(build_sequence_deserializer(self)?)
     }
 
     fn deserialize_tuple_struct<V: Visitor<'de>>(
@@ -75,7 +561,7 @@ impl<'de> serde::de::Deserializer<'de> for Deserializer<'de> {
         _len: usize,
         visitor: V,
     ) -> Result<V::Value> {
-        visitor.visit_seq(self.0)
+        visitor.visit_seq(build_sequence_deserializer(self)?)
     }
 
     fn deserialize_newtype_struct<V: Visitor<'de>>(
@@ -202,6 +688,7 @@ impl<'de> serde::de::Deserializer<'de> for Deserializer<'de> {
 }
 
 const _: () = {
+    #[allow(dead_code)]
     trait AssertSendSync: Send + Sync {}
     impl<'de> AssertSendSync for Deserializer<'de> {}
 };
@@ -291,6 +291,18 @@ impl From<std::num::ParseIntError> for Error {
     }
 }
 
+impl From<std::num::ParseFloatError> for Error {
+    fn from(err: std::num::ParseFloatError) -> Self {
+        Self::custom_from(format!("ParseFloatError: {err}"), err)
+    }
+}
+
+impl From<std::str::ParseBoolError> for Error {
+    fn from(err: std::str::ParseBoolError) -> Self {
+        Self::custom_from(format!("ParseBoolError: {err}"), err)
+    }
+}
+
 impl From<std::fmt::Error> for Error {
     fn from(err: std::fmt::Error) -> Self {
         Self::custom_from(format!("std::fmt::Error: {err}"), err)
@@ -338,6 +350,7 @@ fn error_can_be_converted_to_anyhow() {
 }
 
 const _: () = {
+    #[allow(dead_code)]
     trait AssertSendSync: Send + Sync {}
     impl AssertSendSync for Error {}
     impl<T: Send + Sync> AssertSendSync for Result<T> {}
@@ -0,0 +1,339 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::internal::{
+    arrow::{DataType, Field},
+    error::{fail, set_default, Context, ContextSupport, Result},
+    schema::{
+        field_names::split_words, get_strategy_from_metadata, DataTypeDisplay, FieldNames,
+        SerdeArrowSchema, Strategy,
+    },
+};
+
+const DERIVES: &str = "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]";
+
+impl SerdeArrowSchema {
+    /// Generate Rust types matching the schema
+    ///
+    /// The result is Rust source code that defines a record type called
+    /// `name` and all nested types. All types derive `Serialize` and
+    /// `Deserialize` and can be used to (de)serialize arrays with the schema.
+    /// The types are mapped as follows:
+    ///
+    /// - nullable fields: `Option<T>`
+    /// - `Struct`: a separate struct named after the path of the field,
+    ///   structs with the `TupleAsStruct` strategy are mapped to tuples
+    /// - `List`, `LargeList`, `FixedSizeList`: `Vec<T>`
+    /// - `Map`: `HashMap<K, V>`, or `BTreeMap<K, V>` for sorted maps
+    /// - `Union`: an enum with one variant per child
+    /// - `Date32`, `Time32`, `Time64`: `chrono::NaiveDate`, `chrono::NaiveTime`
+    /// - `Timestamp`: `chrono::DateTime<Utc>` with a timezone, otherwise
+    ///   `chrono::NaiveDateTime`
+    /// - `Date64`: according to its strategy, `i64` without strategy
+    /// - `Decimal128`: `String`
+    ///
+    /// Field names that are not valid Rust identifiers are renamed via
+    /// `#[serde(rename = "...")]`. If the schema consists of a single field
+    /// called `"item"`, the record type is a type alias for
+    /// [`Item`][crate::utils::Item] of the item type, collections of records
+    /// are then (de)serialized via [`Items`][crate::utils::Items].
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_json::json;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let schema = SerdeArrowSchema::from_value(json!([
+    ///     {"name": "id", "data_type": "U64"},
+    ///     {"name": "createdAt", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+    ///     {"name": "comment", "data_type": "LargeUtf8", "nullable": true},
+    /// ]))?;
+    ///
+    /// let source = schema.generate_rust_types("Record")?;
+    /// assert!(source.contains("pub struct Record {"));
+    /// assert!(source.contains("#[serde(rename = \"createdAt\")]"));
+    /// assert!(source.contains("pub created_at: chrono::DateTime<chrono::Utc>,"));
+    /// assert!(source.contains("pub comment: Option<String>,"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn generate_rust_types(&self, name: &str) -> Result<String> {
+        let mut generator = Generator::default();
+        let root_name = generator.type_name(name);
+
+        let root = match self.fields.as_slice() {
+            [field] if field.name == "item" => {
+                let item_type = generator.field_type(&format!("{root_name}_item"), field)?;
+                format!("pub type {root_name} = serde_arrow::utils::Item<{item_type}>;\n")
+            }
+            fields => generator.struct_definition(&root_name, fields)?,
+        };
+
+        let mut source = String::from("use serde::{Deserialize, Serialize};\n\n");
+        source.push_str(&root);
+        for definition in &generator.definitions {
+            source.push('\n');
+            source.push_str(definition);
+        }
+        Ok(source)
+    }
+}
+
+struct CodegenContext<'a> {
+    path: &'a str,
+}
+
+impl<'a> Context for CodegenContext<'a> {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", self.path);
+    }
+}
+
+#[derive(Default)]
+struct Generator {
+    definitions: Vec<String>,
+    used_names: HashSet<String>,
+}
+
+impl Generator {
+    /// Reserve a unique type name
+    fn type_name(&mut self, name: &str) -> String {
+        let base = match to_pascal_case(name) {
+            name if name.is_empty() => String::from("Type"),
+            name if name.starts_with(|c: char| c.is_ascii_digit()) => format!("Type{name}"),
+            name => name,
+        };
+
+        let mut name = base.clone();
+        let mut counter = 1;
+        while self.used_names.contains(&name) {
+            counter += 1;
+            name = format!("{base}{counter}");
+        }
+        self.used_names.insert(name.clone());
+        name
+    }
+
+    /// Reserve the position of a definition to emit parents before their children
+    fn reserve_definition(&mut self) -> usize {
+        self.definitions.push(String::new());
+        self.definitions.len() - 1
+    }
+
+    fn struct_definition(&mut self, name: &str, fields: &[Field]) -> Result<String> {
+        let mut res = format!("{DERIVES}\npub struct {name} {{\n");
+        let mut used_idents = HashSet::new();
+        for field in fields {
+            let ident = unique_ident(&mut used_idents, to_field_ident(&field.name));
+            let ty = self
+                .field_type(&format!("{name}_{ident}"), field)
+                .ctx(&CodegenContext { path: &field.name })?;
+
+            if unraw(&ident) != field.name {
+                res.push_str(&format!("    #[serde(rename = {:?})]\n", field.name));
+            }
+            res.push_str(&format!("    pub {ident}: {ty},\n"));
+        }
+        res.push_str("}\n");
+        Ok(res)
+    }
+
+    fn enum_definition(&mut self, name: &str, variants: &[(i8, Field)]) -> Result<String> {
+        let mut res = format!("{DERIVES}\npub enum {name} {{\n");
+        let mut used_idents = HashSet::new();
+        for (idx, (_, variant)) in variants.iter().enumerate() {
+            let strategy = get_strategy_from_metadata(&variant.metadata)?;
+            if matches!(strategy, Some(Strategy::UnknownVariant)) {
+                let ident = unique_ident(&mut used_idents, format!("Unknown{idx}"));
+                res.push_str(&format!("    #[serde(skip)]\n    {ident},\n"));
+                continue;
+            }
+
+            let ident = unique_ident(&mut used_idents, to_variant_ident(&variant.name));
+            if ident != variant.name {
+                res.push_str(&format!("    #[serde(rename = {:?})]\n", variant.name));
+            }
+            if matches!(variant.data_type, DataType::Null) {
+                res.push_str(&format!("    {ident},\n"));
+            } else {
+                let ty = self.data_type(&format!("{name}_{ident}"), variant)?;
+                res.push_str(&format!("    {ident}({ty}),\n"));
+            }
+        }
+        res.push_str("}\n");
+        Ok(res)
+    }
+
+    fn field_type(&mut self, name: &str, field: &Field) -> Result<String> {
+        let ty = self.data_type(name, field)?;
+        if field.nullable && !matches!(field.data_type, DataType::Null) {
+            Ok(format!("Option<{ty}>"))
+        } else {
+            Ok(ty)
+        }
+    }
+
+    fn data_type(&mut self, name: &str, field: &Field) -> Result<String> {
+        use DataType as T;
+
+        let strategy = get_strategy_from_metadata(&field.metadata)?;
+        let ty = match (&field.data_type, strategy) {
+            (T::Null, _) => String::from("()"),
+            (T::Boolean, _) => String::from("bool"),
+            (T::Int8, _) => String::from("i8"),
+            (T::Int16, _) => String::from("i16"),
+            (T::Int32, _) => String::from("i32"),
+            (T::Int64, _) => String::from("i64"),
+            (T::UInt8, _) => String::from("u8"),
+            (T::UInt16, _) => String::from("u16"),
+            (T::UInt32, _) => String::from("u32"),
+            (T::UInt64, _) => String::from("u64"),
+            (T::Float16 | T::Float32, _) => String::from("f32"),
+            (T::Float64, _) => String::from("f64"),
+            (T::Utf8 | T::LargeUtf8 | T::Decimal128(_, _), _) => String::from("String"),
+            (
+                T::FixedSizeBinary(_),
+                Some(Strategy::UuidStrAsFixedSizeBinary | Strategy::IpStrAsFixedSizeBinary),
+            ) => String::from("String"),
+            (T::Binary | T::LargeBinary | T::FixedSizeBinary(_), _) => String::from("Vec<u8>"),
+            (T::Date32, _) => String::from("chrono::NaiveDate"),
+            (T::Date64, Some(Strategy::UtcStrAsDate64)) => {
+                String::from("chrono::DateTime<chrono::Utc>")
+            }
+            (T::Date64, Some(Strategy::NaiveStrAsDate64)) => String::from("chrono::NaiveDateTime"),
+            (T::Date64, _) => String::from("i64"),
+            (T::Timestamp(_, Some(_)), _) => String::from("chrono::DateTime<chrono::Utc>"),
+            (T::Timestamp(_, None), _) => String::from("chrono::NaiveDateTime"),
+            (T::Time32(_) | T::Time64(_), _) => String::from("chrono::NaiveTime"),
+            (T::Duration(_), _) => String::from("i64"),
+            (T::Dictionary(_, value, _), _) => match value.as_ref() {
+                T::Utf8 | T::LargeUtf8 => String::from("String"),
+                value => fail!(
+                    "Cannot generate a Rust type for dictionaries with values of type {}",
+                    DataTypeDisplay(value),
+                ),
+            },
+            (T::Struct(children), Some(Strategy::TupleAsStruct)) => {
+                let mut types = Vec::new();
+                for child in children {
+                    types.push(self.field_type(&format!("{name}_{}", child.name), child)?);
+                }
+                match types.as_slice() {
+                    [ty] => format!("({ty},)"),
+                    types => format!("({})", types.join(", ")),
+                }
+            }
+            (T::Struct(children), _) => {
+                let type_name = self.type_name(name);
+                let slot = self.reserve_definition();
+                self.definitions[slot] = self.struct_definition(&type_name, children)?;
+                type_name
+            }
+            (T::List(item) | T::LargeList(item) | T::FixedSizeList(item, _), _) => {
+                format!("Vec<{}>", self.field_type(name, item)?)
+            }
+            (T::Map(entries, sorted), _) => {
+                let T::Struct(children) = &entries.data_type else {
+                    fail!("Invalid map: entries must be a struct");
+                };
+                let [key, value] = children.as_slice() else {
+                    fail!("Invalid map: entries must have two fields");
+                };
+                let key = self.field_type(&format!("{name}_key"), key)?;
+                let value = self.field_type(&format!("{name}_value"), value)?;
+                if *sorted {
+                    format!("std::collections::BTreeMap<{key}, {value}>")
+                } else {
+                    format!("std::collections::HashMap<{key}, {value}>")
+                }
+            }
+            (T::Union(variants, _), _) => {
+                let type_name = self.type_name(name);
+                let slot = self.reserve_definition();
+                self.definitions[slot] = self.enum_definition(&type_name, variants)?;
+                type_name
+            }
+        };
+        Ok(ty)
+    }
+}
+
+fn unique_ident(used: &mut HashSet<String>, ident: String) -> String {
+    let mut candidate = ident.clone();
+    let mut counter = 1;
+    while used.contains(&candidate) {
+        counter += 1;
+        candidate = format!("{ident}_{counter}");
+    }
+    used.insert(candidate.clone());
+    candidate
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while",
+    "abstract", "become", "box", "do", "final", "gen", "macro", "override", "priv", "try",
+    "typeof", "unsized", "virtual", "yield",
+];
+
+/// Keywords that cannot be used as raw identifiers
+const RESERVED: &[&str] = &["crate", "self", "Self", "super", "_"];
+
+fn unraw(ident: &str) -> &str {
+    ident.strip_prefix("r#").unwrap_or(ident)
+}
+
+fn to_pascal_case(name: &str) -> String {
+    FieldNames::PascalCase
+        .to_arrow(&split_words(name).join("_"))
+        .into_owned()
+}
+
+fn to_field_ident(name: &str) -> String {
+    let ident = split_words(name).join("_");
+    let ident = if ident.is_empty() {
+        String::from("field")
+    } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("field_{ident}")
+    } else {
+        ident
+    };
+
+    if KEYWORDS.contains(&ident.as_str()) {
+        format!("r#{ident}")
+    } else if RESERVED.contains(&ident.as_str()) {
+        format!("{ident}_")
+    } else {
+        ident
+    }
+}
+
+fn to_variant_ident(name: &str) -> String {
+    let ident = to_pascal_case(name);
+    if ident.is_empty() {
+        String::from("Variant")
+    } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Variant{ident}")
+    } else if RESERVED.contains(&ident.as_str()) {
+        format!("{ident}_")
+    } else {
+        ident
+    }
+}
+
+#[test]
+fn test_identifiers() {
+    assert_eq!(to_field_ident("createdAt"), "created_at");
+    assert_eq!(to_field_ident("created_at"), "created_at");
+    assert_eq!(to_field_ident("Created At"), "created_at");
+    assert_eq!(to_field_ident("type"), "r#type");
+    assert_eq!(to_field_ident("self"), "self_");
+    assert_eq!(to_field_ident("0"), "field_0");
+    assert_eq!(to_field_ident(""), "field");
+    assert_eq!(to_field_ident("HTTPStatus"), "httpstatus");
+
+    assert_eq!(to_pascal_case("record_items"), "RecordItems");
+    assert_eq!(to_pascal_case("Record_createdAt"), "RecordCreatedAt");
+    assert_eq!(to_variant_ident("first-variant"), "FirstVariant");
+    assert_eq!(to_variant_ident("Self"), "Self_");
+}
@@ -0,0 +1,347 @@
+//! Support for `#[derive(ArrowSchema)]`
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    rc::Rc,
+    sync::Arc,
+};
+
+use crate::internal::{
+    arrow::{DataType, Field, UnionMode},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    schema::{
+        serde::deserialize::{build_data_type, merge_strategy_with_metadata},
+        tracer::unknown_variant_field,
+        validate_field, FieldNames, SerdeArrowSchema, Strategy, STRATEGY_KEY,
+    },
+};
+
+/// Types with an Arrow schema known at compile time
+///
+/// This trait is implemented by `#[derive(ArrowSchema)]` for structs with named
+/// fields (*requires the `derive` feature*). The schema is determined from the
+/// type definition alone and honors serde attributes that change the
+/// serialized layout, e.g., `rename`, `rename_all`, `skip`, `flatten` or
+/// `transparent`. Internally tagged, adjacently tagged and untagged enums are
+/// not supported.
+///
+/// The data type of a field can be overwritten with the `#[arrow(data_type =
+/// "...")]` attribute using the data type names of
+/// [`SchemaLike::from_value`][crate::schema::SchemaLike::from_value]. A
+/// strategy can be attached with `#[arrow(strategy = "...")]`. Both are
+/// required for fields whose serialization does not follow from their type,
+/// e.g., fields using `#[serde(with = "...")]`, or for types that do not
+/// implement the derive support, e.g., chrono's types.
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # fn main() -> serde_arrow::Result<()> {
+/// use serde::Serialize;
+/// use serde_arrow::schema::{ArrowSchema, SchemaLike, SerdeArrowSchema};
+/// use serde_json::json;
+///
+/// #[derive(Serialize, ArrowSchema)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Record {
+///     record_id: u64,
+///     #[arrow(data_type = "Date64", strategy = "NaiveStrAsDate64")]
+///     created_at: String,
+///     tags: Vec<String>,
+///     comment: Option<String>,
+/// }
+///
+/// let schema = Record::arrow_schema()?;
+/// let expected = SerdeArrowSchema::from_value(json!([
+///     {"name": "recordId", "data_type": "U64"},
+///     {"name": "createdAt", "data_type": "Date64", "strategy": "NaiveStrAsDate64"},
+///     {"name": "tags", "data_type": "LargeList", "children": [
+///         {"name": "element", "data_type": "LargeUtf8"},
+///     ]},
+///     {"name": "comment", "data_type": "LargeUtf8", "nullable": true},
+/// ]))?;
+/// assert_eq!(schema, expected);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "derive"))]
+/// # fn main() {}
+/// ```
+///
+/// The data type and strategy strings are checked at compile time. Only
+/// non-nested data types can be given, as the children of nested types follow
+/// from the field type:
+///
+/// ```rust,compile_fail
+/// use serde::Serialize;
+/// use serde_arrow::schema::ArrowSchema;
+///
+/// #[derive(Serialize, ArrowSchema)]
+/// struct Record {
+///     #[arrow(data_type = "Timestmap(Millisecond, None)")]
+///     timestamp: i64,
+/// }
+/// ```
+///
+/// The schema can be converted into arrow or arrow2 fields via `TryFrom`, e.g.,
+/// `Vec::<FieldRef>::try_from(Record::arrow_schema()?)`.
+pub trait ArrowSchema {
+    /// The schema of the type
+    fn arrow_schema() -> Result<SerdeArrowSchema>;
+}
+
+/// Types that can be used inside types deriving [`ArrowSchema`]
+///
+/// *Internal. Do not use*: it is implemented by the derive macro and for
+/// common std types.
+pub trait ArrowField {
+    fn arrow_field(name: &str) -> Result<Field>;
+}
+
+struct FieldContext<'a>(&'a str);
+
+impl<'a> Context for FieldContext<'a> {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", self.0);
+    }
+}
+
+pub fn schema_from_field(field: Field) -> Result<SerdeArrowSchema> {
+    let DataType::Struct(fields) = field.data_type else {
+        fail!("ArrowSchema requires a struct with named fields");
+    };
+    Ok(SerdeArrowSchema {
+        fields,
+        metadata: HashMap::new(),
+    })
+}
+
+/// A field with a data type given as a string, e.g., `"Date64"`
+///
+/// The string has already been validated by the derive macro.
+pub fn custom_field(
+    name: &str,
+    data_type: &str,
+    nullable: bool,
+    strategy: Option<&str>,
+) -> Result<Field> {
+    try_(|| {
+        let data_type = build_data_type(data_type.to_owned(), Vec::new())?;
+        let strategy = strategy.map(str::parse::<Strategy>).transpose()?;
+        let field = Field {
+            name: name.to_owned(),
+            nullable: nullable || matches!(data_type, DataType::Null),
+            data_type,
+            metadata: merge_strategy_with_metadata(HashMap::new(), strategy)?,
+        };
+        validate_field(&field)?;
+        Ok(field)
+    })
+    .ctx(&FieldContext(name))
+}
+
+pub fn with_strategy(mut field: Field, strategy: &str) -> Result<Field> {
+    let strategy = strategy
+        .parse::<Strategy>()
+        .ctx(&FieldContext(&field.name))?;
+    field
+        .metadata
+        .insert(STRATEGY_KEY.to_owned(), strategy.to_string());
+    validate_field(&field).ctx(&FieldContext(&field.name))?;
+    Ok(field)
+}
+
+pub fn nullable(field: Field) -> Field {
+    Field {
+        nullable: true,
+        ..field
+    }
+}
+
+/// Apply a `rename_all` rule to the name of a struct field
+pub fn rename_field(rule: FieldNames, name: &str) -> String {
+    rule.to_arrow(name).into_owned()
+}
+
+/// Apply a `rename_all` rule to the name of an enum variant
+pub fn rename_variant(rule: FieldNames, name: &str) -> String {
+    rule.variant_to_arrow(name).into_owned()
+}
+
+pub fn null_field(name: &str) -> Field {
+    Field {
+        name: name.to_owned(),
+        data_type: DataType::Null,
+        nullable: true,
+        metadata: HashMap::new(),
+    }
+}
+
+pub fn unknown_variant() -> Field {
+    unknown_variant_field()
+}
+
+pub fn struct_field(name: &str, fields: Vec<Field>) -> Field {
+    primitive_field(name, DataType::Struct(fields))
+}
+
+pub fn tuple_field(name: &str, fields: Vec<Field>) -> Field {
+    let mut field = primitive_field(name, DataType::Struct(fields));
+    field
+        .metadata
+        .insert(STRATEGY_KEY.to_owned(), Strategy::TupleAsStruct.to_string());
+    field
+}
+
+pub fn union_field(name: &str, variants: Vec<Field>) -> Result<Field> {
+    let mut fields = Vec::new();
+    for (idx, variant) in variants.into_iter().enumerate() {
+        fields.push((i8::try_from(idx).ctx(&FieldContext(name))?, variant));
+    }
+    Ok(primitive_field(
+        name,
+        DataType::Union(fields, UnionMode::Dense),
+    ))
+}
+
+/// The fields of a `#[serde(flatten)]` member
+pub fn flattened_fields(field: Field) -> Result<Vec<Field>> {
+    let DataType::Struct(fields) = field.data_type else {
+        fail!("Only structs with named fields can be flattened in ArrowSchema");
+    };
+    if field.nullable {
+        Ok(fields.into_iter().map(nullable).collect())
+    } else {
+        Ok(fields)
+    }
+}
+
+fn primitive_field(name: &str, data_type: DataType) -> Field {
+    Field {
+        name: name.to_owned(),
+        data_type,
+        nullable: false,
+        metadata: HashMap::new(),
+    }
+}
+
+macro_rules! impl_primitive {
+    ($($ty:ty => $data_type:ident),* $(,)?) => {
+        $(
+            impl ArrowField for $ty {
+                fn arrow_field(name: &str) -> Result<Field> {
+                    Ok(primitive_field(name, DataType::$data_type))
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive!(
+    bool => Boolean,
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    u8 => UInt8,
+    u16 => UInt16,
+    u32 => UInt32,
+    u64 => UInt64,
+    half::f16 => Float16,
+    f32 => Float32,
+    f64 => Float64,
+    char => UInt32,
+    str => LargeUtf8,
+    String => LargeUtf8,
+);
+
+impl ArrowField for () {
+    fn arrow_field(name: &str) -> Result<Field> {
+        Ok(null_field(name))
+    }
+}
+
+impl<T: ArrowField> ArrowField for Option<T> {
+    fn arrow_field(name: &str) -> Result<Field> {
+        Ok(nullable(T::arrow_field(name)?))
+    }
+}
+
+macro_rules! impl_wrapper {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<T: ArrowField + ?Sized> ArrowField for $ty {
+                fn arrow_field(name: &str) -> Result<Field> {
+                    T::arrow_field(name)
+                }
+            }
+        )*
+    };
+}
+
+impl_wrapper!(&T, Box<T>, Rc<T>, Arc<T>);
+
+macro_rules! impl_list {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<T: ArrowField> ArrowField for $ty {
+                fn arrow_field(name: &str) -> Result<Field> {
+                    let item = T::arrow_field("element")?;
+                    Ok(primitive_field(name, DataType::LargeList(Box::new(item))))
+                }
+            }
+        )*
+    };
+}
+
+impl_list!([T], Vec<T>, VecDeque<T>, BTreeSet<T>);
+
+impl<T: ArrowField, S> ArrowField for HashSet<T, S> {
+    fn arrow_field(name: &str) -> Result<Field> {
+        let item = T::arrow_field("element")?;
+        Ok(primitive_field(name, DataType::LargeList(Box::new(item))))
+    }
+}
+
+fn map_field(name: &str, key: Field, value: Field) -> Field {
+    let entries = struct_field("entries", vec![key, value]);
+    primitive_field(name, DataType::Map(Box::new(entries), false))
+}
+
+impl<K: ArrowField, V: ArrowField> ArrowField for BTreeMap<K, V> {
+    fn arrow_field(name: &str) -> Result<Field> {
+        Ok(map_field(
+            name,
+            K::arrow_field("key")?,
+            V::arrow_field("value")?,
+        ))
+    }
+}
+
+impl<K: ArrowField, V: ArrowField, S> ArrowField for HashMap<K, V, S> {
+    fn arrow_field(name: &str) -> Result<Field> {
+        Ok(map_field(
+            name,
+            K::arrow_field("key")?,
+            V::arrow_field("value")?,
+        ))
+    }
+}
+
+macro_rules! impl_tuple {
+    ($(($($ty:ident: $idx:literal),*)),* $(,)?) => {
+        $(
+            impl<$($ty: ArrowField),*> ArrowField for ($($ty,)*) {
+                fn arrow_field(name: &str) -> Result<Field> {
+                    Ok(tuple_field(name, vec![$($ty::arrow_field($idx)?),*]))
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple!(
+    (A: "0"),
+    (A: "0", B: "1"),
+    (A: "0", B: "1", C: "2"),
+    (A: "0", B: "1", C: "2", D: "3"),
+    (A: "0", B: "1", C: "2", D: "3", E: "4"),
+    (A: "0", B: "1", C: "2", D: "3", E: "4", F: "5"),
+);
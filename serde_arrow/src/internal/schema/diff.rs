@@ -0,0 +1,336 @@
+use std::collections::BTreeSet;
+
+use crate::internal::{
+    arrow::{DataType, Field},
+    schema::{DataTypeDisplay, SerdeArrowSchema, STRATEGY_KEY},
+    utils::ChildName,
+};
+
+/// How a schema change affects readers and writers
+///
+/// The classification follows the usual conventions of schema registries:
+///
+/// - [`Backward`][Compatibility::Backward]: data written with the old schema
+///   can be read with the new schema
+/// - [`Forward`][Compatibility::Forward]: data written with the new schema can
+///   be read with the old schema
+/// - [`Full`][Compatibility::Full]: both backward and forward compatible
+/// - [`Breaking`][Compatibility::Breaking]: neither backward nor forward
+///   compatible
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compatibility {
+    Full,
+    Backward,
+    Forward,
+    Breaking,
+}
+
+impl Compatibility {
+    /// Whether data written with the old schema can be read with the new one
+    pub fn is_backward(self) -> bool {
+        matches!(self, Self::Full | Self::Backward)
+    }
+
+    /// Whether data written with the new schema can be read with the old one
+    pub fn is_forward(self) -> bool {
+        matches!(self, Self::Full | Self::Forward)
+    }
+
+    /// Combine two compatibilities into the one satisfied by both
+    pub fn and(self, other: Self) -> Self {
+        match (
+            self.is_backward() && other.is_backward(),
+            self.is_forward() && other.is_forward(),
+        ) {
+            (true, true) => Self::Full,
+            (true, false) => Self::Backward,
+            (false, true) => Self::Forward,
+            (false, false) => Self::Breaking,
+        }
+    }
+}
+
+impl std::fmt::Display for Compatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Full => write!(f, "full"),
+            Self::Backward => write!(f, "backward"),
+            Self::Forward => write!(f, "forward"),
+            Self::Breaking => write!(f, "breaking"),
+        }
+    }
+}
+
+/// The kind of a single difference between two schemas
+///
+/// Data types are given in their display form, e.g., `"Int32"` or
+/// `"Timestamp(Millisecond, None)"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SchemaChangeKind {
+    /// The field only exists in the new schema
+    FieldAdded { nullable: bool },
+    /// The field only exists in the old schema
+    FieldRemoved { nullable: bool },
+    /// The data type of the field changed
+    DataTypeChanged { old: String, new: String },
+    /// The nullability of the field changed
+    NullabilityChanged { old: bool, new: bool },
+    /// The `SERDE_ARROW:strategy` metadata of the field changed
+    StrategyChanged {
+        old: Option<String>,
+        new: Option<String>,
+    },
+    /// Any other metadata entry of the field changed
+    MetadataChanged {
+        key: String,
+        old: Option<String>,
+        new: Option<String>,
+    },
+}
+
+/// A single difference between two schemas
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaChange {
+    /// The path of the affected field, e.g., `"$.a.b"`
+    pub path: String,
+    pub kind: SchemaChangeKind,
+    pub compatibility: Compatibility,
+}
+
+/// The differences between two schemas, see [`SerdeArrowSchema::diff`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaDiff {
+    pub changes: Vec<SchemaChange>,
+}
+
+impl SchemaDiff {
+    /// Whether the schemas are identical
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The compatibility satisfied by all changes
+    pub fn compatibility(&self) -> Compatibility {
+        self.changes
+            .iter()
+            .fold(Compatibility::Full, |acc, change| {
+                acc.and(change.compatibility)
+            })
+    }
+
+    /// The changes that are neither backward nor forward compatible
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &SchemaChange> {
+        self.changes
+            .iter()
+            .filter(|change| change.compatibility == Compatibility::Breaking)
+    }
+}
+
+impl SerdeArrowSchema {
+    /// Compare this schema (the old schema) to a new schema
+    ///
+    /// The result lists all added and removed fields, changes in data types,
+    /// nullability and metadata. Each change is classified according to its
+    /// [`Compatibility`]. Changes of nested fields are reported with the full
+    /// path of the field. Widening a data type (e.g., `Int32` to `Int64` or
+    /// `Utf8` to `LargeUtf8`) is backward compatible, the reverse is forward
+    /// compatible.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_json::json;
+    /// use serde_arrow::schema::{Compatibility, SchemaLike, SerdeArrowSchema};
+    ///
+    /// let old = SerdeArrowSchema::from_value(&json!([
+    ///     {"name": "a", "data_type": "I32"},
+    /// ]))?;
+    /// let new = SerdeArrowSchema::from_value(&json!([
+    ///     {"name": "a", "data_type": "I64"},
+    ///     {"name": "b", "data_type": "Utf8", "nullable": true},
+    /// ]))?;
+    ///
+    /// let diff = old.diff(&new);
+    /// assert_eq!(diff.changes.len(), 2);
+    /// assert_eq!(diff.compatibility(), Compatibility::Backward);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff(&self, new: &SerdeArrowSchema) -> SchemaDiff {
+        let mut changes = Vec::new();
+        diff_fields(&mut changes, "$", &self.fields, &new.fields);
+        SchemaDiff { changes }
+    }
+}
+
+fn diff_fields(changes: &mut Vec<SchemaChange>, path: &str, old: &[Field], new: &[Field]) {
+    for old_field in old {
+        let child_path = format!("{path}.{child}", child = ChildName(&old_field.name));
+        match new
+            .iter()
+            .find(|new_field| new_field.name == old_field.name)
+        {
+            Some(new_field) => diff_field(changes, &child_path, old_field, new_field),
+            None => changes.push(SchemaChange {
+                path: child_path,
+                kind: SchemaChangeKind::FieldRemoved {
+                    nullable: old_field.nullable,
+                },
+                compatibility: if old_field.nullable {
+                    Compatibility::Full
+                } else {
+                    Compatibility::Backward
+                },
+            }),
+        }
+    }
+    for new_field in new {
+        if !old.iter().any(|old_field| old_field.name == new_field.name) {
+            changes.push(SchemaChange {
+                path: format!("{path}.{child}", child = ChildName(&new_field.name)),
+                kind: SchemaChangeKind::FieldAdded {
+                    nullable: new_field.nullable,
+                },
+                compatibility: if new_field.nullable {
+                    Compatibility::Full
+                } else {
+                    Compatibility::Forward
+                },
+            });
+        }
+    }
+}
+
+fn diff_field(changes: &mut Vec<SchemaChange>, path: &str, old: &Field, new: &Field) {
+    diff_data_type(changes, path, &old.data_type, &new.data_type);
+
+    if old.nullable != new.nullable {
+        changes.push(SchemaChange {
+            path: path.to_owned(),
+            kind: SchemaChangeKind::NullabilityChanged {
+                old: old.nullable,
+                new: new.nullable,
+            },
+            compatibility: if new.nullable {
+                Compatibility::Backward
+            } else {
+                Compatibility::Forward
+            },
+        });
+    }
+
+    let keys = old
+        .metadata
+        .keys()
+        .chain(new.metadata.keys())
+        .collect::<BTreeSet<_>>();
+    for key in keys {
+        let old_value = old.metadata.get(key);
+        let new_value = new.metadata.get(key);
+        if old_value == new_value {
+            continue;
+        }
+
+        let (kind, compatibility) = if key == STRATEGY_KEY {
+            let kind = SchemaChangeKind::StrategyChanged {
+                old: old_value.cloned(),
+                new: new_value.cloned(),
+            };
+            (kind, Compatibility::Breaking)
+        } else {
+            let kind = SchemaChangeKind::MetadataChanged {
+                key: key.to_owned(),
+                old: old_value.cloned(),
+                new: new_value.cloned(),
+            };
+            (kind, Compatibility::Full)
+        };
+        changes.push(SchemaChange {
+            path: path.to_owned(),
+            kind,
+            compatibility,
+        });
+    }
+}
+
+fn diff_data_type(changes: &mut Vec<SchemaChange>, path: &str, old: &DataType, new: &DataType) {
+    use DataType as T;
+
+    let outer_changed = DataTypeDisplay(old).to_string() != DataTypeDisplay(new).to_string();
+    if outer_changed {
+        changes.push(SchemaChange {
+            path: path.to_owned(),
+            kind: SchemaChangeKind::DataTypeChanged {
+                old: DataTypeDisplay(old).to_string(),
+                new: DataTypeDisplay(new).to_string(),
+            },
+            compatibility: data_type_compatibility(old, new),
+        });
+    }
+
+    match (old, new) {
+        (T::Struct(old_fields), T::Struct(new_fields)) => {
+            diff_fields(changes, path, old_fields, new_fields);
+        }
+        (
+            T::List(old_item) | T::LargeList(old_item) | T::FixedSizeList(old_item, _),
+            T::List(new_item) | T::LargeList(new_item) | T::FixedSizeList(new_item, _),
+        )
+        | (T::Map(old_item, _), T::Map(new_item, _)) => {
+            let child_path = format!("{path}.{child}", child = ChildName(&old_item.name));
+            diff_field(changes, &child_path, old_item, new_item);
+        }
+        (T::Union(_, _), T::Union(_, _)) if !outer_changed && old != new => {
+            changes.push(SchemaChange {
+                path: path.to_owned(),
+                kind: SchemaChangeKind::DataTypeChanged {
+                    old: DataTypeDisplay(old).to_string(),
+                    new: DataTypeDisplay(new).to_string(),
+                },
+                compatibility: Compatibility::Breaking,
+            });
+        }
+        _ => {}
+    }
+}
+
+fn data_type_compatibility(old: &DataType, new: &DataType) -> Compatibility {
+    match (is_widening(old, new), is_widening(new, old)) {
+        (true, true) => Compatibility::Full,
+        (true, false) => Compatibility::Backward,
+        (false, true) => Compatibility::Forward,
+        (false, false) => Compatibility::Breaking,
+    }
+}
+
+/// Whether all values of `from` can be represented by `to`
+fn is_widening(from: &DataType, to: &DataType) -> bool {
+    use DataType as T;
+
+    match (from, to) {
+        (T::Null, _) => true,
+        (T::Int8, T::Int16 | T::Int32 | T::Int64 | T::Float32 | T::Float64) => true,
+        (T::Int16, T::Int32 | T::Int64 | T::Float32 | T::Float64) => true,
+        (T::Int32, T::Int64 | T::Float64) => true,
+        (T::UInt8, T::UInt16 | T::UInt32 | T::UInt64) => true,
+        (T::UInt8, T::Int16 | T::Int32 | T::Int64 | T::Float32 | T::Float64) => true,
+        (T::UInt16, T::UInt32 | T::UInt64 | T::Int32 | T::Int64 | T::Float32 | T::Float64) => true,
+        (T::UInt32, T::UInt64 | T::Int64 | T::Float64) => true,
+        (T::Float16, T::Float32 | T::Float64) => true,
+        (T::Float32, T::Float64) => true,
+        (T::Utf8, T::LargeUtf8) => true,
+        (T::Binary, T::LargeBinary) => true,
+        (T::List(_) | T::FixedSizeList(_, _), T::LargeList(_)) => true,
+        (T::FixedSizeList(_, _), T::List(_)) => true,
+        (T::Decimal128(from_precision, from_scale), T::Decimal128(to_precision, to_scale)) => {
+            let from_integer_digits = i16::from(*from_precision) - i16::from(*from_scale);
+            let to_integer_digits = i16::from(*to_precision) - i16::from(*to_scale);
+            to_scale >= from_scale && to_integer_digits >= from_integer_digits
+        }
+        (T::Dictionary(from_key, from_value, _), T::Dictionary(to_key, to_value, _)) => {
+            (from_key == to_key || is_widening(from_key, to_key))
+                && (from_value == to_value || is_widening(from_value, to_value))
+        }
+        _ => false,
+    }
+}
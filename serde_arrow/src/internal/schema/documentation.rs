@@ -0,0 +1,235 @@
+//! Render schemas as data dictionaries
+use std::fmt::Write;
+
+use crate::internal::{
+    arrow::{DataType, Field},
+    schema::{DataTypeDisplay, SerdeArrowSchema, STRATEGY_KEY},
+    utils::ChildName,
+};
+
+/// The metadata key used for the description column
+const DESCRIPTION_KEY: &str = "description";
+
+const COLUMNS: [&str; 6] = [
+    "Path",
+    "Type",
+    "Nullable",
+    "Strategy",
+    "Description",
+    "Metadata",
+];
+
+impl SerdeArrowSchema {
+    /// Render the schema as a Markdown table
+    ///
+    /// The table contains one row per field, including all nested fields,
+    /// i.e., the children of structs, lists, maps and unions. Each row lists
+    /// the path of the field with the names of its parents separated by dots,
+    /// its Arrow type, whether it is nullable, its strategy, its description
+    /// and any further metadata. The description is taken from the
+    /// `"description"` metadata entry.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_json::json;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let schema = SerdeArrowSchema::from_value(json!([
+    ///     {
+    ///         "name": "id",
+    ///         "data_type": "U64",
+    ///         "metadata": {"description": "The id of the user"},
+    ///     },
+    ///     {
+    ///         "name": "tags",
+    ///         "data_type": "LargeList",
+    ///         "nullable": true,
+    ///         "children": [{"name": "element", "data_type": "LargeUtf8"}],
+    ///     },
+    /// ]))?;
+    ///
+    /// assert_eq!(
+    ///     schema.to_markdown(),
+    ///     concat!(
+    ///         "| Path | Type | Nullable | Strategy | Description | Metadata |\n",
+    ///         "|---|---|---|---|---|---|\n",
+    ///         "| `id` | `UInt64` | no |  | The id of the user |  |\n",
+    ///         "| `tags` | `LargeList` | yes |  |  |  |\n",
+    ///         "| `tags.element` | `LargeUtf8` | no |  |  |  |\n",
+    ///     ),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let mut res = String::new();
+        res.push('|');
+        for column in COLUMNS {
+            res.push_str(&format!(" {column} |"));
+        }
+        res.push('\n');
+        res.push('|');
+        for _ in COLUMNS {
+            res.push_str("---|");
+        }
+        res.push('\n');
+
+        for row in collect_rows(&self.fields) {
+            let code = |s: &str| {
+                if s.is_empty() {
+                    String::new()
+                } else {
+                    format!("`{}`", escape_markdown(s))
+                }
+            };
+            let metadata = row
+                .metadata
+                .iter()
+                .map(|(key, value)| code(&format!("{key}={value}")))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let _ = writeln!(
+                res,
+                "| {path} | {data_type} | {nullable} | {strategy} | {description} | {metadata} |",
+                path = code(&row.path),
+                data_type = code(&row.data_type),
+                nullable = if row.nullable { "yes" } else { "no" },
+                strategy = code(row.strategy.unwrap_or_default()),
+                description = escape_markdown(row.description.unwrap_or_default()),
+            );
+        }
+        res
+    }
+
+    /// Render the schema as an HTML table
+    ///
+    /// The table has the same rows and columns as the one generated by
+    /// [`to_markdown`][SerdeArrowSchema::to_markdown]. All values are escaped
+    /// and the result can be embedded into HTML documents directly.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_json::json;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let schema = SerdeArrowSchema::from_value(json!([
+    ///     {"name": "date", "data_type": "Date64", "strategy": "NaiveStrAsDate64"},
+    /// ]))?;
+    ///
+    /// let html = schema.to_html();
+    /// assert!(html.starts_with("<table>\n"));
+    /// assert!(html.contains("<td><code>date</code></td>"));
+    /// assert!(html.contains("<td><code>NaiveStrAsDate64</code></td>"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_html(&self) -> String {
+        let mut res = String::from("<table>\n  <thead>\n    <tr>");
+        for column in COLUMNS {
+            res.push_str(&format!("<th>{column}</th>"));
+        }
+        res.push_str("</tr>\n  </thead>\n  <tbody>\n");
+
+        for row in collect_rows(&self.fields) {
+            let code = |s: &str| {
+                if s.is_empty() {
+                    String::new()
+                } else {
+                    format!("<code>{}</code>", escape_html(s))
+                }
+            };
+            let metadata = row
+                .metadata
+                .iter()
+                .map(|(key, value)| code(&format!("{key}={value}")))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            res.push_str("    <tr>");
+            for cell in [
+                code(&row.path),
+                code(&row.data_type),
+                String::from(if row.nullable { "yes" } else { "no" }),
+                code(row.strategy.unwrap_or_default()),
+                escape_html(row.description.unwrap_or_default()),
+                metadata,
+            ] {
+                res.push_str(&format!("<td>{cell}</td>"));
+            }
+            res.push_str("</tr>\n");
+        }
+        res.push_str("  </tbody>\n</table>\n");
+        res
+    }
+}
+
+struct Row<'a> {
+    path: String,
+    data_type: String,
+    nullable: bool,
+    strategy: Option<&'a str>,
+    description: Option<&'a str>,
+    metadata: Vec<(&'a str, &'a str)>,
+}
+
+fn collect_rows(fields: &[Field]) -> Vec<Row<'_>> {
+    let mut rows = Vec::new();
+    for field in fields {
+        collect_field_rows(&mut rows, ChildName(&field.name).to_string(), field);
+    }
+    rows
+}
+
+fn collect_field_rows<'a>(rows: &mut Vec<Row<'a>>, path: String, field: &'a Field) {
+    let mut metadata = field
+        .metadata
+        .iter()
+        .filter(|(key, _)| *key != STRATEGY_KEY && *key != DESCRIPTION_KEY)
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect::<Vec<_>>();
+    metadata.sort();
+
+    rows.push(Row {
+        path: path.clone(),
+        data_type: DataTypeDisplay(&field.data_type).to_string(),
+        nullable: field.nullable,
+        strategy: field.metadata.get(STRATEGY_KEY).map(String::as_str),
+        description: field.metadata.get(DESCRIPTION_KEY).map(String::as_str),
+        metadata,
+    });
+
+    let children: Vec<&Field> = match &field.data_type {
+        DataType::Struct(children) => children.iter().collect(),
+        DataType::List(child)
+        | DataType::LargeList(child)
+        | DataType::FixedSizeList(child, _)
+        | DataType::Map(child, _) => vec![child.as_ref()],
+        DataType::Union(variants, _) => variants.iter().map(|(_, child)| child).collect(),
+        _ => Vec::new(),
+    };
+    for child in children {
+        collect_field_rows(rows, format!("{path}.{}", ChildName(&child.name)), child);
+    }
+}
+
+fn escape_markdown(s: &str) -> String {
+    s.replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+fn escape_html(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '&' => res.push_str("&amp;"),
+            '<' => res.push_str("&lt;"),
+            '>' => res.push_str("&gt;"),
+            '"' => res.push_str("&quot;"),
+            '\'' => res.push_str("&#39;"),
+            ch => res.push(ch),
+        }
+    }
+    res
+}
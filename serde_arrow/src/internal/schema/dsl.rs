@@ -0,0 +1,428 @@
+//! A compact text representation of schemas, e.g., `a: I32?, b: List<F64>`
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
+
+use crate::internal::{
+    arrow::{DataType, Field, UnionMode},
+    error::{fail, Error, Result},
+    schema::{
+        serde::deserialize::{build_data_type, merge_strategy_with_metadata},
+        validate_field, DataTypeDisplay, SerdeArrowSchema, Strategy, STRATEGY_KEY,
+    },
+    utils::dsl::{parse_term, Term},
+};
+
+impl FromStr for SerdeArrowSchema {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (fields, rest) = parse_fields(s)?;
+        if !rest.trim().is_empty() {
+            fail!("Trailing content in schema: {rest:?}", rest = excerpt(rest));
+        }
+        Ok(Self {
+            fields,
+            metadata: HashMap::new(),
+        })
+    }
+}
+
+impl std::fmt::Display for SerdeArrowSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_fields(f, &self.fields)
+    }
+}
+
+fn parse_fields(s: &str) -> Result<(Vec<Field>, &str)> {
+    let mut s = s;
+    let mut fields = Vec::new();
+
+    loop {
+        s = s.trim_start();
+        if s.is_empty() || s.starts_with('}') {
+            break;
+        }
+
+        let field;
+        (field, s) = parse_field(s)?;
+        fields.push(field);
+
+        s = s.trim_start();
+        s = match s.strip_prefix(',') {
+            Some(s) => s,
+            None => break,
+        };
+    }
+
+    Ok((fields, s))
+}
+
+fn parse_field(s: &str) -> Result<(Field, &str)> {
+    let (name, s) = parse_name(s)?;
+    let s = expect(s, ':')?;
+    parse_field_type(name, s)
+}
+
+/// Parse a child field with an optional name, e.g., `I32?` or `item: I32?`
+fn parse_child<'a>(default_name: &str, s: &'a str) -> Result<(Field, &'a str)> {
+    let s = s.trim_start();
+    if let Ok((name, rest)) = parse_name(s) {
+        if let Some(rest) = rest.trim_start().strip_prefix(':') {
+            return parse_field_type(name, rest);
+        }
+    }
+    parse_field_type(default_name.to_owned(), s)
+}
+
+fn parse_field_type(name: String, s: &str) -> Result<(Field, &str)> {
+    let (data_type, s) = parse_data_type(s)?;
+
+    let s = s.trim_start();
+    let (nullable, s) = match s.strip_prefix('?') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+
+    let s = s.trim_start();
+    let (metadata, s) = if s.starts_with('[') {
+        parse_metadata(s)?
+    } else {
+        (HashMap::new(), s)
+    };
+
+    let field = Field {
+        name,
+        nullable: nullable || matches!(data_type, DataType::Null),
+        data_type,
+        metadata,
+    };
+    validate_field(&field)?;
+    Ok((field, s))
+}
+
+fn parse_data_type(s: &str) -> Result<(DataType, &str)> {
+    let (term, s) = parse_term(s)?;
+    let (name, args) = term.as_call()?;
+
+    match name {
+        "List" | "LargeList" | "FixedSizeList" => {
+            let s = expect(s, '<')?;
+            let (child, s) = parse_child("element", s)?;
+            let s = expect(s, '>')?;
+
+            let data_type = match (name, args) {
+                ("List", []) => DataType::List(Box::new(child)),
+                ("LargeList", []) => DataType::LargeList(Box::new(child)),
+                ("FixedSizeList", [n]) => {
+                    DataType::FixedSizeList(Box::new(child), n.as_ident()?.parse()?)
+                }
+                _ => fail!("Invalid arguments for {name}"),
+            };
+            Ok((data_type, s))
+        }
+        "Struct" => {
+            if !args.is_empty() {
+                fail!("Invalid arguments for Struct");
+            }
+            let s = expect(s, '{')?;
+            let (fields, s) = parse_fields(s)?;
+            let s = expect(s, '}')?;
+            Ok((DataType::Struct(fields), s))
+        }
+        "Union" => {
+            let mode = match args {
+                [] => UnionMode::Dense,
+                [mode] => mode.as_ident()?.parse()?,
+                _ => fail!("Invalid arguments for Union"),
+            };
+            let s = expect(s, '{')?;
+            let (fields, s) = parse_fields(s)?;
+            let s = expect(s, '}')?;
+
+            let mut children = Vec::new();
+            for (idx, field) in fields.into_iter().enumerate() {
+                children.push((i8::try_from(idx)?, field));
+            }
+            Ok((DataType::Union(children, mode), s))
+        }
+        "Map" => {
+            let sorted = parse_sorted(name, args)?;
+            let s = expect(s, '<')?;
+            let (first, s) = parse_child("", s)?;
+
+            let (entries, s) = match s.trim_start().strip_prefix(',') {
+                Some(s) => {
+                    let (value, s) = parse_child("value", s)?;
+                    let key = if first.name.is_empty() {
+                        Field {
+                            name: String::from("key"),
+                            ..first
+                        }
+                    } else {
+                        first
+                    };
+                    let entries = Field {
+                        name: String::from("entries"),
+                        data_type: DataType::Struct(vec![key, value]),
+                        nullable: false,
+                        metadata: HashMap::new(),
+                    };
+                    (entries, s)
+                }
+                None if first.name.is_empty() => (
+                    Field {
+                        name: String::from("entries"),
+                        ..first
+                    },
+                    s,
+                ),
+                None => (first, s),
+            };
+            let s = expect(s, '>')?;
+            Ok((DataType::Map(Box::new(entries), sorted), s))
+        }
+        "Dictionary" => {
+            let sorted = parse_sorted(name, args)?;
+            let s = expect(s, '<')?;
+            let (key, s) = parse_data_type(s)?;
+            let s = expect(s, ',')?;
+            let (value, s) = parse_data_type(s)?;
+            let s = expect(s, '>')?;
+            Ok((
+                DataType::Dictionary(Box::new(key), Box::new(value), sorted),
+                s,
+            ))
+        }
+        _ => Ok((build_data_type(term.to_string(), Vec::new())?, s)),
+    }
+}
+
+fn parse_sorted(name: &str, args: &[Term]) -> Result<bool> {
+    match args {
+        [] => Ok(false),
+        [sorted] => Ok(sorted.as_ident()?.parse()?),
+        _ => fail!("Invalid arguments for {name}"),
+    }
+}
+
+/// Parse field metadata, e.g., `[strategy: TupleAsStruct, "key": "value"]`
+fn parse_metadata(s: &str) -> Result<(HashMap<String, String>, &str)> {
+    let mut s = expect(s, '[')?;
+    let mut metadata = HashMap::new();
+    let mut strategy = None;
+
+    loop {
+        s = s.trim_start();
+        if s.starts_with(']') {
+            break;
+        }
+
+        let key;
+        let value;
+        (key, s) = parse_name(s)?;
+        s = expect(s, ':')?;
+        (value, s) = parse_name(s)?;
+
+        if key == "strategy" {
+            strategy = Some(value.parse::<Strategy>()?);
+        } else {
+            metadata.insert(key, value);
+        }
+
+        s = s.trim_start();
+        s = match s.strip_prefix(',') {
+            Some(s) => s,
+            None => break,
+        };
+    }
+    let s = expect(s, ']')?;
+
+    Ok((merge_strategy_with_metadata(metadata, strategy)?, s))
+}
+
+/// Parse an identifier like name or a quoted string
+fn parse_name(s: &str) -> Result<(String, &str)> {
+    let s = s.trim_start();
+    if let Some(s) = s.strip_prefix('"') {
+        let mut name = String::new();
+        let mut chars = s.char_indices();
+        while let Some((idx, c)) = chars.next() {
+            match c {
+                '"' => return Ok((name, &s[idx + 1..])),
+                '\\' => match chars.next() {
+                    Some((_, c)) => name.push(c),
+                    None => break,
+                },
+                c => name.push(c),
+            }
+        }
+        fail!("Missing end quote in {s:?}", s = excerpt(s));
+    }
+
+    let pos = s.find(|c| !is_name_char(c)).unwrap_or(s.len());
+    if pos == 0 {
+        fail!("Expected a name, found {s:?}", s = excerpt(s));
+    }
+    Ok((s[..pos].to_owned(), &s[pos..]))
+}
+
+fn expect(s: &str, c: char) -> Result<&str> {
+    let s = s.trim_start();
+    match s.strip_prefix(c) {
+        Some(s) => Ok(s),
+        None => fail!("Expected {c:?}, found {s:?}", s = excerpt(s)),
+    }
+}
+
+fn excerpt(s: &str) -> &str {
+    match s.char_indices().nth(20) {
+        Some((idx, _)) => &s[..idx],
+        None => s,
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn write_fields(f: &mut std::fmt::Formatter<'_>, fields: &[Field]) -> std::fmt::Result {
+    for (idx, field) in fields.iter().enumerate() {
+        if idx != 0 {
+            write!(f, ", ")?;
+        }
+        write_field(f, None, field)?;
+    }
+    Ok(())
+}
+
+/// Write a field, omitting its name if it matches the default name
+fn write_field(
+    f: &mut std::fmt::Formatter<'_>,
+    default_name: Option<&str>,
+    field: &Field,
+) -> std::fmt::Result {
+    if default_name != Some(field.name.as_str()) {
+        write_name(f, &field.name)?;
+        write!(f, ": ")?;
+    }
+    write_data_type(f, &field.data_type)?;
+    if field.nullable && !matches!(field.data_type, DataType::Null) {
+        write!(f, "?")?;
+    }
+
+    if field.metadata.is_empty() {
+        return Ok(());
+    }
+
+    let mut metadata = field.metadata.iter().collect::<BTreeMap<_, _>>();
+    write!(f, " [")?;
+    if let Some(strategy) = metadata.remove(&STRATEGY_KEY.to_owned()) {
+        write!(f, "strategy: ")?;
+        write_name(f, strategy)?;
+        if !metadata.is_empty() {
+            write!(f, ", ")?;
+        }
+    }
+    for (idx, (key, value)) in metadata.into_iter().enumerate() {
+        if idx != 0 {
+            write!(f, ", ")?;
+        }
+        write_quoted(f, key)?;
+        write!(f, ": ")?;
+        write_quoted(f, value)?;
+    }
+    write!(f, "]")
+}
+
+fn write_data_type(f: &mut std::fmt::Formatter<'_>, data_type: &DataType) -> std::fmt::Result {
+    match data_type {
+        DataType::List(child) => {
+            write!(f, "List<")?;
+            write_field(f, Some("element"), child)?;
+            write!(f, ">")
+        }
+        DataType::LargeList(child) => {
+            write!(f, "LargeList<")?;
+            write_field(f, Some("element"), child)?;
+            write!(f, ">")
+        }
+        DataType::FixedSizeList(child, n) => {
+            write!(f, "FixedSizeList({n})<")?;
+            write_field(f, Some("element"), child)?;
+            write!(f, ">")
+        }
+        DataType::Struct(fields) => {
+            write!(f, "Struct{{")?;
+            write_fields(f, fields)?;
+            write!(f, "}}")
+        }
+        DataType::Union(children, mode) => {
+            match mode {
+                UnionMode::Dense => write!(f, "Union{{")?,
+                UnionMode::Sparse => write!(f, "Union(Sparse){{")?,
+            }
+            for (idx, (_, field)) in children.iter().enumerate() {
+                if idx != 0 {
+                    write!(f, ", ")?;
+                }
+                write_field(f, None, field)?;
+            }
+            write!(f, "}}")
+        }
+        DataType::Map(entries, sorted) => {
+            write!(f, "Map")?;
+            if *sorted {
+                write!(f, "(true)")?;
+            }
+            write!(f, "<")?;
+            match &entries.data_type {
+                DataType::Struct(fields)
+                    if fields.len() == 2
+                        && entries.name == "entries"
+                        && !entries.nullable
+                        && entries.metadata.is_empty()
+                        && !fields[0].name.is_empty() =>
+                {
+                    write_field(f, Some("key"), &fields[0])?;
+                    write!(f, ", ")?;
+                    write_field(f, Some("value"), &fields[1])?;
+                }
+                _ => write_field(f, Some(""), entries)?,
+            }
+            write!(f, ">")
+        }
+        DataType::Dictionary(key, value, sorted) => {
+            write!(f, "Dictionary")?;
+            if *sorted {
+                write!(f, "(true)")?;
+            }
+            write!(f, "<")?;
+            write_data_type(f, key)?;
+            write!(f, ", ")?;
+            write_data_type(f, value)?;
+            write!(f, ">")
+        }
+        data_type => write!(f, "{}", DataTypeDisplay(data_type)),
+    }
+}
+
+fn write_name(f: &mut std::fmt::Formatter<'_>, name: &str) -> std::fmt::Result {
+    if !name.is_empty() && name.chars().all(is_name_char) {
+        write!(f, "{name}")
+    } else {
+        write_quoted(f, name)
+    }
+}
+
+fn write_quoted(f: &mut std::fmt::Formatter<'_>, s: &str) -> std::fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        if matches!(c, '"' | '\\') {
+            write!(f, "\\")?;
+        }
+        write!(f, "{c}")?;
+    }
+    write!(f, "\"")
+}
@@ -6,6 +6,8 @@ use crate::internal::{
     schema::PrettyField,
 };
 
+use super::{EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY};
+
 /// A helper to construct new `Bool8` fields (`arrow.bool8`)
 ///
 /// This extension type can be used with `overwrites` in schema tracing:
@@ -63,8 +65,8 @@ impl TryFrom<&Bool8Field> for Field {
 
     fn try_from(value: &Bool8Field) -> Result<Self> {
         let mut metadata = HashMap::new();
-        metadata.insert("ARROW:extension:name".into(), "arrow.bool8".into());
-        metadata.insert("ARROW:extension:metadata".into(), String::new());
+        metadata.insert(EXTENSION_NAME_KEY.into(), "arrow.bool8".into());
+        metadata.insert(EXTENSION_METADATA_KEY.into(), String::new());
 
         Ok(Field {
             name: value.name.to_owned(),
@@ -90,7 +92,7 @@ fn bool8_repr() -> crate::internal::error::PanicOnError<()> {
     let field = Bool8Field::new("hello");
 
     let field = Field::try_from(&field)?;
-    let actual = serde_json::to_value(&PrettyField(&field))?;
+    let actual = serde_json::to_value(PrettyField(&field))?;
 
     let expected = json!({
         "name": "hello",
@@ -6,7 +6,10 @@ use crate::internal::{
     schema::{transmute_field, PrettyField},
 };
 
-use super::utils::{check_dim_names, check_permutation, write_list, DebugRepr};
+use super::{
+    utils::{check_dim_names, check_permutation, write_list, DebugRepr},
+    EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY,
+};
 
 /// Easily construct a fixed shape tensor fields (`arrow.fixed_shape_tensor`)
 ///
@@ -128,11 +131,8 @@ impl TryFrom<&FixedShapeTensorField> for Field {
         }
 
         let mut metadata = HashMap::new();
-        metadata.insert(
-            "ARROW:extension:name".into(),
-            "arrow.fixed_shape_tensor".into(),
-        );
-        metadata.insert("ARROW:extension:metadata".into(), value.get_ext_metadata()?);
+        metadata.insert(EXTENSION_NAME_KEY.into(), "arrow.fixed_shape_tensor".into());
+        metadata.insert(EXTENSION_METADATA_KEY.into(), value.get_ext_metadata()?);
 
         Ok(Field {
             name: value.name.to_owned(),
@@ -161,7 +161,7 @@ fn fixed_shape_tensor_field_repr() -> crate::internal::error::PanicOnError<()> {
         vec![2, 3],
     )?;
     let field = Field::try_from(&field)?;
-    let actual = serde_json::to_value(&PrettyField(&field))?;
+    let actual = serde_json::to_value(PrettyField(&field))?;
     let expected = json!({
         "name": "hello",
         "data_type": "FixedSizeList(6)",
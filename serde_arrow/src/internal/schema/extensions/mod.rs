@@ -7,7 +7,14 @@ pub use bool8_field::Bool8Field;
 pub use fixed_shape_tensor_field::FixedShapeTensorField;
 pub use variable_shape_tensor_field::VariableShapeTensorField;
 
+/// The metadata key of the extension type name, e.g., `arrow.uuid`
+pub(crate) const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
+/// The metadata key of the serialized extension type parameters
+pub(crate) const EXTENSION_METADATA_KEY: &str = "ARROW:extension:metadata";
+
 const _: () = {
+    #[allow(dead_code)]
     trait AssertSendSync: Send + Sync {}
     impl AssertSendSync for Bool8Field {}
     impl AssertSendSync for FixedShapeTensorField {}
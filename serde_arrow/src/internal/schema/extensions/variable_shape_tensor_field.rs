@@ -6,7 +6,10 @@ use crate::internal::{
     schema::{transmute_field, PrettyField},
 };
 
-use super::utils::{check_dim_names, check_permutation, write_list, DebugRepr};
+use super::{
+    utils::{check_dim_names, check_permutation, write_list, DebugRepr},
+    EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY,
+};
 
 /// Helper to build variable shape tensor fields (`arrow.variable_shape_tensor`)
 ///
@@ -130,10 +133,10 @@ impl TryFrom<&VariableShapeTensorField> for Field {
     fn try_from(value: &VariableShapeTensorField) -> Result<Self> {
         let mut metadata = HashMap::new();
         metadata.insert(
-            "ARROW:extension:name".into(),
+            EXTENSION_NAME_KEY.into(),
             "arrow.variable_shape_tensor".into(),
         );
-        metadata.insert("ARROW:extension:metadata".into(), value.get_ext_metadata()?);
+        metadata.insert(EXTENSION_METADATA_KEY.into(), value.get_ext_metadata()?);
 
         let fields = vec![
             Field {
@@ -0,0 +1,167 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+/// How to translate between serde field names and Arrow field names
+///
+/// The conventions follow serde's `rename_all` attribute: serde field names
+/// are assumed to be `snake_case`, as is common for Rust structs, and are
+/// converted to the given case to obtain the Arrow field names. The policy is
+/// applied to the fields of structs and of maps traced as structs, but not to
+/// enum variants or tuple fields.
+///
+/// During tracing, the policy is configured with
+/// [`TracingOptions::field_names`][crate::schema::TracingOptions::field_names].
+/// When building or deserializing arrays, the same policy must be configured
+/// with [`ArrayBuilder::with_field_names`][crate::ArrayBuilder::with_field_names]
+/// or [`Deserializer::with_field_names`][crate::Deserializer::with_field_names].
+///
+/// ```rust
+/// # use serde_arrow::schema::FieldNames;
+/// assert_eq!(FieldNames::CamelCase.to_arrow("user_id"), "userId");
+/// assert_eq!(FieldNames::ScreamingSnakeCase.to_arrow("user_id"), "USER_ID");
+/// assert_eq!(FieldNames::CamelCase.to_serde("userId"), "user_id");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum FieldNames {
+    /// Use the serde field names as Arrow field names (the default)
+    #[default]
+    Unchanged,
+    /// `lowercase`
+    #[serde(rename = "lowercase")]
+    LowerCase,
+    /// `UPPERCASE`
+    #[serde(rename = "UPPERCASE")]
+    UpperCase,
+    /// `PascalCase`
+    #[serde(rename = "PascalCase")]
+    PascalCase,
+    /// `camelCase`
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    /// `snake_case`
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+    /// `SCREAMING_SNAKE_CASE`
+    #[serde(rename = "SCREAMING_SNAKE_CASE")]
+    ScreamingSnakeCase,
+    /// `kebab-case`
+    #[serde(rename = "kebab-case")]
+    KebabCase,
+    /// `SCREAMING-KEBAB-CASE`
+    #[serde(rename = "SCREAMING-KEBAB-CASE")]
+    ScreamingKebabCase,
+}
+
+impl FieldNames {
+    /// Convert a serde field name into the corresponding Arrow field name
+    pub fn to_arrow<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        use FieldNames as N;
+        match self {
+            N::Unchanged | N::LowerCase | N::SnakeCase => Cow::Borrowed(name),
+            N::UpperCase | N::ScreamingSnakeCase => Cow::Owned(name.to_ascii_uppercase()),
+            N::PascalCase => {
+                let mut result = String::with_capacity(name.len());
+                let mut capitalize = true;
+                for ch in name.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        result.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        result.push(ch);
+                    }
+                }
+                Cow::Owned(result)
+            }
+            N::CamelCase => Cow::Owned(lowercase_first(&N::PascalCase.to_arrow(name))),
+            N::KebabCase => Cow::Owned(name.replace('_', "-")),
+            N::ScreamingKebabCase => Cow::Owned(name.replace('_', "-").to_ascii_uppercase()),
+        }
+    }
+
+    /// Convert an Arrow field name back into a `snake_case` serde field name
+    ///
+    /// This conversion is only used if the serde field names are not known,
+    /// e.g., when deserializing a struct array into a map. It does not undo
+    /// lossy conversions, e.g., `field_1` in `camelCase` is `field1`, which is
+    /// converted back into `field1`.
+    pub fn to_serde<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        use FieldNames as N;
+        match self {
+            N::Unchanged | N::LowerCase | N::SnakeCase => Cow::Borrowed(name),
+            N::UpperCase | N::ScreamingSnakeCase => Cow::Owned(name.to_ascii_lowercase()),
+            N::PascalCase | N::CamelCase => Cow::Owned(split_words(name).join("_")),
+            N::KebabCase | N::ScreamingKebabCase => {
+                Cow::Owned(name.replace('-', "_").to_ascii_lowercase())
+            }
+        }
+    }
+
+    /// Convert a serde variant name given in `PascalCase`, as used by serde's
+    /// `rename_all` attribute on enums
+    pub(crate) fn variant_to_arrow<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        use FieldNames as N;
+        match self {
+            N::Unchanged | N::PascalCase => Cow::Borrowed(name),
+            N::LowerCase => Cow::Owned(name.to_ascii_lowercase()),
+            N::UpperCase => Cow::Owned(name.to_ascii_uppercase()),
+            N::CamelCase => Cow::Owned(lowercase_first(name)),
+            N::SnakeCase => {
+                let mut result = String::with_capacity(name.len() + 4);
+                for (idx, ch) in name.char_indices() {
+                    if idx > 0 && ch.is_uppercase() {
+                        result.push('_');
+                    }
+                    result.push(ch.to_ascii_lowercase());
+                }
+                Cow::Owned(result)
+            }
+            N::ScreamingSnakeCase => {
+                Cow::Owned(N::SnakeCase.variant_to_arrow(name).to_ascii_uppercase())
+            }
+            N::KebabCase => Cow::Owned(N::SnakeCase.variant_to_arrow(name).replace('_', "-")),
+            N::ScreamingKebabCase => Cow::Owned(
+                N::ScreamingSnakeCase
+                    .variant_to_arrow(name)
+                    .replace('_', "-"),
+            ),
+        }
+    }
+}
+
+fn lowercase_first(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Split a name into lower case words at non-alphanumeric characters and
+/// lower-to-upper case transitions
+pub(crate) fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+    for ch in name.chars() {
+        if !ch.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            prev_lower = false;
+            continue;
+        }
+        if ch.is_uppercase() && prev_lower && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        prev_lower = ch.is_lowercase() || ch.is_ascii_digit();
+        current.extend(ch.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
@@ -0,0 +1,308 @@
+//! Canonical forms and fingerprints of schemas
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+};
+
+use crate::internal::{
+    arrow::{DataType, Field, TimeUnit, UnionMode},
+    schema::SerdeArrowSchema,
+};
+
+impl SerdeArrowSchema {
+    /// Render the schema in a canonical text form
+    ///
+    /// The canonical form describes the names, data types, nullability and
+    /// metadata of all fields, including nested fields, and the schema-level
+    /// metadata. Metadata entries are sorted by key, so the canonical form does
+    /// not depend on the iteration order of the underlying maps. The names of
+    /// list items and map entries are normalized to `element` and
+    /// `entries` / `key` / `value`, as different producers use different
+    /// names, e.g., `item` or `element` for list items. Names, metadata keys
+    /// and values are written as double-quoted strings with `"`, `\` and
+    /// control characters escaped.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_json::json;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let schema = SerdeArrowSchema::from_value(json!([
+    ///     {"name": "id", "data_type": "U64", "metadata": {"b": "2", "a": "1"}},
+    ///     {
+    ///         "name": "tags",
+    ///         "data_type": "List",
+    ///         "nullable": true,
+    ///         "children": [{"name": "item", "data_type": "Utf8"}],
+    ///     },
+    /// ]))?;
+    ///
+    /// assert_eq!(
+    ///     schema.to_canonical_string(),
+    ///     r#""id": UInt64 ["a": "1", "b": "2"], "tags": List? <"element": Utf8>"#,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_canonical_string(&self) -> String {
+        let mut res = String::new();
+        for (idx, field) in self.fields.iter().enumerate() {
+            if idx != 0 {
+                res.push_str(", ");
+            }
+            write_field(&mut res, None, field);
+        }
+        if !self.metadata.is_empty() {
+            res.push_str("; ");
+            write_metadata(&mut res, &self.metadata);
+        }
+        res
+    }
+
+    /// Compute a stable fingerprint of the schema
+    ///
+    /// The fingerprint is the 64 bit FNV-1a hash of the
+    /// [canonical form][SerdeArrowSchema::to_canonical_string]. It does not
+    /// depend on the process or platform and can be used, e.g., as part of
+    /// cache keys. Equivalent schemas have the same fingerprint.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_json::json;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let a = SerdeArrowSchema::from_value(json!([
+    ///     {"name": "tags", "data_type": "List", "children": [{"name": "item", "data_type": "Utf8"}]},
+    /// ]))?;
+    /// let b = SerdeArrowSchema::from_value(json!([
+    ///     {"name": "tags", "data_type": "List", "children": [{"name": "element", "data_type": "Utf8"}]},
+    /// ]))?;
+    ///
+    /// assert_ne!(a, b);
+    /// assert!(a.is_equivalent(&b));
+    /// assert_eq!(a.fingerprint(), b.fingerprint());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut hash = OFFSET_BASIS;
+        for byte in self.to_canonical_string().bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+        hash
+    }
+
+    /// Check whether two schemas are equal up to the names of list items and
+    /// map entries
+    ///
+    /// Two schemas are equivalent if their
+    /// [canonical forms][SerdeArrowSchema::to_canonical_string] agree.
+    pub fn is_equivalent(&self, other: &SerdeArrowSchema) -> bool {
+        self.to_canonical_string() == other.to_canonical_string()
+    }
+}
+
+fn write_field(res: &mut String, name: Option<&str>, field: &Field) {
+    write_field_header(res, name, field);
+
+    match &field.data_type {
+        DataType::List(child) | DataType::LargeList(child) | DataType::FixedSizeList(child, _) => {
+            res.push_str(" <");
+            write_field(res, Some("element"), child);
+            res.push('>');
+        }
+        DataType::Struct(children) => {
+            res.push_str(" <");
+            write_children(res, children.iter().map(|child| (None, None, child)));
+            res.push('>');
+        }
+        DataType::Map(entries, _) => {
+            res.push_str(" <");
+            match &entries.data_type {
+                DataType::Struct(children) if children.len() == 2 => {
+                    let names = [Some("key"), Some("value")];
+                    write_field_header(res, Some("entries"), entries);
+                    res.push_str(" <");
+                    write_children(
+                        res,
+                        names
+                            .into_iter()
+                            .zip(children)
+                            .map(|(name, child)| (None, name, child)),
+                    );
+                    res.push('>');
+                }
+                _ => write_field(res, Some("entries"), entries),
+            }
+            res.push('>');
+        }
+        DataType::Union(variants, _) => {
+            res.push_str(" <");
+            write_children(
+                res,
+                variants
+                    .iter()
+                    .map(|(type_id, child)| (Some(*type_id), None, child)),
+            );
+            res.push('>');
+        }
+        _ => {}
+    }
+}
+
+/// Write the name, data type, nullability and metadata of a field
+fn write_field_header(res: &mut String, name: Option<&str>, field: &Field) {
+    write_quoted(res, name.unwrap_or(&field.name));
+    res.push_str(": ");
+    write_data_type(res, &field.data_type);
+    if field.nullable {
+        res.push('?');
+    }
+    if !field.metadata.is_empty() {
+        res.push(' ');
+        write_metadata(res, &field.metadata);
+    }
+}
+
+/// Write the data type without its children
+///
+/// The tokens are spelled out here, instead of relying on any `Display` or
+/// `Debug` impl, as fingerprints must not change when these impls do.
+fn write_data_type(res: &mut String, data_type: &DataType) {
+    match data_type {
+        DataType::Null => res.push_str("Null"),
+        DataType::Boolean => res.push_str("Boolean"),
+        DataType::Int8 => res.push_str("Int8"),
+        DataType::Int16 => res.push_str("Int16"),
+        DataType::Int32 => res.push_str("Int32"),
+        DataType::Int64 => res.push_str("Int64"),
+        DataType::UInt8 => res.push_str("UInt8"),
+        DataType::UInt16 => res.push_str("UInt16"),
+        DataType::UInt32 => res.push_str("UInt32"),
+        DataType::UInt64 => res.push_str("UInt64"),
+        DataType::Float16 => res.push_str("Float16"),
+        DataType::Float32 => res.push_str("Float32"),
+        DataType::Float64 => res.push_str("Float64"),
+        DataType::Utf8 => res.push_str("Utf8"),
+        DataType::LargeUtf8 => res.push_str("LargeUtf8"),
+        DataType::Binary => res.push_str("Binary"),
+        DataType::LargeBinary => res.push_str("LargeBinary"),
+        DataType::FixedSizeBinary(n) => {
+            let _ = write!(res, "FixedSizeBinary({n})");
+        }
+        DataType::Date32 => res.push_str("Date32"),
+        DataType::Date64 => res.push_str("Date64"),
+        DataType::Time32(unit) => {
+            res.push_str("Time32(");
+            write_time_unit(res, *unit);
+            res.push(')');
+        }
+        DataType::Time64(unit) => {
+            res.push_str("Time64(");
+            write_time_unit(res, *unit);
+            res.push(')');
+        }
+        DataType::Timestamp(unit, tz) => {
+            res.push_str("Timestamp(");
+            write_time_unit(res, *unit);
+            match tz {
+                Some(tz) => {
+                    res.push_str(", Some(");
+                    write_quoted(res, tz);
+                    res.push_str("))");
+                }
+                None => res.push_str(", None)"),
+            }
+        }
+        DataType::Duration(unit) => {
+            res.push_str("Duration(");
+            write_time_unit(res, *unit);
+            res.push(')');
+        }
+        DataType::Decimal128(precision, scale) => {
+            let _ = write!(res, "Decimal128({precision}, {scale})");
+        }
+        DataType::Struct(_) => res.push_str("Struct"),
+        DataType::List(_) => res.push_str("List"),
+        DataType::LargeList(_) => res.push_str("LargeList"),
+        DataType::FixedSizeList(_, n) => {
+            let _ = write!(res, "FixedSizeList({n})");
+        }
+        DataType::Map(_, sorted) => {
+            res.push_str(if *sorted { "Map(true)" } else { "Map(false)" });
+        }
+        DataType::Dictionary(key, value, sorted) => {
+            res.push_str("Dictionary(");
+            write_data_type(res, key);
+            res.push_str(", ");
+            write_data_type(res, value);
+            res.push_str(if *sorted { ", true)" } else { ", false)" });
+        }
+        DataType::Union(_, mode) => res.push_str(match mode {
+            UnionMode::Dense => "Union(Dense)",
+            UnionMode::Sparse => "Union(Sparse)",
+        }),
+    }
+}
+
+fn write_time_unit(res: &mut String, unit: TimeUnit) {
+    res.push_str(match unit {
+        TimeUnit::Second => "Second",
+        TimeUnit::Millisecond => "Millisecond",
+        TimeUnit::Microsecond => "Microsecond",
+        TimeUnit::Nanosecond => "Nanosecond",
+    });
+}
+
+/// Write a double-quoted string, escaping quotes, backslashes and control
+/// characters
+fn write_quoted(res: &mut String, value: &str) {
+    res.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(res, "\\u{{{:x}}}", u32::from(c));
+            }
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+}
+
+fn write_children<'a>(
+    res: &mut String,
+    children: impl Iterator<Item = (Option<i8>, Option<&'a str>, &'a Field)>,
+) {
+    for (idx, (type_id, name, child)) in children.enumerate() {
+        if idx != 0 {
+            res.push_str(", ");
+        }
+        if let Some(type_id) = type_id {
+            let _ = write!(res, "{type_id}=");
+        }
+        write_field(res, name, child);
+    }
+}
+
+fn write_metadata(res: &mut String, metadata: &HashMap<String, String>) {
+    let metadata = metadata.iter().collect::<BTreeMap<_, _>>();
+    res.push('[');
+    for (idx, (key, value)) in metadata.into_iter().enumerate() {
+        if idx != 0 {
+            res.push_str(", ");
+        }
+        write_quoted(res, key);
+        res.push_str(": ");
+        write_quoted(res, value);
+    }
+    res.push(']');
+}
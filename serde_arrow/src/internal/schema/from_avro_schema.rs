@@ -0,0 +1,439 @@
+//! Import schemas from Avro schemas
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{de::Visitor, Deserialize, Serialize};
+
+use crate::internal::{
+    arrow::{DataType, Field, TimeUnit, UnionMode},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    schema::{union_null_variant, validate_field, SerdeArrowSchema},
+    utils::value::transmute,
+};
+
+impl SerdeArrowSchema {
+    /// Build a schema from an Avro schema
+    ///
+    /// The Avro schema can be given as any value that serializes into the JSON
+    /// form of Avro schemas, e.g., a `serde_json::Value` parsed from an
+    /// `.avsc` file. The root schema must be a record, its fields become the
+    /// fields of the schema. The types are mapped as follows:
+    ///
+    /// - `null`, `boolean`, `int`, `long`, `float`, `double`: `Null`,
+    ///   `Boolean`, `Int32`, `Int64`, `Float32`, `Float64`
+    /// - `bytes`, `string`: `LargeBinary`, `LargeUtf8`
+    /// - `record`: `Struct`
+    /// - `enum`: `Dictionary(UInt32, LargeUtf8)`
+    /// - `array`, `map`: `LargeList`, `Map` with `LargeUtf8` keys
+    /// - `fixed`: `FixedSizeBinary(size)`
+    /// - unions: nullable fields for `["null", T]`, otherwise a `Union` with
+    ///   variants named after the type of each branch, e.g., `"long"` or the
+    ///   name of a record. For unions including `null`, a `Null` variant named
+    ///   `"null"` is added after all other variants. `None` values are written
+    ///   to this variant, i.e., the union maps to an `Option` of a Rust enum
+    ///   with the non-null branches as variants
+    ///
+    /// The logical types `date`, `time-millis`, `time-micros`,
+    /// `timestamp-{millis,micros,nanos}`,
+    /// `local-timestamp-{millis,micros,nanos}` and `decimal` are mapped to
+    /// `Date32`, `Time32`, `Time64`, `Timestamp` in UTC, `Timestamp` without
+    /// timezone and `Decimal128`. Other logical types use the underlying
+    /// type. Named types can be referenced by their name after their
+    /// definition, recursive types are not supported.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_json::json;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let schema = SerdeArrowSchema::from_avro_schema(json!({
+    ///     "type": "record",
+    ///     "name": "Event",
+    ///     "fields": [
+    ///         {"name": "id", "type": "long"},
+    ///         {"name": "timestamp", "type": {"type": "long", "logicalType": "timestamp-millis"}},
+    ///         {"name": "comment", "type": ["null", "string"], "default": null},
+    ///     ],
+    /// }))?;
+    ///
+    /// let expected = SerdeArrowSchema::from_value(json!([
+    ///     {"name": "id", "data_type": "I64"},
+    ///     {"name": "timestamp", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+    ///     {"name": "comment", "data_type": "LargeUtf8", "nullable": true},
+    /// ]))?;
+    /// assert_eq!(schema, expected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_avro_schema<T: Serialize>(schema: T) -> Result<Self> {
+        let root: AvroSchema = transmute(schema)?;
+        let mut converter = Converter {
+            named_types: HashMap::new(),
+        };
+        let (data_type, _) = converter.data_type(&root, None, "$")?;
+        let DataType::Struct(fields) = data_type else {
+            fail!("The root of an Avro schema must be a record");
+        };
+        Ok(Self {
+            fields,
+            metadata: HashMap::new(),
+        })
+    }
+}
+
+struct AvroContext<'a> {
+    path: &'a str,
+}
+
+impl<'a> Context for AvroContext<'a> {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", self.path);
+    }
+}
+
+struct Converter {
+    /// The data types of named types by full name, `None` while being defined
+    named_types: HashMap<String, Option<DataType>>,
+}
+
+impl Converter {
+    fn field(
+        &mut self,
+        name: &str,
+        schema: &AvroSchema,
+        namespace: Option<&str>,
+        path: &str,
+    ) -> Result<Field> {
+        let path = format!("{path}.{name}");
+        try_(|| {
+            let (data_type, nullable) = self.data_type(schema, namespace, &path)?;
+            let field = Field {
+                name: name.to_owned(),
+                nullable: nullable || matches!(data_type, DataType::Null),
+                data_type,
+                metadata: HashMap::new(),
+            };
+            validate_field(&field)?;
+            Ok(field)
+        })
+        .ctx(&AvroContext { path: &path })
+    }
+
+    /// Determine the data type and whether the schema is nullable
+    fn data_type(
+        &mut self,
+        schema: &AvroSchema,
+        namespace: Option<&str>,
+        path: &str,
+    ) -> Result<(DataType, bool)> {
+        match schema {
+            AvroSchema::Name(name) => Ok((self.named_data_type(name, namespace)?, false)),
+            AvroSchema::Union(variants) => self.union(variants, namespace, path),
+            AvroSchema::Object(object) => self.object(object, namespace, path),
+        }
+    }
+
+    fn named_data_type(&mut self, name: &str, namespace: Option<&str>) -> Result<DataType> {
+        if let Some(data_type) = primitive_data_type(name) {
+            return Ok(data_type);
+        }
+
+        let full_name = full_name(name, None, namespace);
+        let entry = match self.named_types.get(&full_name) {
+            Some(entry) => entry,
+            None => match self.named_types.get(name) {
+                Some(entry) => entry,
+                None => fail!("Unknown type {name:?}"),
+            },
+        };
+        match entry {
+            Some(data_type) => Ok(data_type.clone()),
+            None => fail!("Recursive types are not supported: {name:?}"),
+        }
+    }
+
+    fn object(
+        &mut self,
+        object: &AvroObject,
+        namespace: Option<&str>,
+        path: &str,
+    ) -> Result<(DataType, bool)> {
+        let AvroSchema::Name(ty) = object.ty.as_ref() else {
+            // e.g., `{"type": ["null", "string"]}`
+            return self.data_type(&object.ty, namespace, path);
+        };
+
+        if let Some(data_type) = self.logical_data_type(object, ty)? {
+            if ty == "fixed" {
+                let (full_name, _) = self.declare(object, namespace)?;
+                self.named_types.insert(full_name, Some(data_type.clone()));
+            }
+            return Ok((data_type, false));
+        }
+
+        let data_type = match ty.as_str() {
+            "record" | "error" => {
+                let (full_name, namespace) = self.declare(object, namespace)?;
+                let mut fields = Vec::new();
+                for field in &object.fields {
+                    fields.push(self.field(&field.name, &field.ty, namespace.as_deref(), path)?);
+                }
+                let data_type = DataType::Struct(fields);
+                self.named_types.insert(full_name, Some(data_type.clone()));
+                data_type
+            }
+            "enum" => {
+                let (full_name, _) = self.declare(object, namespace)?;
+                let data_type = DataType::Dictionary(
+                    Box::new(DataType::UInt32),
+                    Box::new(DataType::LargeUtf8),
+                    false,
+                );
+                self.named_types.insert(full_name, Some(data_type.clone()));
+                data_type
+            }
+            "fixed" => {
+                let (full_name, _) = self.declare(object, namespace)?;
+                let Some(size) = object.size else {
+                    fail!("Fixed types require a size");
+                };
+                let data_type = DataType::FixedSizeBinary(size);
+                self.named_types.insert(full_name, Some(data_type.clone()));
+                data_type
+            }
+            "array" => {
+                let Some(items) = object.items.as_deref() else {
+                    fail!("Arrays require items");
+                };
+                DataType::LargeList(Box::new(self.field("element", items, namespace, path)?))
+            }
+            "map" => {
+                let Some(values) = object.values.as_deref() else {
+                    fail!("Maps require values");
+                };
+                let key = Field {
+                    name: String::from("key"),
+                    data_type: DataType::LargeUtf8,
+                    nullable: false,
+                    metadata: HashMap::new(),
+                };
+                let value = self.field("value", values, namespace, path)?;
+                let entries = Field {
+                    name: String::from("entries"),
+                    data_type: DataType::Struct(vec![key, value]),
+                    nullable: false,
+                    metadata: HashMap::new(),
+                };
+                DataType::Map(Box::new(entries), false)
+            }
+            name => self.named_data_type(name, namespace)?,
+        };
+        Ok((data_type, false))
+    }
+
+    /// Register a named type and return its full name and namespace
+    fn declare(
+        &mut self,
+        object: &AvroObject,
+        namespace: Option<&str>,
+    ) -> Result<(String, Option<String>)> {
+        let Some(name) = object.name.as_deref() else {
+            fail!("Named types require a name");
+        };
+        let full_name = full_name(name, object.namespace.as_deref(), namespace);
+        if self.named_types.contains_key(&full_name) {
+            fail!("Duplicate definition of type {full_name:?}");
+        }
+        self.named_types.insert(full_name.clone(), None);
+
+        let namespace = full_name
+            .rsplit_once('.')
+            .map(|(namespace, _)| namespace.to_owned());
+        Ok((full_name, namespace))
+    }
+
+    fn logical_data_type(&self, object: &AvroObject, ty: &str) -> Result<Option<DataType>> {
+        let Some(logical_type) = object.logical_type.as_deref() else {
+            return Ok(None);
+        };
+        let utc = || Some(String::from("UTC"));
+
+        let data_type = match (ty, logical_type) {
+            ("int", "date") => DataType::Date32,
+            ("int", "time-millis") => DataType::Time32(TimeUnit::Millisecond),
+            ("long", "time-micros") => DataType::Time64(TimeUnit::Microsecond),
+            ("long", "timestamp-millis") => DataType::Timestamp(TimeUnit::Millisecond, utc()),
+            ("long", "timestamp-micros") => DataType::Timestamp(TimeUnit::Microsecond, utc()),
+            ("long", "timestamp-nanos") => DataType::Timestamp(TimeUnit::Nanosecond, utc()),
+            ("long", "local-timestamp-millis") => DataType::Timestamp(TimeUnit::Millisecond, None),
+            ("long", "local-timestamp-micros") => DataType::Timestamp(TimeUnit::Microsecond, None),
+            ("long", "local-timestamp-nanos") => DataType::Timestamp(TimeUnit::Nanosecond, None),
+            ("bytes" | "fixed", "decimal") => {
+                let Some(precision) = object.precision else {
+                    fail!("Decimal logical types require a precision");
+                };
+                DataType::Decimal128(precision, object.scale.unwrap_or_default())
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(data_type))
+    }
+
+    fn union(
+        &mut self,
+        variants: &[AvroSchema],
+        namespace: Option<&str>,
+        path: &str,
+    ) -> Result<(DataType, bool)> {
+        let mut nullable = false;
+        let mut non_null_variants = Vec::new();
+        for variant in variants {
+            if matches!(variant, AvroSchema::Name(name) if name == "null") {
+                nullable = true;
+            } else {
+                non_null_variants.push(variant);
+            }
+        }
+
+        match non_null_variants.as_slice() {
+            [] => Ok((DataType::Null, true)),
+            [variant] => {
+                let (data_type, variant_nullable) = self.data_type(variant, namespace, path)?;
+                Ok((data_type, nullable || variant_nullable))
+            }
+            variants => {
+                let mut children = Vec::new();
+                for (idx, variant) in variants.iter().enumerate() {
+                    let name = variant_name(variant);
+                    let field = self.field(&name, variant, namespace, path)?;
+                    children.push((i8::try_from(idx)?, field));
+                }
+                // dense unions have no validity, null is an explicit trailing variant
+                if nullable {
+                    children.push((i8::try_from(children.len())?, union_null_variant()));
+                }
+                Ok((DataType::Union(children, UnionMode::Dense), false))
+            }
+        }
+    }
+}
+
+fn primitive_data_type(name: &str) -> Option<DataType> {
+    match name {
+        "null" => Some(DataType::Null),
+        "boolean" => Some(DataType::Boolean),
+        "int" => Some(DataType::Int32),
+        "long" => Some(DataType::Int64),
+        "float" => Some(DataType::Float32),
+        "double" => Some(DataType::Float64),
+        "bytes" => Some(DataType::LargeBinary),
+        "string" => Some(DataType::LargeUtf8),
+        _ => None,
+    }
+}
+
+/// The full name of a named type following the namespace rules of Avro
+fn full_name(name: &str, namespace: Option<&str>, enclosing_namespace: Option<&str>) -> String {
+    if name.contains('.') {
+        return name.to_owned();
+    }
+    match namespace.or(enclosing_namespace) {
+        Some(namespace) if !namespace.is_empty() => format!("{namespace}.{name}"),
+        _ => name.to_owned(),
+    }
+}
+
+/// The name of a union variant: the name of named types or the type otherwise
+fn variant_name(schema: &AvroSchema) -> String {
+    let name = match schema {
+        AvroSchema::Name(name) => name.as_str(),
+        AvroSchema::Union(_) => "union",
+        AvroSchema::Object(object) => match (object.name.as_deref(), object.ty.as_ref()) {
+            (Some(name), _) => name,
+            (None, AvroSchema::Name(ty)) => ty.as_str(),
+            (None, _) => "union",
+        },
+    };
+    match name.rsplit_once('.') {
+        Some((_, name)) => name.to_owned(),
+        None => name.to_owned(),
+    }
+}
+
+#[derive(Debug)]
+enum AvroSchema {
+    /// A primitive type or a reference to a named type
+    Name(String),
+    Union(Vec<AvroSchema>),
+    Object(AvroObject),
+}
+
+#[derive(Debug, Deserialize)]
+struct AvroObject {
+    #[serde(rename = "type")]
+    ty: Box<AvroSchema>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    namespace: Option<String>,
+    #[serde(default)]
+    fields: Vec<AvroField>,
+    #[serde(default)]
+    items: Option<Box<AvroSchema>>,
+    #[serde(default)]
+    values: Option<Box<AvroSchema>>,
+    #[serde(default)]
+    size: Option<i32>,
+    #[serde(default, rename = "logicalType")]
+    logical_type: Option<String>,
+    #[serde(default)]
+    precision: Option<u8>,
+    #[serde(default)]
+    scale: Option<i8>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AvroField {
+    name: String,
+    #[serde(rename = "type")]
+    ty: AvroSchema,
+}
+
+impl<'de> Deserialize<'de> for AvroSchema {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VisitorImpl;
+
+        impl<'de> Visitor<'de> for VisitorImpl {
+            type Value = AvroSchema;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a type name, a union or a schema object")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(AvroSchema::Name(v.to_owned()))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut variants = Vec::new();
+                while let Some(variant) = seq.next_element::<AvroSchema>()? {
+                    variants.push(variant);
+                }
+                Ok(AvroSchema::Union(variants))
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                map: A,
+            ) -> Result<Self::Value, A::Error> {
+                let object =
+                    AvroObject::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+                Ok(AvroSchema::Object(object))
+            }
+        }
+
+        deserializer.deserialize_any(VisitorImpl)
+    }
+}
@@ -0,0 +1,624 @@
+//! Import schemas from JSON Schema documents
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{de::Visitor, Deserialize, Serialize};
+
+use crate::internal::{
+    arrow::{DataType, Field, TimeUnit, UnionMode},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    schema::{
+        extensions::EXTENSION_NAME_KEY, union_null_variant, validate_field, SerdeArrowSchema,
+        Strategy, STRATEGY_KEY, UNION_NULL_VARIANT,
+    },
+    utils::value::transmute,
+};
+
+impl SerdeArrowSchema {
+    /// Build a schema from a JSON Schema document
+    ///
+    /// The document can be given as any value that serializes into a JSON
+    /// Schema, e.g., a `serde_json::Value`. The root schema must describe an
+    /// object, its properties become the fields of the schema. The types are
+    /// mapped as follows:
+    ///
+    /// - `object` with `properties`: `Struct`, properties not listed in
+    ///   `required` are nullable
+    /// - `object` with only `additionalProperties`: `Map` with `LargeUtf8` keys
+    /// - `array` with `items`: `LargeList`, with `prefixItems`: `Struct` with
+    ///   the `TupleAsStruct` strategy
+    /// - `string`: `LargeUtf8`, with `format: date-time` a
+    ///   `Timestamp(Millisecond, Some("UTC"))` with the `UtcStrAsDate64`
+    ///   strategy, with `format: date` a `Date32`, with `format: uuid`,
+    ///   `ipv4` or `ipv6` a `FixedSizeBinary(16)` with the matching strategy
+    /// - `integer`, `number`, `boolean`, `null`: `Int64`, `Float64`,
+    ///   `Boolean`, `Null`
+    /// - `enum` of strings: `Dictionary(UInt32, LargeUtf8)`
+    /// - `oneOf` / `anyOf`: a `Union`. Variants that are objects with a
+    ///   single required property or string enums are mapped to the variants
+    ///   of externally tagged Rust enums, i.e., the layout used by serde.
+    ///   Other variants are named after their `title` or their position
+    ///
+    /// Types that include `null`, e.g., `"type": ["string", "null"]`, or
+    /// `oneOf` alternatives of `null` result in nullable fields. Nullable
+    /// unions get an explicit `Null` variant named `"null"` after all other
+    /// variants, that `None` values are written to. References
+    /// to `definitions` or `$defs` are resolved, recursive references are not
+    /// supported. Fields are ordered as the properties of the document, e.g.,
+    /// alphabetically for `serde_json::Value` without the `preserve_order`
+    /// feature.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_json::json;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let schema = SerdeArrowSchema::from_json_schema(json!({
+    ///     "type": "object",
+    ///     "properties": {
+    ///         "id": {"type": "integer"},
+    ///         "timestamp": {"type": "string", "format": "date-time"},
+    ///         "tags": {"type": "array", "items": {"type": "string"}},
+    ///     },
+    ///     "required": ["id", "timestamp"],
+    /// }))?;
+    ///
+    /// let expected = SerdeArrowSchema::from_value(json!([
+    ///     {"name": "id", "data_type": "I64"},
+    ///     {
+    ///         "name": "tags",
+    ///         "data_type": "LargeList",
+    ///         "nullable": true,
+    ///         "children": [{"name": "element", "data_type": "LargeUtf8"}],
+    ///     },
+    ///     {
+    ///         "name": "timestamp",
+    ///         "data_type": "Timestamp(Millisecond, Some(\"UTC\"))",
+    ///         "strategy": "UtcStrAsDate64",
+    ///     },
+    /// ]))?;
+    /// assert_eq!(schema, expected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_json_schema<T: Serialize>(schema: T) -> Result<Self> {
+        let root: JsonSchema = transmute(schema)?;
+
+        let mut definitions = BTreeMap::new();
+        for (name, schema) in &root.definitions {
+            definitions.insert(format!("#/definitions/{name}"), schema);
+        }
+        for (name, schema) in &root.defs {
+            definitions.insert(format!("#/$defs/{name}"), schema);
+        }
+
+        let mut converter = Converter {
+            definitions,
+            references: Vec::new(),
+        };
+        let (schema, _) = converter.resolve(&root)?;
+        let DataType::Struct(fields) = converter.data_type(schema, "$")?.0 else {
+            fail!("The root of a JSON Schema must be an object with properties");
+        };
+        Ok(Self {
+            fields,
+            metadata: HashMap::new(),
+        })
+    }
+}
+
+struct JsonSchemaContext<'a> {
+    path: &'a str,
+}
+
+impl<'a> Context for JsonSchemaContext<'a> {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", self.path);
+    }
+}
+
+struct Converter<'a> {
+    definitions: BTreeMap<String, &'a JsonSchema>,
+    references: Vec<&'a str>,
+}
+
+impl<'a> Converter<'a> {
+    /// Follow references and single element `allOf`s
+    ///
+    /// The followed references are recorded to detect recursive types, the
+    /// caller must pop them after the schema has been converted.
+    fn resolve(&mut self, schema: &'a JsonSchema) -> Result<(&'a JsonSchema, usize)> {
+        let mut schema = schema;
+        let mut pushed = 0;
+        loop {
+            if let Some(reference) = schema.reference.as_deref() {
+                if self.references.contains(&reference) {
+                    fail!("Recursive references are not supported: {reference:?}");
+                }
+                let Some(target) = self.definitions.get(reference) else {
+                    fail!("Unknown reference {reference:?}: only references to definitions are supported");
+                };
+                self.references.push(reference);
+                pushed += 1;
+                schema = target;
+            } else if let [inner] = schema.all_of.as_slice() {
+                schema = inner;
+            } else if !schema.all_of.is_empty() {
+                fail!("allOf with multiple schemas is not supported");
+            } else {
+                return Ok((schema, pushed));
+            }
+        }
+    }
+
+    fn field(&mut self, name: &str, schema: &'a JsonSchema, path: &str) -> Result<Field> {
+        let path = format!("{path}.{name}");
+        try_(|| {
+            let (schema, pushed) = self.resolve(schema)?;
+            let res = self.data_type(schema, &path);
+            self.references.truncate(self.references.len() - pushed);
+            let (data_type, nullable, metadata) = res?;
+
+            let field = Field {
+                name: name.to_owned(),
+                nullable: matches!(data_type, DataType::Null),
+                data_type,
+                metadata,
+            };
+            let field = if nullable {
+                into_nullable(field)?
+            } else {
+                field
+            };
+            validate_field(&field)?;
+            Ok(field)
+        })
+        .ctx(&JsonSchemaContext { path: &path })
+    }
+
+    fn data_type(
+        &mut self,
+        schema: &'a JsonSchema,
+        path: &str,
+    ) -> Result<(DataType, bool, HashMap<String, String>)> {
+        let mut nullable = schema.nullable;
+        let mut metadata = HashMap::new();
+
+        let mut types = Vec::new();
+        for ty in schema.ty.as_slice() {
+            if ty == "null" {
+                nullable = true;
+            } else {
+                types.push(ty.as_str());
+            }
+        }
+
+        if !schema.one_of.is_empty() || !schema.any_of.is_empty() {
+            let variants = if !schema.one_of.is_empty() {
+                &schema.one_of
+            } else {
+                &schema.any_of
+            };
+            let (data_type, variants_nullable) = self.union(variants, path)?;
+            return Ok((data_type, nullable || variants_nullable, metadata));
+        }
+
+        let values = match (&schema.enum_values, &schema.const_value) {
+            (Some(values), _) => values.as_slice(),
+            (None, Some(value)) => std::slice::from_ref(value),
+            (None, None) => &[],
+        };
+        if !values.is_empty() {
+            let (data_type, values_nullable) = literal_data_type(values)?;
+            return Ok((data_type, nullable || values_nullable, metadata));
+        }
+
+        let ty = match types.as_slice() {
+            [ty] => *ty,
+            [] if schema.properties.is_some() || schema.additional_properties.is_some() => "object",
+            [] if schema.items.is_some() || !schema.prefix_items.is_empty() => "array",
+            [] if nullable => "null",
+            [] => fail!("Cannot determine the type of the schema"),
+            types => fail!("Multiple types are not supported: {types:?}"),
+        };
+
+        let data_type = match ty {
+            "null" => DataType::Null,
+            "boolean" => DataType::Boolean,
+            "integer" => DataType::Int64,
+            "number" => DataType::Float64,
+            "string" => {
+                let (data_type, strategy) = string_data_type(schema.format.as_deref());
+                if let Some(strategy) = strategy {
+                    if matches!(strategy, Strategy::UuidStrAsFixedSizeBinary) {
+                        metadata.insert(EXTENSION_NAME_KEY.into(), "arrow.uuid".into());
+                    }
+                    metadata.insert(STRATEGY_KEY.to_owned(), strategy.to_string());
+                }
+                data_type
+            }
+            "array" => {
+                let prefix_items = match &schema.items {
+                    Some(Items::Tuple(items)) => items.as_slice(),
+                    _ => schema.prefix_items.as_slice(),
+                };
+                if !prefix_items.is_empty() {
+                    let mut fields = Vec::new();
+                    for (idx, item) in prefix_items.iter().enumerate() {
+                        fields.push(self.field(&idx.to_string(), item, path)?);
+                    }
+                    metadata.insert(STRATEGY_KEY.to_owned(), Strategy::TupleAsStruct.to_string());
+                    DataType::Struct(fields)
+                } else if let Some(Items::Schema(items)) = &schema.items {
+                    DataType::LargeList(Box::new(self.field("element", items, path)?))
+                } else {
+                    fail!("Arrays require an items schema");
+                }
+            }
+            "object" => {
+                if let Some(properties) = &schema.properties {
+                    let mut fields = Vec::new();
+                    for (name, property) in &properties.0 {
+                        let mut field = self.field(name, property, path)?;
+                        if !schema.required.contains(name) {
+                            field = into_nullable(field)?;
+                        }
+                        fields.push(field);
+                    }
+                    DataType::Struct(fields)
+                } else if let Some(AdditionalProperties::Schema(value)) =
+                    &schema.additional_properties
+                {
+                    let key = Field {
+                        name: String::from("key"),
+                        data_type: DataType::LargeUtf8,
+                        nullable: false,
+                        metadata: HashMap::new(),
+                    };
+                    let value = self.field("value", value, path)?;
+                    let entries = Field {
+                        name: String::from("entries"),
+                        data_type: DataType::Struct(vec![key, value]),
+                        nullable: false,
+                        metadata: HashMap::new(),
+                    };
+                    DataType::Map(Box::new(entries), false)
+                } else {
+                    fail!("Objects require properties or an additionalProperties schema");
+                }
+            }
+            ty => fail!("Unsupported type {ty:?}"),
+        };
+        Ok((data_type, nullable, metadata))
+    }
+
+    /// Convert `oneOf` / `anyOf` alternatives into a union
+    ///
+    /// Returns the data type and whether `null` is one of the alternatives.
+    fn union(&mut self, variants: &'a [JsonSchema], path: &str) -> Result<(DataType, bool)> {
+        let mut nullable = false;
+        let mut tagged = false;
+        let mut fields = Vec::new();
+
+        for (idx, variant) in variants.iter().enumerate() {
+            let (variant, pushed) = self.resolve(variant)?;
+            let res = self.variant_fields(idx, variant, path);
+            self.references.truncate(self.references.len() - pushed);
+
+            match res? {
+                None => nullable = true,
+                Some((variant_fields, variant_tagged)) => {
+                    fields.extend(variant_fields);
+                    tagged |= variant_tagged;
+                }
+            }
+        }
+
+        // a single alternative besides null, e.g., `oneOf: [{...}, {"type": "null"}]`
+        if fields.len() == 1 && !tagged {
+            let field = fields.remove(0);
+            return Ok((field.data_type, nullable || field.nullable));
+        }
+
+        let mut children = Vec::new();
+        for (idx, field) in fields.into_iter().enumerate() {
+            children.push((i8::try_from(idx)?, field));
+        }
+        Ok((DataType::Union(children, UnionMode::Dense), nullable))
+    }
+
+    /// The union fields of a variant or `None` for null alternatives
+    ///
+    /// The flag is `true` for variants of externally tagged enums.
+    fn variant_fields(
+        &mut self,
+        idx: usize,
+        variant: &'a JsonSchema,
+        path: &str,
+    ) -> Result<Option<(Vec<Field>, bool)>> {
+        if is_null_schema(variant) {
+            return Ok(None);
+        }
+
+        // unit variants of externally tagged enums
+        if let Some(values) = &variant.enum_values {
+            if let Some(names) = values
+                .iter()
+                .map(|value| match value {
+                    Literal::Str(name) => Some(name),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()
+            {
+                let fields = names
+                    .into_iter()
+                    .map(|name| Field {
+                        name: name.to_owned(),
+                        data_type: DataType::Null,
+                        nullable: true,
+                        metadata: HashMap::new(),
+                    })
+                    .collect();
+                return Ok(Some((fields, true)));
+            }
+        }
+
+        // variants with data of externally tagged enums
+        if let (Some(properties), [required]) = (&variant.properties, variant.required.as_slice()) {
+            if let [(name, schema)] = properties.0.as_slice() {
+                if name == required {
+                    return Ok(Some((vec![self.field(name, schema, path)?], true)));
+                }
+            }
+        }
+
+        let name = match &variant.title {
+            Some(title) => title.to_owned(),
+            None => idx.to_string(),
+        };
+        Ok(Some((vec![self.field(&name, variant, path)?], false)))
+    }
+}
+
+/// Allow null values for a field
+///
+/// Dense unions have no validity, instead an explicit null variant is added.
+fn into_nullable(mut field: Field) -> Result<Field> {
+    if let DataType::Union(children, _) = &mut field.data_type {
+        let has_null_variant = children.iter().any(|(_, child)| {
+            child.name == UNION_NULL_VARIANT && matches!(child.data_type, DataType::Null)
+        });
+        if !has_null_variant {
+            children.push((i8::try_from(children.len())?, union_null_variant()));
+        }
+    } else {
+        field.nullable = true;
+    }
+    Ok(field)
+}
+
+fn is_null_schema(schema: &JsonSchema) -> bool {
+    match (&schema.enum_values, schema.ty.as_slice()) {
+        (Some(values), _) => values.iter().all(|value| matches!(value, Literal::Null)),
+        (None, [ty]) => ty == "null",
+        (None, _) => false,
+    }
+}
+
+fn string_data_type(format: Option<&str>) -> (DataType, Option<Strategy>) {
+    match format {
+        Some("date-time") => (
+            DataType::Timestamp(TimeUnit::Millisecond, Some(String::from("UTC"))),
+            Some(Strategy::UtcStrAsDate64),
+        ),
+        Some("date") => (DataType::Date32, None),
+        Some("uuid") => (
+            DataType::FixedSizeBinary(16),
+            Some(Strategy::UuidStrAsFixedSizeBinary),
+        ),
+        Some("ipv4" | "ipv6") => (
+            DataType::FixedSizeBinary(16),
+            Some(Strategy::IpStrAsFixedSizeBinary),
+        ),
+        _ => (DataType::LargeUtf8, None),
+    }
+}
+
+/// The data type of `enum` or `const` values and whether they include `null`
+fn literal_data_type(values: &[Literal]) -> Result<(DataType, bool)> {
+    let nullable = values.iter().any(|value| matches!(value, Literal::Null));
+    let mut data_type = None;
+
+    for value in values {
+        let value_type = match value {
+            Literal::Null => continue,
+            Literal::Bool => DataType::Boolean,
+            Literal::Int => DataType::Int64,
+            Literal::Float => DataType::Float64,
+            Literal::Str(_) => DataType::Dictionary(
+                Box::new(DataType::UInt32),
+                Box::new(DataType::LargeUtf8),
+                false,
+            ),
+        };
+        data_type = match (data_type, value_type) {
+            (None, value_type) => Some(value_type),
+            (Some(DataType::Int64), DataType::Float64) => Some(DataType::Float64),
+            (Some(DataType::Float64), DataType::Int64) => Some(DataType::Float64),
+            (Some(data_type), value_type) if data_type == value_type => Some(data_type),
+            _ => fail!("Enum values of different types are not supported"),
+        };
+    }
+
+    Ok((data_type.unwrap_or(DataType::Null), nullable))
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct JsonSchema {
+    #[serde(rename = "type")]
+    ty: OneOrMany,
+    format: Option<String>,
+    title: Option<String>,
+    nullable: bool,
+    properties: Option<Properties>,
+    required: Vec<String>,
+    #[serde(rename = "additionalProperties")]
+    additional_properties: Option<AdditionalProperties>,
+    items: Option<Items>,
+    #[serde(rename = "prefixItems")]
+    prefix_items: Vec<JsonSchema>,
+    #[serde(rename = "enum")]
+    enum_values: Option<Vec<Literal>>,
+    #[serde(rename = "const")]
+    const_value: Option<Literal>,
+    #[serde(rename = "oneOf")]
+    one_of: Vec<JsonSchema>,
+    #[serde(rename = "anyOf")]
+    any_of: Vec<JsonSchema>,
+    #[serde(rename = "allOf")]
+    all_of: Vec<JsonSchema>,
+    #[serde(rename = "$ref")]
+    reference: Option<String>,
+    definitions: BTreeMap<String, JsonSchema>,
+    #[serde(rename = "$defs")]
+    defs: BTreeMap<String, JsonSchema>,
+}
+
+#[derive(Debug, Default)]
+struct OneOrMany(Vec<String>);
+
+impl OneOrMany {
+    fn as_slice(&self) -> &[String] {
+        self.0.as_slice()
+    }
+}
+
+impl<'de> Deserialize<'de> for OneOrMany {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VisitorImpl;
+
+        impl<'de> Visitor<'de> for VisitorImpl {
+            type Value = OneOrMany;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a type name or a sequence of type names")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(OneOrMany(vec![v.to_owned()]))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut types = Vec::new();
+                while let Some(ty) = seq.next_element::<String>()? {
+                    types.push(ty);
+                }
+                Ok(OneOrMany(types))
+            }
+        }
+
+        deserializer.deserialize_any(VisitorImpl)
+    }
+}
+
+/// The properties of an object in the order of the document
+#[derive(Debug, Default)]
+struct Properties(Vec<(String, JsonSchema)>);
+
+impl<'de> Deserialize<'de> for Properties {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VisitorImpl;
+
+        impl<'de> Visitor<'de> for VisitorImpl {
+            type Value = Properties;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a map of property schemas")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut properties = Vec::new();
+                while let Some(entry) = map.next_entry::<String, JsonSchema>()? {
+                    properties.push(entry);
+                }
+                Ok(Properties(properties))
+            }
+        }
+
+        deserializer.deserialize_map(VisitorImpl)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AdditionalProperties {
+    Bool(#[allow(unused)] bool),
+    Schema(Box<JsonSchema>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Items {
+    Schema(Box<JsonSchema>),
+    Tuple(Vec<JsonSchema>),
+}
+
+/// A value of `enum` or `const`
+#[derive(Debug)]
+enum Literal {
+    Null,
+    Bool,
+    Int,
+    Float,
+    Str(String),
+}
+
+impl<'de> Deserialize<'de> for Literal {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VisitorImpl;
+
+        impl<'de> Visitor<'de> for VisitorImpl {
+            type Value = Literal;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "null, a boolean, a number or a string")
+            }
+
+            fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+                Ok(Literal::Null)
+            }
+
+            fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+                Ok(Literal::Null)
+            }
+
+            fn visit_bool<E: serde::de::Error>(self, _: bool) -> Result<Self::Value, E> {
+                Ok(Literal::Bool)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, _: i64) -> Result<Self::Value, E> {
+                Ok(Literal::Int)
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, _: u64) -> Result<Self::Value, E> {
+                Ok(Literal::Int)
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, _: f64) -> Result<Self::Value, E> {
+                Ok(Literal::Float)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(Literal::Str(v.to_owned()))
+            }
+        }
+
+        deserializer.deserialize_any(VisitorImpl)
+    }
+}
@@ -11,22 +11,90 @@ use crate::internal::{
     chrono,
     error::{fail, try_, Context, ContextSupport, Error, Result},
     schema::{Strategy, TracingMode, TracingOptions},
+    utils::{decimal, str_formats},
 };
 
 use super::tracer::{
     ListTracer, MapTracer, StructMode, StructTracer, Tracer, TupleTracer, UnionVariant,
 };
 
+/// Determine the data type of a string sample according to the `guess_*` options
+fn guess_str_type(options: &TracingOptions, s: &str) -> (DataType, Option<Strategy>) {
+    if options.guess_dates {
+        if chrono::matches_naive_datetime(s) {
+            return (DataType::Date64, Some(Strategy::NaiveStrAsDate64));
+        } else if chrono::matches_utc_datetime(s) {
+            return (DataType::Date64, Some(Strategy::UtcStrAsDate64));
+        } else if chrono::matches_naive_time(s) {
+            return (DataType::Time64(TimeUnit::Nanosecond), None);
+        } else if chrono::matches_naive_date(s) {
+            return (DataType::Date32, None);
+        }
+    }
+    if options.guess_uuids && str_formats::parse_uuid(s).is_some() {
+        return (
+            DataType::FixedSizeBinary(16),
+            Some(Strategy::UuidStrAsFixedSizeBinary),
+        );
+    }
+    if options.guess_ip_addresses && str_formats::parse_ip_address(s).is_some() {
+        return (
+            DataType::FixedSizeBinary(16),
+            Some(Strategy::IpStrAsFixedSizeBinary),
+        );
+    }
+    if options.guess_decimals {
+        if let Some((precision, scale)) = decimal::infer_precision_and_scale(s.as_bytes()) {
+            if !options.exact_number_strings || roundtrips_as_decimal(s, precision, scale) {
+                return (DataType::Decimal128(precision, scale), None);
+            }
+        }
+    }
+    if options.guess_numbers && is_number_like(s) {
+        let exact = |formatted: String| !options.exact_number_strings || formatted == s;
+        if s.parse::<i64>().is_ok_and(|v| exact(v.to_string())) {
+            return (DataType::Int64, Some(Strategy::StrAsNumber));
+        } else if s.parse::<f64>().is_ok_and(|v| exact(v.to_string())) {
+            return (DataType::Float64, Some(Strategy::StrAsNumber));
+        }
+    }
+    (options.string_type(), None)
+}
+
+fn roundtrips_as_decimal(s: &str, precision: u8, scale: i8) -> bool {
+    let mut parse_buffer = [0; decimal::BUFFER_SIZE_I128];
+    let Ok(val) = decimal::DecimalParser::new(precision, scale, true)
+        .parse_decimal128(&mut parse_buffer, s.as_bytes())
+    else {
+        return false;
+    };
+    let mut buffer = [0; decimal::BUFFER_SIZE_I128];
+    decimal::format_decimal(&mut buffer, val, scale) == s
+}
+
+/// Check that the string only contains characters used in numbers (excluding `inf` or `NaN`)
+fn is_number_like(s: &str) -> bool {
+    s.bytes().any(|c| c.is_ascii_digit())
+        && s.bytes()
+            .all(|c| c.is_ascii_digit() || matches!(c, b'+' | b'-' | b'.' | b'e' | b'E'))
+}
+
 impl Tracer {
     pub fn from_samples<T: Serialize>(samples: T, options: TracingOptions) -> Result<Self> {
         let options = options.tracing_mode(TracingMode::FromSamples);
         let mut tracer = Tracer::new(String::from("$"), String::from("$"), Arc::new(options));
-        samples.serialize(OuterSequenceSerializer(&mut tracer))?;
-        tracer.finish()?;
+        tracer.trace_samples(samples)?;
         tracer.check()?;
 
         Ok(tracer)
     }
+
+    /// Trace the given samples, accumulating the evidence with any previously
+    /// traced samples
+    pub fn trace_samples<T: Serialize>(&mut self, samples: T) -> Result<()> {
+        samples.serialize(OuterSequenceSerializer(self))?;
+        self.finish()
+    }
 }
 
 struct OuterSequenceSerializer<'a>(&'a mut Tracer);
@@ -278,43 +346,43 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
     type SerializeSeq = ListSerializer<'a>;
     type SerializeTuple = TupleSerializer<'a>;
     type SerializeTupleStruct = TupleSerializer<'a>;
-    type SerializeStructVariant = StructSerializer<'a>;
-    type SerializeTupleVariant = TupleSerializer<'a>;
+    type SerializeStructVariant = VariantSerializer<StructSerializer<'a>>;
+    type SerializeTupleVariant = VariantSerializer<TupleSerializer<'a>>;
 
     fn serialize_bool(self, _: bool) -> Result<Self::Ok> {
         try_(|| self.0.ensure_primitive(DataType::Boolean)).ctx(&self)
     }
 
-    fn serialize_i8(self, _: i8) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::Int8)).ctx(&self)
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_integer(DataType::Int8, i128::from(v))).ctx(&self)
     }
 
-    fn serialize_i16(self, _: i16) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::Int16)).ctx(&self)
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_integer(DataType::Int16, i128::from(v))).ctx(&self)
     }
 
-    fn serialize_i32(self, _: i32) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::Int32)).ctx(&self)
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_integer(DataType::Int32, i128::from(v))).ctx(&self)
     }
 
-    fn serialize_i64(self, _: i64) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::Int64)).ctx(&self)
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_integer(DataType::Int64, i128::from(v))).ctx(&self)
     }
 
-    fn serialize_u8(self, _: u8) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::UInt8)).ctx(&self)
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_integer(DataType::UInt8, i128::from(v))).ctx(&self)
     }
 
-    fn serialize_u16(self, _: u16) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::UInt16)).ctx(&self)
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_integer(DataType::UInt16, i128::from(v))).ctx(&self)
     }
 
-    fn serialize_u32(self, _: u32) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::UInt32)).ctx(&self)
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_integer(DataType::UInt32, i128::from(v))).ctx(&self)
     }
 
-    fn serialize_u64(self, _: u64) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::UInt64)).ctx(&self)
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_integer(DataType::UInt64, i128::from(v))).ctx(&self)
     }
 
     fn serialize_f32(self, _: f32) -> Result<Self::Ok> {
@@ -335,23 +403,8 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
 
     fn serialize_str(self, s: &str) -> Result<Self::Ok> {
         try_(|| {
-            #[allow(clippy::collapsible_else_if)]
-            let (ty, st) = if !self.0.get_options().guess_dates {
-                (self.0.get_options().string_type(), None)
-            } else {
-                if chrono::matches_naive_datetime(s) {
-                    (DataType::Date64, Some(Strategy::NaiveStrAsDate64))
-                } else if chrono::matches_utc_datetime(s) {
-                    (DataType::Date64, Some(Strategy::UtcStrAsDate64))
-                } else if chrono::matches_naive_time(s) {
-                    (DataType::Time64(TimeUnit::Nanosecond), None)
-                } else if chrono::matches_naive_date(s) {
-                    (DataType::Date32, None)
-                } else {
-                    (self.0.get_options().string_type(), None)
-                }
-            };
-            self.0.ensure_primitive_with_strategy(ty, st)
+            let (ty, st) = guess_str_type(self.0.get_options(), s);
+            self.0.ensure_string(ty, st, s)
         })
         .ctx(&self)
     }
@@ -481,6 +534,9 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
 
         try_(|| {
             let variant = self.ensure_union_variant(variant_name, variant_index)?;
+            if variant.tracer.ensure_max_depth()? {
+                return Ok(());
+            }
             variant.tracer.ensure_primitive(DataType::Null)
         })
         .ctx(&ctx)
@@ -498,7 +554,7 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
 
         try_(|| {
             let variant = self.ensure_union_variant(variant_name, variant_index)?;
-            value.serialize(TracerSerializer(&mut variant.tracer))
+            trace_child(&mut variant.tracer, value)
         })
         .ctx(&ctx)
     }
@@ -515,13 +571,16 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
 
         try_(|| {
             let variant = self.ensure_union_variant(variant_name, variant_index)?;
+            if variant.tracer.ensure_max_depth()? {
+                return Ok(VariantSerializer::MaxDepth);
+            }
             variant
                 .tracer
                 .ensure_struct::<&str>(&[], StructMode::Struct)?;
             let Tracer::Struct(tracer) = &mut variant.tracer else {
                 unreachable!();
             };
-            Ok(StructSerializer(tracer))
+            Ok(VariantSerializer::Traced(StructSerializer(tracer)))
         })
         .ctx(&ctx)
     }
@@ -538,16 +597,80 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
 
         try_(|| {
             let variant = self.ensure_union_variant(variant_name, variant_index)?;
+            if variant.tracer.ensure_max_depth()? {
+                return Ok(VariantSerializer::MaxDepth);
+            }
             variant.tracer.ensure_tuple(len)?;
             let Tracer::Tuple(tracer) = &mut variant.tracer else {
                 unreachable!();
             };
-            Ok(TupleSerializer::new(tracer))
+            Ok(VariantSerializer::Traced(TupleSerializer::new(tracer)))
         })
         .ctx(&ctx)
     }
 }
 
+/// Trace a nested value, unless it is nested deeper than `max_depth`
+fn trace_child<T: Serialize + ?Sized>(tracer: &mut Tracer, value: &T) -> Result<()> {
+    if tracer.ensure_max_depth()? {
+        return Ok(());
+    }
+    value.serialize(TracerSerializer(tracer))
+}
+
+/// Trace the fields of a struct or tuple variant, unless the variant is
+/// nested deeper than `max_depth`
+enum VariantSerializer<S> {
+    Traced(S),
+    MaxDepth,
+}
+
+impl<S: serde::ser::SerializeStructVariant<Ok = (), Error = Error>>
+    serde::ser::SerializeStructVariant for VariantSerializer<S>
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        match self {
+            Self::Traced(serializer) => serializer.serialize_field(key, value),
+            Self::MaxDepth => Ok(()),
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        match self {
+            Self::Traced(serializer) => serializer.end(),
+            Self::MaxDepth => Ok(()),
+        }
+    }
+}
+
+impl<S: serde::ser::SerializeTupleVariant<Ok = (), Error = Error>> serde::ser::SerializeTupleVariant
+    for VariantSerializer<S>
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        match self {
+            Self::Traced(serializer) => serializer.serialize_field(value),
+            Self::MaxDepth => Ok(()),
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        match self {
+            Self::Traced(serializer) => serializer.end(),
+            Self::MaxDepth => Ok(()),
+        }
+    }
+}
+
 struct StructSerializer<'a>(&'a mut StructTracer);
 
 impl<'a> Context for StructSerializer<'a> {
@@ -570,7 +693,7 @@ impl<'a> serde::ser::SerializeStruct for StructSerializer<'a> {
             let Some(field_tracer) = self.0.get_field_tracer_mut(field_idx) else {
                 unreachable!();
             };
-            value.serialize(TracerSerializer(field_tracer))
+            trace_child(field_tracer, value)
         })
         .ctx(self)
     }
@@ -594,7 +717,7 @@ impl<'a> serde::ser::SerializeStructVariant for StructSerializer<'a> {
             let Some(field_tracer) = self.0.get_field_tracer_mut(field_idx) else {
                 unreachable!();
             };
-            value.serialize(TracerSerializer(field_tracer))
+            trace_child(field_tracer, value)
         })
         .ctx(self)
     }
@@ -617,7 +740,7 @@ impl<'a> serde::ser::SerializeSeq for ListSerializer<'a> {
     type Error = Error;
 
     fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
-        try_(|| value.serialize(TracerSerializer(&mut self.0.item_tracer))).ctx(self)
+        try_(|| trace_child(&mut self.0.item_tracer, value)).ctx(self)
     }
 
     fn end(self) -> Result<Self::Ok> {
@@ -646,7 +769,7 @@ impl<'a> serde::ser::SerializeTuple for TupleSerializer<'a> {
     fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
         try_(|| {
             let pos = self.1;
-            value.serialize(TracerSerializer(self.0.field_tracer(pos)))?;
+            trace_child(self.0.field_tracer(pos), value)?;
             self.1 += 1;
             Ok(())
         })
@@ -665,7 +788,7 @@ impl<'a> serde::ser::SerializeTupleStruct for TupleSerializer<'a> {
     fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
         try_(|| {
             let pos = self.1;
-            value.serialize(TracerSerializer(self.0.field_tracer(pos)))?;
+            trace_child(self.0.field_tracer(pos), value)?;
             self.1 += 1;
             Ok(())
         })
@@ -684,7 +807,7 @@ impl<'a> serde::ser::SerializeTupleVariant for TupleSerializer<'a> {
     fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> where {
         try_(|| {
             let pos = self.1;
-            value.serialize(TracerSerializer(self.0.field_tracer(pos)))?;
+            trace_child(self.0.field_tracer(pos), value)?;
             self.1 += 1;
             Ok(())
         })
@@ -720,7 +843,7 @@ impl<'a> serde::ser::SerializeMap for MapSerializer<'a> {
                 *next_key = Some(key.serialize(SerializeToString)?);
                 Ok(())
             }
-            Self::AsMap(tracer) => key.serialize(TracerSerializer(&mut tracer.key_tracer)),
+            Self::AsMap(tracer) => trace_child(&mut tracer.key_tracer, key),
         })
         .ctx(self)
     }
@@ -735,9 +858,9 @@ impl<'a> serde::ser::SerializeMap for MapSerializer<'a> {
                 let Some(field_tracer) = tracer.get_field_tracer_mut(field_idx) else {
                     unreachable!();
                 };
-                value.serialize(TracerSerializer(field_tracer))
+                trace_child(field_tracer, value)
             }
-            Self::AsMap(tracer) => value.serialize(TracerSerializer(&mut tracer.value_tracer)),
+            Self::AsMap(tracer) => trace_child(&mut tracer.value_tracer, value),
         })
         .ctx(self)
     }
@@ -1,6 +1,7 @@
 //! Support for SchemaLike::from_type
 #[cfg(test)]
 mod test_error_messages;
+mod type_hints;
 
 use std::{collections::BTreeMap, sync::Arc};
 
@@ -10,12 +11,13 @@ use serde::{
 };
 
 use crate::internal::{
-    arrow::DataType,
+    arrow::{DataType, Field},
     error::{fail, try_, Context, ContextSupport, Error, Result},
     schema::{TracingMode, TracingOptions},
 };
 
 use super::tracer::{StructField, StructMode, Tracer};
+use type_hints::PlaceholderDeserializer;
 
 impl Tracer {
     pub fn from_type<'de, T: Deserialize<'de>>(options: TracingOptions) -> Result<Self> {
@@ -44,7 +46,8 @@ impl Tracer {
                         concat!(
                             "{message}{maybe_period} ",
                             "It seems that `from_type` encountered a non self describing type. ",
-                            "Consider using `from_samples` instead. ",
+                            "Consider using `from_samples` instead or adding a type hint via ",
+                            "`TracingOptions::path_type_hint` or `TracingOptions::type_hint`. ",
                         ),
                         message = message,
                         maybe_period = if message.trim_end().ends_with('.') {
@@ -85,21 +88,50 @@ impl<'a> Context for TraceAny<'a> {
     }
 }
 
+impl<'a> TraceAny<'a> {
+    fn get_type_hint(&self, name: Option<&str>) -> Option<Field> {
+        let path = self.0.get_path();
+        if let Some(field) = self.0.get_options().get_type_hint(path, name) {
+            return Some(field.clone());
+        }
+        // values nested deeper than `max_depth` are stored as JSON and not traced
+        self.0.get_max_depth_field()
+    }
+}
+
+/// Use placeholder values if a type hint is registered for the current field
+macro_rules! apply_type_hint {
+    ($this:ident, $name:expr, $method:ident($($arg:expr),*)) => {
+        if let Some(field) = $this.get_type_hint($name) {
+            return try_(|| {
+                $this.0.ensure_hinted(&field)?;
+                PlaceholderDeserializer::new(&field).$method($($arg),*)
+            })
+            .ctx(&$this);
+        }
+    };
+}
+
 impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     type Error = Error;
 
-    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_any(visitor));
         fail!(
             in self,
             concat!(
             "Non self describing types cannot be traced with `from_type`. ",
             "Consider using `from_samples`. ",
             "One example is `serde_json::Value`: ",
-            "the schema depends on the JSON content and cannot be determined from the type alone."
+            "the schema depends on the JSON content and cannot be determined from the type alone. ",
+            "Other examples are `#[serde(untagged)]` enums and structs with `#[serde(flatten)]` fields: ",
+            "serde buffers their content and does not expose the types of the variants or fields. ",
+            "These types can be traced with a type hint via `TracingOptions::path_type_hint`."
         ));
     }
 
     fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_bool(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::Boolean)?;
             visitor.visit_bool(Default::default())
@@ -108,6 +140,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_i8(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::Int8)?;
             visitor.visit_i8(Default::default())
@@ -116,6 +149,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_i16(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::Int16)?;
             visitor.visit_i16(Default::default())
@@ -124,6 +158,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_i32(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::Int32)?;
             visitor.visit_i32(Default::default())
@@ -132,6 +167,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_i64(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::Int64)?;
             visitor.visit_i64(Default::default())
@@ -140,6 +176,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_u8(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::UInt8)?;
             visitor.visit_u8(Default::default())
@@ -148,6 +185,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_u16(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::UInt16)?;
             visitor.visit_u16(Default::default())
@@ -156,6 +194,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_u32(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::UInt32)?;
             visitor.visit_u32(Default::default())
@@ -164,6 +203,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_u64(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::UInt64)?;
             visitor.visit_u64(Default::default())
@@ -172,6 +212,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_f32(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::Float32)?;
             visitor.visit_f32(Default::default())
@@ -180,6 +221,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_f64(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::Float64)?;
             visitor.visit_f64(Default::default())
@@ -188,6 +230,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_char(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::UInt32)?;
             visitor.visit_char(Default::default())
@@ -196,6 +239,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_str(visitor));
         try_(|| {
             self.0
                 .ensure_utf8(self.0.get_options().string_type(), None)?;
@@ -205,6 +249,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_string(visitor));
         try_(|| {
             self.0
                 .ensure_utf8(self.0.get_options().string_type(), None)?;
@@ -214,6 +259,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_bytes(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::LargeBinary)?;
             visitor.visit_borrowed_bytes(&[])
@@ -222,6 +268,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_byte_buf(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::LargeBinary)?;
             visitor.visit_byte_buf(Default::default())
@@ -230,6 +277,10 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.get_type_hint(None).is_some() {
+            self.0.mark_nullable();
+        }
+        apply_type_hint!(self, None, deserialize_option(visitor));
         try_(|| {
             self.0.mark_nullable();
             visitor.visit_some(TraceAny(&mut *self.0))
@@ -238,6 +289,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_unit(visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::Null)?;
             visitor.visit_unit()
@@ -247,9 +299,10 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
 
     fn deserialize_unit_struct<V: Visitor<'de>>(
         self,
-        _name: &'static str,
+        name: &'static str,
         visitor: V,
     ) -> Result<V::Value> {
+        apply_type_hint!(self, Some(name), deserialize_unit_struct(name, visitor));
         try_(|| {
             self.0.ensure_primitive(DataType::Null)?;
             visitor.visit_unit()
@@ -259,13 +312,15 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
 
     fn deserialize_newtype_struct<V: Visitor<'de>>(
         self,
-        _name: &'static str,
+        name: &'static str,
         visitor: V,
     ) -> Result<V::Value> {
+        apply_type_hint!(self, Some(name), deserialize_newtype_struct(name, visitor));
         try_(|| visitor.visit_newtype_struct(TraceAny(&mut *self.0))).ctx(&self)
     }
 
     fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_seq(visitor));
         try_(|| {
             self.0.ensure_list()?;
             let Tracer::List(tracer) = self.0 else {
@@ -278,6 +333,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     }
 
     fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_tuple(len, visitor));
         try_(|| {
             self.0.ensure_tuple(len)?;
             let Tracer::Tuple(tracer) = self.0 else {
@@ -294,20 +350,27 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
 
     fn deserialize_tuple_struct<V: Visitor<'de>>(
         self,
-        _name: &'static str,
+        name: &'static str,
         len: usize,
         visitor: V,
     ) -> Result<V::Value> {
+        apply_type_hint!(
+            self,
+            Some(name),
+            deserialize_tuple_struct(name, len, visitor)
+        );
         try_(|| TraceAny(&mut *self.0).deserialize_tuple(len, visitor)).ctx(&self)
     }
 
     fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        apply_type_hint!(self, None, deserialize_map(visitor));
         try_(|| {
             if self.0.get_options().map_as_struct {
                 fail!(concat!(
                     "Cannot trace maps as structs with `from_type`. ",
-                    "The struct fields cannot be known from the type alone.",
-                    "Consider using `from_samples`. ",
+                    "The struct fields cannot be known from the type alone. ",
+                    "Structs with `#[serde(flatten)]` fields are also deserialized as maps. ",
+                    "Consider using `from_samples` or a type hint via `TracingOptions::path_type_hint`.",
                 ));
             }
 
@@ -326,10 +389,11 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
 
     fn deserialize_struct<V: Visitor<'de>>(
         self,
-        _name: &'static str,
+        name: &'static str,
         fields: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value> {
+        apply_type_hint!(self, Some(name), deserialize_struct(name, fields, visitor));
         try_(|| {
             self.0.ensure_struct(fields, StructMode::Struct)?;
             let Tracer::Struct(tracer) = self.0 else {
@@ -347,10 +411,11 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
 
     fn deserialize_enum<V: Visitor<'de>>(
         self,
-        _name: &'static str,
+        name: &'static str,
         variants: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value> {
+        apply_type_hint!(self, Some(name), deserialize_enum(name, variants, visitor));
         try_(|| {
             self.0.ensure_union(variants)?;
             let Tracer::Union(tracer) = self.0 else {
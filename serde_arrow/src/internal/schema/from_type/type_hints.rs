@@ -0,0 +1,381 @@
+//! Placeholder values for fields given by type hints
+use serde::de::{DeserializeSeed, Deserializer, Visitor};
+
+use crate::internal::{
+    arrow::{DataType, Field},
+    error::{fail, Error, Result},
+    schema::{get_strategy_from_metadata, Strategy},
+};
+
+/// Get a placeholder string that can be parsed into values of the field
+fn placeholder_str(field: &Field) -> &'static str {
+    let strategy = get_strategy_from_metadata(&field.metadata).ok().flatten();
+    match (&field.data_type, strategy) {
+        (DataType::Dictionary(_, value, _), _) => match value.as_ref() {
+            DataType::Utf8 | DataType::LargeUtf8 => "",
+            _ => "0",
+        },
+        (DataType::Timestamp(_, Some(_)), _) => "1970-01-01T00:00:00Z",
+        (DataType::Date64, Some(Strategy::UtcStrAsDate64)) => "1970-01-01T00:00:00Z",
+        (DataType::Timestamp(_, None) | DataType::Date64, _) => "1970-01-01T00:00:00",
+        (DataType::Date32, _) => "1970-01-01",
+        (DataType::Time32(_) | DataType::Time64(_), _) => "00:00:00",
+        (DataType::Duration(_), _) => "PT0S",
+        (DataType::FixedSizeBinary(_), Some(Strategy::UuidStrAsFixedSizeBinary)) => {
+            "00000000-0000-0000-0000-000000000000"
+        }
+        (DataType::FixedSizeBinary(_), Some(Strategy::IpStrAsFixedSizeBinary)) => "0.0.0.0",
+        (DataType::Boolean, _) => "false",
+        (DataType::Utf8 | DataType::LargeUtf8 | DataType::Null, _) => "",
+        _ => "0",
+    }
+}
+
+/// The maximum nesting of placeholder values, guards against recursive types
+const MAX_PLACEHOLDER_DEPTH: usize = 20;
+
+/// A deserializer that produces placeholder values for a hinted field
+///
+/// The values follow the method requested by the `Deserialize` implementation,
+/// the field is only used to determine strings and the result of
+/// `deserialize_any`. Enums use their first variant.
+#[derive(Clone, Copy)]
+pub struct PlaceholderDeserializer<'a> {
+    field: &'a Field,
+    depth: usize,
+}
+
+impl<'a> PlaceholderDeserializer<'a> {
+    pub fn new(field: &'a Field) -> Self {
+        Self { field, depth: 0 }
+    }
+
+    fn nested(&self, field: &'a Field) -> Result<PlaceholderDeserializer<'a>> {
+        if self.depth >= MAX_PLACEHOLDER_DEPTH {
+            fail!(concat!(
+                "Cannot construct placeholder values for too deeply nested types. ",
+                "Recursive enums are constructed from their first variant, ",
+                "which must not be recursive.",
+            ));
+        }
+        Ok(Self {
+            field,
+            depth: self.depth + 1,
+        })
+    }
+
+    fn child(&self, name: &str) -> Result<PlaceholderDeserializer<'a>> {
+        match &self.field.data_type {
+            DataType::Struct(children) => match children.iter().find(|child| child.name == name) {
+                Some(child) => self.nested(child),
+                None => self.nested(self.field),
+            },
+            _ => self.nested(self.field),
+        }
+    }
+}
+
+impl<'de, 'a> serde::de::Deserializer<'de> for PlaceholderDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match &self.field.data_type {
+            DataType::Null => visitor.visit_unit(),
+            DataType::Boolean => visitor.visit_bool(false),
+            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+                visitor.visit_i64(0)
+            }
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+                visitor.visit_u64(0)
+            }
+            DataType::Float16 | DataType::Float32 | DataType::Float64 => visitor.visit_f64(0.0),
+            DataType::Binary | DataType::LargeBinary => visitor.visit_bytes(&[]),
+            DataType::FixedSizeBinary(n)
+                if get_strategy_from_metadata(&self.field.metadata)?.is_none() =>
+            {
+                visitor.visit_byte_buf(vec![0; usize::try_from(*n)?])
+            }
+            DataType::Struct(children) => {
+                let names = children
+                    .iter()
+                    .map(|child| child.name.as_str())
+                    .collect::<Vec<_>>();
+                visitor.visit_map(PlaceholderMap {
+                    parent: self,
+                    names: &names,
+                    pos: 0,
+                })
+            }
+            DataType::List(_) | DataType::LargeList(_) | DataType::FixedSizeList(_, _) => visitor
+                .visit_seq(PlaceholderSeq {
+                    parent: self,
+                    len: 0,
+                }),
+            DataType::Map(_, _) => visitor.visit_map(PlaceholderMap {
+                parent: self,
+                names: &[],
+                pos: 0,
+            }),
+            // untagged enums try all variants, use the first one
+            DataType::Union(variants, _) => match variants.first() {
+                Some((_, variant)) => self.nested(variant)?.deserialize_any(visitor),
+                None => fail!("Cannot construct placeholder values for Union without variants"),
+            },
+            _ => visitor.visit_str(placeholder_str(self.field)),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bool(false)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i8(0)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i16(0)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i32(0)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i64(0)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u8(0)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u16(0)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u32(0)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u64(0)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_f32(0.0)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_f64(0.0)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_char('\0')
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_str(placeholder_str(self.field))
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_str(placeholder_str(self.field))
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        // some types (e.g., `jiff::Timestamp`) deserialize strings via `deserialize_bytes`
+        match &self.field.data_type {
+            DataType::Binary | DataType::LargeBinary => visitor.visit_bytes(&[]),
+            DataType::FixedSizeBinary(n)
+                if get_strategy_from_metadata(&self.field.metadata)?.is_none() =>
+            {
+                visitor.visit_byte_buf(vec![0; usize::try_from(*n)?])
+            }
+            _ => visitor.visit_bytes(placeholder_str(self.field).as_bytes()),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_none()
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self.nested(self.field)?)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(PlaceholderSeq {
+            parent: self,
+            len: 0,
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(PlaceholderSeq { parent: self, len })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        // structs with flattened fields are deserialized as maps
+        let names = match &self.field.data_type {
+            DataType::Struct(children) => children
+                .iter()
+                .map(|child| child.name.as_str())
+                .collect::<Vec<_>>(),
+            _ => Vec::new(),
+        };
+        visitor.visit_map(PlaceholderMap {
+            parent: self,
+            names: &names,
+            pos: 0,
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_map(PlaceholderMap {
+            parent: self,
+            names: fields,
+            pos: 0,
+        })
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let Some(variant) = variants.first() else {
+            fail!("Cannot construct placeholder values for enums without variants");
+        };
+        visitor.visit_enum(PlaceholderEnum {
+            parent: self,
+            variant,
+        })
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+}
+
+struct PlaceholderSeq<'a> {
+    parent: PlaceholderDeserializer<'a>,
+    len: usize,
+}
+
+impl<'de, 'a> serde::de::SeqAccess<'de> for PlaceholderSeq<'a> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        self.len -= 1;
+        seed.deserialize(self.parent.nested(self.parent.field)?)
+            .map(Some)
+    }
+}
+
+struct PlaceholderMap<'a, 'n> {
+    parent: PlaceholderDeserializer<'a>,
+    names: &'n [&'n str],
+    pos: usize,
+}
+
+impl<'de, 'a, 'n> serde::de::MapAccess<'de> for PlaceholderMap<'a, 'n> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some(name) = self.names.get(self.pos) else {
+            return Ok(None);
+        };
+        let key = seed.deserialize(serde::de::value::StrDeserializer::<Error>::new(name))?;
+        Ok(Some(key))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let name = self.names[self.pos];
+        self.pos += 1;
+        seed.deserialize(self.parent.child(name)?)
+    }
+}
+
+struct PlaceholderEnum<'a> {
+    parent: PlaceholderDeserializer<'a>,
+    variant: &'static str,
+}
+
+impl<'de, 'a> serde::de::EnumAccess<'de> for PlaceholderEnum<'a> {
+    type Error = Error;
+    type Variant = PlaceholderVariant<'a>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant)> {
+        let variant = seed.deserialize(serde::de::value::StrDeserializer::<Error>::new(
+            self.variant,
+        ))?;
+        Ok((variant, PlaceholderVariant(self.parent)))
+    }
+}
+
+struct PlaceholderVariant<'a>(PlaceholderDeserializer<'a>);
+
+impl<'de, 'a> serde::de::VariantAccess<'de> for PlaceholderVariant<'a> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self.0.nested(self.0.field)?)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_tuple(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.0.deserialize_struct("", fields, visitor)
+    }
+}
@@ -0,0 +1,281 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::internal::{
+    arrow::{DataType, Field},
+    error::{fail, set_default, Context, ContextSupport, Result},
+    schema::{
+        get_strategy_from_metadata,
+        tracer::{coerce_primitive_type, is_numeric},
+        DataTypeDisplay, SerdeArrowSchema, TracingOptions, STRATEGY_KEY,
+    },
+    utils::ChildName,
+};
+
+impl SerdeArrowSchema {
+    /// Merge two schemas into a schema compatible with both
+    ///
+    /// The rules follow the ones used when tracing samples:
+    ///
+    /// - struct fields are unioned by name, fields missing in either schema
+    ///   are marked as nullable
+    /// - `Null` fields are merged with any other type and marked as nullable
+    /// - numeric types are widened if
+    ///   [`coerce_numbers`][TracingOptions::coerce_numbers] is set
+    /// - `Utf8` / `LargeUtf8`, `Binary` / `LargeBinary` and `List` /
+    ///   `LargeList` are unified to their large variants
+    ///
+    /// Irreconcilable types result in an error that includes the path of the
+    /// offending field.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_json::json;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema, TracingOptions};
+    ///
+    /// let a = SerdeArrowSchema::from_value(&json!([
+    ///     {"name": "a", "data_type": "U8"},
+    ///     {"name": "b", "data_type": "Utf8"},
+    /// ]))?;
+    /// let b = SerdeArrowSchema::from_value(&json!([
+    ///     {"name": "a", "data_type": "U32"},
+    ///     {"name": "c", "data_type": "Bool"},
+    /// ]))?;
+    ///
+    /// let options = TracingOptions::default().coerce_numbers(true);
+    /// let merged = SerdeArrowSchema::merge(&a, &b, options)?;
+    ///
+    /// let expected = SerdeArrowSchema::from_value(&json!([
+    ///     {"name": "a", "data_type": "U64"},
+    ///     {"name": "b", "data_type": "Utf8", "nullable": true},
+    ///     {"name": "c", "data_type": "Bool", "nullable": true},
+    /// ]))?;
+    /// assert_eq!(merged, expected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge(
+        schema_a: &SerdeArrowSchema,
+        schema_b: &SerdeArrowSchema,
+        options: TracingOptions,
+    ) -> Result<SerdeArrowSchema> {
+        let fields = merge_fields("$", &schema_a.fields, &schema_b.fields, &options)?;
+        Ok(SerdeArrowSchema {
+            fields,
+            metadata: merge_metadata(&schema_a.metadata, &schema_b.metadata),
+        })
+    }
+}
+
+struct MergeContext<'a> {
+    path: &'a str,
+}
+
+impl<'a> Context for MergeContext<'a> {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", self.path);
+    }
+}
+
+fn merge_fields(
+    path: &str,
+    fields_a: &[Field],
+    fields_b: &[Field],
+    options: &TracingOptions,
+) -> Result<Vec<Field>> {
+    let mut result = Vec::new();
+
+    for field_a in fields_a {
+        match fields_b.iter().find(|field_b| field_b.name == field_a.name) {
+            Some(field_b) => {
+                let child_path = format!("{path}.{child}", child = ChildName(&field_a.name));
+                result.push(merge_field(&child_path, field_a, field_b, options)?);
+            }
+            None => result.push(Field {
+                nullable: true,
+                ..field_a.clone()
+            }),
+        }
+    }
+    for field_b in fields_b {
+        if !fields_a.iter().any(|field_a| field_a.name == field_b.name) {
+            result.push(Field {
+                nullable: true,
+                ..field_b.clone()
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+fn merge_field(
+    path: &str,
+    field_a: &Field,
+    field_b: &Field,
+    options: &TracingOptions,
+) -> Result<Field> {
+    let mut metadata = merge_metadata(&field_a.metadata, &field_b.metadata);
+
+    let (data_type, nullable) = match (&field_a.data_type, &field_b.data_type) {
+        (DataType::Null, DataType::Null) => (DataType::Null, true),
+        (DataType::Null, _) => {
+            metadata = field_b.metadata.clone();
+            (field_b.data_type.clone(), true)
+        }
+        (_, DataType::Null) => {
+            metadata = field_a.metadata.clone();
+            (field_a.data_type.clone(), true)
+        }
+        (dt_a, dt_b) if is_primitive(dt_a) && is_primitive(dt_b) => {
+            let strategy_a =
+                get_strategy_from_metadata(&field_a.metadata).ctx(&MergeContext { path })?;
+            let strategy_b =
+                get_strategy_from_metadata(&field_b.metadata).ctx(&MergeContext { path })?;
+
+            let (data_type, nullable, strategy) = match (dt_a, dt_b) {
+                (DataType::Utf8 | DataType::LargeUtf8, DataType::Utf8 | DataType::LargeUtf8)
+                    if dt_a != dt_b =>
+                {
+                    (DataType::LargeUtf8, false, None)
+                }
+                (
+                    DataType::Binary | DataType::LargeBinary,
+                    DataType::Binary | DataType::LargeBinary,
+                ) if dt_a != dt_b => (DataType::LargeBinary, false, None),
+                _ => match coerce_primitive_type(
+                    (dt_a, false, strategy_a.as_ref()),
+                    (dt_b.clone(), strategy_b),
+                    options,
+                ) {
+                    Ok(res) => res,
+                    Err(_) => return conflict(path, dt_a, dt_b, options),
+                },
+            };
+
+            metadata.remove(STRATEGY_KEY);
+            if let Some(strategy) = strategy {
+                metadata.insert(STRATEGY_KEY.to_string(), strategy.to_string());
+            }
+            (data_type, nullable)
+        }
+        (DataType::Struct(children_a), DataType::Struct(children_b)) => {
+            let children = merge_fields(path, children_a, children_b, options)?;
+            (DataType::Struct(children), false)
+        }
+        (DataType::List(item_a), DataType::List(item_b)) => {
+            let item = merge_child(path, item_a, item_b, options)?;
+            (DataType::List(Box::new(item)), false)
+        }
+        (
+            DataType::List(item_a) | DataType::LargeList(item_a),
+            DataType::List(item_b) | DataType::LargeList(item_b),
+        ) => {
+            let item = merge_child(path, item_a, item_b, options)?;
+            (DataType::LargeList(Box::new(item)), false)
+        }
+        (DataType::FixedSizeList(item_a, n_a), DataType::FixedSizeList(item_b, n_b))
+            if n_a == n_b =>
+        {
+            let item = merge_child(path, item_a, item_b, options)?;
+            (DataType::FixedSizeList(Box::new(item), *n_a), false)
+        }
+        (DataType::Map(entries_a, sorted_a), DataType::Map(entries_b, sorted_b))
+            if sorted_a == sorted_b =>
+        {
+            let entries = merge_child(path, entries_a, entries_b, options)?;
+            (DataType::Map(Box::new(entries), *sorted_a), false)
+        }
+        (
+            DataType::Dictionary(key_a, value_a, sorted_a),
+            DataType::Dictionary(key_b, value_b, sorted_b),
+        ) if sorted_a == sorted_b => {
+            let key_options = options.clone().coerce_numbers(true);
+            let Ok((key, _, _)) = coerce_primitive_type(
+                (key_a, false, None),
+                ((**key_b).clone(), None),
+                &key_options,
+            ) else {
+                return conflict(path, &field_a.data_type, &field_b.data_type, options);
+            };
+            let value = match (value_a.as_ref(), value_b.as_ref()) {
+                (value_a, value_b) if value_a == value_b => value_a.clone(),
+                (DataType::Utf8 | DataType::LargeUtf8, DataType::Utf8 | DataType::LargeUtf8) => {
+                    DataType::LargeUtf8
+                }
+                _ => return conflict(path, &field_a.data_type, &field_b.data_type, options),
+            };
+            (
+                DataType::Dictionary(Box::new(key), Box::new(value), *sorted_a),
+                false,
+            )
+        }
+        (DataType::Union(_, _), DataType::Union(_, _))
+            if field_a.data_type == field_b.data_type =>
+        {
+            (field_a.data_type.clone(), false)
+        }
+        (dt_a, dt_b) => return conflict(path, dt_a, dt_b, options),
+    };
+
+    Ok(Field {
+        name: field_a.name.clone(),
+        data_type,
+        nullable: nullable || field_a.nullable || field_b.nullable,
+        metadata,
+    })
+}
+
+fn merge_child(
+    path: &str,
+    child_a: &Field,
+    child_b: &Field,
+    options: &TracingOptions,
+) -> Result<Field> {
+    let child_path = format!("{path}.{child}", child = ChildName(&child_a.name));
+    merge_field(&child_path, child_a, child_b, options)
+}
+
+fn merge_metadata(
+    metadata_a: &HashMap<String, String>,
+    metadata_b: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    let mut metadata = metadata_a.clone();
+    for (key, value) in metadata_b {
+        if !metadata.contains_key(key) {
+            metadata.insert(key.clone(), value.clone());
+        }
+    }
+    metadata
+}
+
+fn conflict<T>(
+    path: &str,
+    data_type_a: &DataType,
+    data_type_b: &DataType,
+    options: &TracingOptions,
+) -> Result<T> {
+    let extra = if !options.coerce_numbers && is_numeric(data_type_a) && is_numeric(data_type_b) {
+        ": consider setting `coerce_numbers` to `true` to coerce different numeric types."
+    } else {
+        ""
+    };
+    fail!(
+        in MergeContext { path },
+        "Cannot merge incompatible data types {a} and {b}{extra}",
+        a = DataTypeDisplay(data_type_a),
+        b = DataTypeDisplay(data_type_b),
+    );
+}
+
+fn is_primitive(data_type: &DataType) -> bool {
+    !matches!(
+        data_type,
+        DataType::Struct(_)
+            | DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::FixedSizeList(_, _)
+            | DataType::Map(_, _)
+            | DataType::Dictionary(_, _, _)
+            | DataType::Union(_, _)
+    )
+}
@@ -1,6 +1,18 @@
+mod codegen;
+pub mod derive;
+mod diff;
+mod documentation;
+mod dsl;
 pub mod extensions;
+mod field_names;
+mod fingerprint;
+mod from_avro_schema;
+mod from_json_schema;
 mod from_samples;
 mod from_type;
+mod merge;
+mod path_pattern;
+mod schema_tracer;
 mod serde;
 mod strategy;
 pub mod tracer;
@@ -9,6 +21,8 @@ mod tracing_options;
 #[cfg(test)]
 mod test;
 
+use std::collections::HashMap;
+
 use crate::internal::{
     arrow::{TimeUnit, UnionMode},
     error::{fail, Result},
@@ -18,12 +32,46 @@ use crate::internal::{
 use ::serde::{Deserialize, Serialize};
 
 pub use self::serde::serialize::PrettyField;
+pub use derive::ArrowSchema;
+pub use diff::{Compatibility, SchemaChange, SchemaChangeKind, SchemaDiff};
+pub use field_names::FieldNames;
+pub use schema_tracer::SchemaTracer;
 pub use strategy::{get_strategy_from_metadata, Strategy, STRATEGY_KEY};
 use tracer::Tracer;
-pub use tracing_options::{Overwrites, TracingMode, TracingOptions};
+pub use tracing_options::{
+    DataTypeSubstitutions, FieldMetadata, Overwrites, PatternOverwrites, TracingMode,
+    TracingOptions, TypeHints,
+};
 
 use super::arrow::{DataType, Field};
 
+/// The metadata key under which to store the default value of a field
+///
+/// The default value is used to fill fields missing from the input when
+/// deserializing with an expected schema, see
+/// [`Deserializer::with_schema`][crate::Deserializer::with_schema]. It is
+/// given as a string, e.g., `"42"` for integer fields or `"2024-01-01"` for
+/// date fields. Temporal and decimal defaults are parsed as when serializing
+/// strings into the field, and are deserialized like the values of an existing
+/// column, e.g., as the number of days or as the date string for `Date32`.
+pub const DEFAULT_VALUE_KEY: &str = "SERDE_ARROW:default";
+
+/// The name of the `Null` variant of a union that `None` values are written to
+///
+/// Used by the schema importers for nullable unions, as dense unions do not
+/// have a validity of their own.
+pub const UNION_NULL_VARIANT: &str = "null";
+
+/// The `Null` variant added to unions that include null values
+pub fn union_null_variant() -> Field {
+    Field {
+        name: UNION_NULL_VARIANT.to_owned(),
+        data_type: DataType::Null,
+        nullable: true,
+        metadata: HashMap::new(),
+    }
+}
+
 pub trait Sealed {}
 
 /// A sealed trait to add support for constructing schema-like objects
@@ -144,11 +192,12 @@ pub trait SchemaLike: Sized + Sealed {
     ///
     /// - auto detection of date time strings
     /// - non self-describing types such as `serde_json::Value`
-    /// - flattened structure (`#[serde(flatten)]`)
+    /// - flattened structure (`#[serde(flatten)]`) and untagged enums (`#[serde(untagged)]`)
     /// - types that require specific data to be deserialized, such as the `DateTime` type of
     ///   `chrono` or the `Uuid` type of the `uuid` package
     ///
-    /// Consider using [`from_samples`][SchemaLike::from_samples] in these cases.
+    /// Consider using [`from_samples`][SchemaLike::from_samples] in these cases or describe the
+    /// affected fields with [type hints][TracingOptions::path_type_hint].
     ///
     /// ```rust
     /// # #[cfg(has_arrow)]
@@ -278,9 +327,99 @@ pub trait SchemaLike: Sized + Sealed {
 ///
 /// It can be converted from / to arrow or arrow2 fields.
 ///
+/// Schemas can also be written in a compact text form, which is parsed via
+/// `FromStr` and printed via `Display`. Each field is given as `name: type`,
+/// followed by `?` for nullable fields and optionally a list of metadata
+/// entries, including the strategy. Nested types list their children in angle
+/// brackets or braces. Child names are only required if they differ from the
+/// defaults, `element` for lists and `key` / `value` for maps. Union type ids
+/// are assigned in order. Names that are not identifiers are quoted. The text
+/// form is also accepted when deserializing a schema from a string, e.g., in
+/// config files.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+/// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+///
+/// let schema: SerdeArrowSchema = r#"
+///     a: I32?,
+///     b: List<Struct{x: F64, y: Utf8}>,
+///     c: Map<Utf8, Date64? [strategy: NaiveStrAsDate64]>,
+///     "d e": Union{int: I64, str: LargeUtf8},
+/// "#.parse()?;
+///
+/// let expected = SerdeArrowSchema::from_value(serde_json::json!([
+///     {"name": "a", "data_type": "I32", "nullable": true},
+///     {"name": "b", "data_type": "List", "children": [
+///         {"name": "element", "data_type": "Struct", "children": [
+///             {"name": "x", "data_type": "F64"},
+///             {"name": "y", "data_type": "Utf8"},
+///         ]},
+///     ]},
+///     {"name": "c", "data_type": "Map", "children": [
+///         {"name": "entries", "data_type": "Struct", "children": [
+///             {"name": "key", "data_type": "Utf8"},
+///             {"name": "value", "data_type": "Date64", "nullable": true, "strategy": "NaiveStrAsDate64"},
+///         ]},
+///     ]},
+///     {"name": "d e", "data_type": "Union", "children": [
+///         {"name": "int", "data_type": "I64"},
+///         {"name": "str", "data_type": "LargeUtf8"},
+///     ]},
+/// ]))?;
+/// assert_eq!(schema, expected);
+///
+/// assert_eq!(
+///     schema.to_string(),
+///     concat!(
+///         "a: Int32?, b: List<Struct{x: Float64, y: Utf8}>, ",
+///         "c: Map<Utf8, Date64? [strategy: NaiveStrAsDate64]>, ",
+///         "\"d e\": Union{int: Int64, str: LargeUtf8}",
+///     ),
+/// );
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Default, Debug, PartialEq, Clone)]
 pub struct SerdeArrowSchema {
     pub(crate) fields: Vec<Field>,
+    pub(crate) metadata: HashMap<String, String>,
+}
+
+impl SerdeArrowSchema {
+    /// The schema-level metadata
+    ///
+    /// The metadata is set during tracing via
+    /// [`TracingOptions::schema_metadata`] or with
+    /// [`with_metadata`][SerdeArrowSchema::with_metadata]. It is included in
+    /// the schema of record batches built with
+    /// [`ArrayBuilder::to_record_batch`][crate::ArrayBuilder::to_record_batch]
+    /// and, after converting the schema into an arrow `Schema`, with
+    /// `to_record_batch_with_schema`.
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    /// Replace the schema-level metadata
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use std::collections::HashMap;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let schema = SerdeArrowSchema::from_value(serde_json::json!([
+    ///     {"name": "id", "data_type": "U64"},
+    /// ]))?
+    /// .with_metadata(HashMap::from([(String::from("owner"), String::from("core"))]));
+    ///
+    /// assert_eq!(schema.metadata()["owner"], "core");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_metadata(mut self, metadata: HashMap<String, String>) -> Self {
+        self.metadata = metadata;
+        self
+    }
 }
 
 impl Sealed for SerdeArrowSchema {}
@@ -329,8 +468,7 @@ pub fn transmute_field(field: impl Serialize) -> Result<Field> {
 pub fn validate_field(field: &Field) -> Result<()> {
     match &field.data_type {
         DataType::Null => validate_null_field(field),
-        DataType::Boolean
-        | DataType::UInt8
+        DataType::UInt8
         | DataType::UInt16
         | DataType::UInt32
         | DataType::UInt64
@@ -338,16 +476,16 @@ pub fn validate_field(field: &Field) -> Result<()> {
         | DataType::Int16
         | DataType::Int32
         | DataType::Int64
-        | DataType::Float16
         | DataType::Float32
-        | DataType::Float64
-        | DataType::Utf8
-        | DataType::LargeUtf8
+        | DataType::Float64 => validate_number_field(field),
+        DataType::Boolean
+        | DataType::Float16
         | DataType::Decimal128(_, _)
         | DataType::Date32
-        | DataType::Binary
-        | DataType::LargeBinary
         | DataType::Duration(_) => validate_primitive_field(field),
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary => {
+            validate_bytes_field(field)
+        }
         DataType::FixedSizeBinary(n) => validate_fixed_size_binary_field(field, *n),
         DataType::Date64 => validate_date64_field(field),
         DataType::Timestamp(unit, tz) => validate_timestamp_field(field, *unit, tz.as_deref()),
@@ -384,11 +522,37 @@ fn validate_primitive_field(field: &Field) -> Result<()> {
     Ok(())
 }
 
+fn validate_bytes_field(field: &Field) -> Result<()> {
+    match get_strategy_from_metadata(&field.metadata)? {
+        None | Some(Strategy::ValueAsJson) => Ok(()),
+        Some(strategy) => fail!(
+            "invalid strategy for {data_type}: {strategy}",
+            data_type = DataTypeDisplay(&field.data_type),
+        ),
+    }
+}
+
+fn validate_number_field(field: &Field) -> Result<()> {
+    match get_strategy_from_metadata(&field.metadata)? {
+        None | Some(Strategy::StrAsNumber) => Ok(()),
+        Some(strategy) => fail!(
+            "invalid strategy for {data_type}: {strategy}",
+            data_type = DataTypeDisplay(&field.data_type),
+        ),
+    }
+}
+
 fn validate_fixed_size_binary_field(field: &Field, n: i32) -> Result<()> {
     if n < 0 {
         fail!("Invalid FixedSizedBinary with negative number of elements");
     }
-    validate_primitive_field(field)
+    match get_strategy_from_metadata(&field.metadata)? {
+        None => Ok(()),
+        Some(Strategy::UuidStrAsFixedSizeBinary | Strategy::IpStrAsFixedSizeBinary) if n == 16 => {
+            Ok(())
+        }
+        Some(strategy) => fail!("invalid strategy for FixedSizeBinary({n}) field: {strategy}"),
+    }
 }
 
 fn validate_fixed_size_list_field(field: &Field, child: &Field, n: i32) -> Result<()> {
@@ -444,16 +608,8 @@ fn validate_date64_field(field: &Field) -> Result<()> {
 fn validate_timestamp_field(field: &Field, unit: TimeUnit, tz: Option<&str>) -> Result<()> {
     match get_strategy_from_metadata(&field.metadata)? {
         None => {}
-        Some(strategy @ Strategy::UtcStrAsDate64) => {
-            if !matches!(tz, Some(tz) if tz.to_uppercase() == "UTC") {
-                fail!("invalid strategy for Timestamp({unit}, {tz:?}) field: {strategy}");
-            }
-        }
-        Some(strategy @ Strategy::NaiveStrAsDate64) => {
-            if tz.is_some() {
-                fail!("invalid strategy for Timestamp({unit}, {tz:?}) field: {strategy}");
-            }
-        }
+        Some(Strategy::UtcStrAsDate64) if matches!(tz, Some(tz) if tz.to_uppercase() == "UTC") => {}
+        Some(Strategy::NaiveStrAsDate64) if tz.is_none() => {}
         Some(strategy) => fail!("invalid strategy for Timestamp({unit}, {tz:?}) field: {strategy}"),
     }
     Ok(())
@@ -549,7 +705,7 @@ impl<'a> std::fmt::Display for DataTypeDisplay<'a> {
             DataType::List(_) => write!(f, "List"),
             DataType::LargeList(_) => write!(f, "LargeList"),
             DataType::FixedSizeList(_, n) => write!(f, "FixedSizeList({n})"),
-            DataType::Decimal128(precision, scale) => write!(f, "Decimal128({precision}, {scale}"),
+            DataType::Decimal128(precision, scale) => write!(f, "Decimal128({precision}, {scale})"),
             DataType::Struct(_) => write!(f, "Struct"),
             DataType::Map(_, sorted) => write!(f, "Map({sorted})"),
             DataType::Dictionary(key, value, sorted) => write!(
@@ -564,9 +720,13 @@ impl<'a> std::fmt::Display for DataTypeDisplay<'a> {
 }
 
 const _: () = {
+    #[allow(dead_code)]
     trait AssertSendSync: Send + Sync {}
     impl AssertSendSync for SerdeArrowSchema {}
     impl AssertSendSync for TracingOptions {}
     impl AssertSendSync for Strategy {}
     impl AssertSendSync for Overwrites {}
+    impl AssertSendSync for PatternOverwrites {}
+    impl AssertSendSync for DataTypeSubstitutions {}
+    impl AssertSendSync for TypeHints {}
 };
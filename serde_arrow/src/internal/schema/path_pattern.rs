@@ -0,0 +1,140 @@
+//! Patterns to match the paths of traced fields
+//!
+//! Patterns follow a subset of JSONPath: `$` is the root, `.name` matches a
+//! child with the given name, `.*` matches any child and `..name` matches a
+//! descendant with the given name at any depth. List items are named `element`,
+//! map entries `key` and `value`.
+use serde::{Deserialize, Serialize};
+
+use crate::internal::error::{fail, Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Name(String),
+    Any,
+    Descendants,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PathPattern {
+    pattern: String,
+    segments: Vec<Segment>,
+}
+
+impl PathPattern {
+    pub fn new(pattern: &str) -> Result<Self> {
+        let pattern = if pattern.starts_with('$') {
+            pattern.to_owned()
+        } else {
+            format!("$.{pattern}")
+        };
+
+        let mut segments = Vec::new();
+        let mut rest = &pattern[1..];
+        while !rest.is_empty() {
+            if let Some(tail) = rest.strip_prefix("..") {
+                segments.push(Segment::Descendants);
+                rest = tail;
+            } else if let Some(tail) = rest.strip_prefix('.') {
+                rest = tail;
+            } else {
+                fail!("Invalid path pattern {pattern:?}: expected `.` or `..` before {rest:?}");
+            }
+
+            let end = rest.find('.').unwrap_or(rest.len());
+            let (name, tail) = rest.split_at(end);
+            match name {
+                "" => fail!("Invalid path pattern {pattern:?}: empty segment"),
+                "*" => segments.push(Segment::Any),
+                name => segments.push(Segment::Name(name.to_owned())),
+            }
+            rest = tail;
+        }
+
+        Ok(Self { pattern, segments })
+    }
+
+    /// Check whether the path (e.g., `"$.a.element.b"`) matches the pattern
+    pub fn matches(&self, path: &str) -> bool {
+        let Some(rest) = path.strip_prefix('$') else {
+            return false;
+        };
+        let parts = match rest.strip_prefix('.') {
+            Some(rest) => rest.split('.').collect::<Vec<_>>(),
+            None if rest.is_empty() => Vec::new(),
+            None => return false,
+        };
+        matches_segments(&self.segments, &parts)
+    }
+}
+
+fn matches_segments(segments: &[Segment], parts: &[&str]) -> bool {
+    match segments {
+        [] => parts.is_empty(),
+        [Segment::Name(name), segments @ ..] => {
+            matches!(parts, [part, parts @ ..] if part == name && matches_segments(segments, parts))
+        }
+        [Segment::Any, segments @ ..] => {
+            matches!(parts, [_, parts @ ..] if matches_segments(segments, parts))
+        }
+        [Segment::Descendants, rest @ ..] => {
+            matches_segments(rest, parts)
+                || matches!(parts, [_, parts @ ..] if matches_segments(segments, parts))
+        }
+    }
+}
+
+impl std::fmt::Display for PathPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+impl TryFrom<String> for PathPattern {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        Self::new(&value)
+    }
+}
+
+impl From<PathPattern> for String {
+    fn from(value: PathPattern) -> Self {
+        value.pattern
+    }
+}
+
+#[test]
+fn test_matches() {
+    let pattern = PathPattern::new("$..timestamp").unwrap();
+    assert!(pattern.matches("$.timestamp"));
+    assert!(pattern.matches("$.events.element.timestamp"));
+    assert!(!pattern.matches("$.events.element.timestamp.value"));
+    assert!(!pattern.matches("$.events"));
+
+    let pattern = PathPattern::new("$.events.element.*").unwrap();
+    assert!(pattern.matches("$.events.element.timestamp"));
+    assert!(pattern.matches("$.events.element.id"));
+    assert!(!pattern.matches("$.events.element"));
+    assert!(!pattern.matches("$.events.element.id.value"));
+
+    let pattern = PathPattern::new("a.b").unwrap();
+    assert!(pattern.matches("$.a.b"));
+    assert!(!pattern.matches("$.a"));
+    assert!(!pattern.matches("$.x.a.b"));
+
+    let pattern = PathPattern::new("$..*").unwrap();
+    assert!(pattern.matches("$.a"));
+    assert!(pattern.matches("$.a.b.c"));
+    assert!(!pattern.matches("$"));
+}
+
+#[test]
+fn test_invalid_patterns() {
+    assert!(PathPattern::new("$.").is_err());
+    assert!(PathPattern::new("$..").is_err());
+    assert!(PathPattern::new("$.a..").is_err());
+    assert!(PathPattern::new("$a").is_err());
+    assert!(PathPattern::new("$...a").is_err());
+}
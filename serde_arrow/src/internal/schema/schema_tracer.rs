@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::internal::{
+    error::Result,
+    schema::{tracer::Tracer, SerdeArrowSchema, TracingMode, TracingOptions},
+};
+
+/// Trace a schema incrementally from multiple collections of samples
+///
+/// In contrast to [`SchemaLike::from_samples`][crate::schema::SchemaLike::from_samples],
+/// the tracer keeps its state between calls to
+/// [`trace_samples`][SchemaTracer::trace_samples]. Therefore, the evidence
+/// accumulates over all traced samples, e.g., fields missing in later samples
+/// are marked as nullable. The tracer can be serialized to resume the schema
+/// inference later. The serialized state is only guaranteed to be compatible
+/// within the same version of `serde_arrow`.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// use serde::Serialize;
+/// use serde_arrow::schema::{SchemaTracer, TracingOptions};
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let mut tracer = SchemaTracer::new(TracingOptions::default());
+/// tracer.trace_samples(&[Record { a: None, b: 1 }])?;
+///
+/// // store the tracer state and resume later
+/// let state = serde_json::to_string(&tracer).unwrap();
+/// let mut tracer: SchemaTracer = serde_json::from_str(&state).unwrap();
+///
+/// tracer.trace_samples(&[Record { a: Some(1.0), b: 2 }])?;
+/// let schema = tracer.to_schema()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaTracer {
+    tracer: Tracer,
+}
+
+impl SchemaTracer {
+    /// Construct a new tracer without any traced samples
+    pub fn new(options: TracingOptions) -> Self {
+        let options = options.tracing_mode(TracingMode::FromSamples);
+        Self {
+            tracer: Tracer::new(String::from("$"), String::from("$"), Arc::new(options)),
+        }
+    }
+
+    /// The options used by this tracer
+    pub fn get_options(&self) -> &TracingOptions {
+        self.tracer.get_options()
+    }
+
+    /// Trace a collection of samples
+    ///
+    /// The samples have to be a sequence of records, as for
+    /// [`SchemaLike::from_samples`][crate::schema::SchemaLike::from_samples].
+    /// If tracing fails, the state of the tracer is not modified.
+    pub fn trace_samples<T: Serialize>(&mut self, samples: T) -> Result<()> {
+        // cheap, as the observed string values are shared with the checkpoint
+        let checkpoint = self.tracer.clone();
+        if let Err(err) = self.tracer.trace_samples(samples) {
+            self.tracer = checkpoint;
+            return Err(err);
+        }
+        drop(checkpoint);
+        self.tracer.commit();
+        Ok(())
+    }
+
+    /// Build the schema from all samples traced so far
+    pub fn to_schema(&self) -> Result<SerdeArrowSchema> {
+        self.tracer.check()?;
+        self.tracer.to_schema()
+    }
+}
+
+#[derive(Serialize)]
+struct SchemaTracerStateRef<'a> {
+    options: &'a TracingOptions,
+    tracer: &'a Tracer,
+}
+
+#[derive(Deserialize)]
+struct SchemaTracerState {
+    options: TracingOptions,
+    tracer: Tracer,
+}
+
+impl Serialize for SchemaTracer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SchemaTracerStateRef {
+            options: self.tracer.get_options(),
+            tracer: &self.tracer,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SchemaTracer {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SchemaTracerState {
+            options,
+            mut tracer,
+        } = SchemaTracerState::deserialize(deserializer)?;
+        tracer.set_options(&Arc::new(options));
+        Ok(Self { tracer })
+    }
+}
+
+const _: () = {
+    #[allow(dead_code)]
+    trait AssertSendSync: Send + Sync {}
+    impl AssertSendSync for SchemaTracer {}
+};
@@ -18,7 +18,11 @@ impl<'de> serde::Deserialize<'de> for SerdeArrowSchema {
             type Value = SerdeArrowSchema;
 
             fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                write!(f, "a sequence of fields or a struct with key 'fields' containing a sequence of fields")
+                write!(f, "a sequence of fields or a struct with key 'fields' containing a sequence of fields, or a schema string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse().map_err(E::custom)
             }
 
             fn visit_seq<A: serde::de::SeqAccess<'de>>(
@@ -32,7 +36,10 @@ impl<'de> serde::Deserialize<'de> for SerdeArrowSchema {
                     fields.push(item.into_field().map_err(A::Error::custom)?);
                 }
 
-                Ok(SerdeArrowSchema { fields })
+                Ok(SerdeArrowSchema {
+                    fields,
+                    metadata: HashMap::new(),
+                })
             }
 
             fn visit_map<A: serde::de::MapAccess<'de>>(
@@ -42,10 +49,13 @@ impl<'de> serde::Deserialize<'de> for SerdeArrowSchema {
                 use serde::de::Error;
 
                 let mut fields = None;
+                let mut metadata = HashMap::new();
 
                 while let Some(key) = map.next_key::<String>()? {
                     if key == "fields" {
                         fields = Some(map.next_value::<Vec<CustomField>>()?);
+                    } else if key == "metadata" {
+                        metadata = map.next_value::<HashMap<String, String>>()?;
                     } else {
                         map.next_value::<serde::de::IgnoredAny>()?;
                     }
@@ -62,6 +72,7 @@ impl<'de> serde::Deserialize<'de> for SerdeArrowSchema {
 
                 Ok(SerdeArrowSchema {
                     fields: converted_fields,
+                    metadata,
                 })
             }
         }
@@ -130,7 +141,7 @@ impl ArrowOrCustomDataType {
     }
 }
 
-fn build_data_type(data_type: String, children: Vec<Field>) -> Result<DataType> {
+pub fn build_data_type(data_type: String, children: Vec<Field>) -> Result<DataType> {
     use DataType as T;
 
     let res = match Term::from_str(&data_type)?.as_call()? {
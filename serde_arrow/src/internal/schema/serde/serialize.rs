@@ -11,8 +11,12 @@ use crate::internal::{
 
 impl serde::Serialize for SerdeArrowSchema {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut s = serializer.serialize_struct("SerdeArrowSchema", 1)?;
+        let num_fields = if self.metadata.is_empty() { 1 } else { 2 };
+        let mut s = serializer.serialize_struct("SerdeArrowSchema", num_fields)?;
         s.serialize_field("fields", &PrettyFields(&self.fields))?;
+        if !self.metadata.is_empty() {
+            s.serialize_field("metadata", &self.metadata)?;
+        }
         s.end()
     }
 }
@@ -63,12 +63,45 @@ pub enum Strategy {
     ///   polars does not support them)
     ///
     MapAsStruct,
+    /// Serialize Rust strings containing numbers as Arrow integers or floats
+    ///
+    /// The strings are parsed when serializing. When deserializing, the
+    /// numbers are formatted as strings. This strategy is set by schema
+    /// tracing with [`guess_numbers`][crate::schema::TracingOptions::guess_numbers].
+    StrAsNumber,
+    /// Serialize Rust strings containing UUIDs as Arrow `FixedSizeBinary(16)`
+    ///
+    /// The UUIDs must be given in their canonical hyphenated form. When
+    /// deserializing, the UUIDs are formatted as lower case hyphenated strings,
+    /// i.e., upper case UUIDs do not round-trip to the same text.
+    /// Schema tracing with
+    /// [`guess_uuids`][crate::schema::TracingOptions::guess_uuids] also sets
+    /// the `arrow.uuid` extension type.
+    UuidStrAsFixedSizeBinary,
+    /// Serialize Rust strings containing IPv4 or IPv6 addresses as Arrow
+    /// `FixedSizeBinary(16)`
+    ///
+    /// IPv4 addresses are stored as IPv4-mapped IPv6 addresses and formatted
+    /// as IPv4 addresses when deserializing. Therefore, IPv4-mapped IPv6
+    /// strings (e.g., `"::ffff:192.168.0.1"`) do not round-trip to the same
+    /// text, but are deserialized as IPv4 addresses (`"192.168.0.1"`).
+    IpStrAsFixedSizeBinary,
     /// Mark a variant as unknown
     ///
     /// This strategy applies only to fields with DataType Null. If
     /// serialization or deserialization of such a field is attempted, it will
     /// result in an error.
     UnknownVariant,
+    /// Serialize arbitrary Rust values as JSON text
+    ///
+    /// This strategy applies to `Utf8`, `LargeUtf8`, `Binary` and
+    /// `LargeBinary` fields. Enums are encoded externally tagged, options as
+    /// their content or null. Schema tracing sets this strategy for values
+    /// nested deeper than
+    /// [`max_depth`][crate::schema::TracingOptions::max_depth], e.g., to
+    /// support recursive types. As in `serde_json`, arrays and objects can be
+    /// nested at most 128 levels deep.
+    ValueAsJson,
 }
 
 impl std::fmt::Display for Strategy {
@@ -79,7 +112,11 @@ impl std::fmt::Display for Strategy {
             Self::NaiveStrAsDate64 => write!(f, "NaiveStrAsDate64"),
             Self::TupleAsStruct => write!(f, "TupleAsStruct"),
             Self::MapAsStruct => write!(f, "MapAsStruct"),
+            Self::StrAsNumber => write!(f, "StrAsNumber"),
+            Self::UuidStrAsFixedSizeBinary => write!(f, "UuidStrAsFixedSizeBinary"),
+            Self::IpStrAsFixedSizeBinary => write!(f, "IpStrAsFixedSizeBinary"),
             Self::UnknownVariant => write!(f, "UnknownVariant"),
+            Self::ValueAsJson => write!(f, "ValueAsJson"),
         }
     }
 }
@@ -108,7 +145,11 @@ impl FromStr for Strategy {
             "NaiveStrAsDate64" => Ok(Self::NaiveStrAsDate64),
             "TupleAsStruct" => Ok(Self::TupleAsStruct),
             "MapAsStruct" => Ok(Self::MapAsStruct),
+            "StrAsNumber" => Ok(Self::StrAsNumber),
+            "UuidStrAsFixedSizeBinary" => Ok(Self::UuidStrAsFixedSizeBinary),
+            "IpStrAsFixedSizeBinary" => Ok(Self::IpStrAsFixedSizeBinary),
             "UnknownVariant" => Ok(Self::UnknownVariant),
+            "ValueAsJson" => Ok(Self::ValueAsJson),
             _ => fail!("Unknown strategy {s}"),
         }
     }
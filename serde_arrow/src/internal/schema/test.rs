@@ -10,7 +10,7 @@ use crate::internal::{
 };
 
 fn type_from_str(s: &str) -> DataType {
-    let schema = SerdeArrowSchema::from_value(&json!([{"name": "item", "data_type": s}])).unwrap();
+    let schema = SerdeArrowSchema::from_value(json!([{"name": "item", "data_type": s}])).unwrap();
     schema.fields[0].data_type.clone()
 }
 
@@ -22,6 +22,7 @@ fn pretty_str_from_type(data_type: &DataType) -> String {
             nullable: false,
             metadata: Default::default(),
         }],
+        metadata: HashMap::new(),
     };
     let json = serde_json::to_value(schema).unwrap();
 
@@ -47,6 +48,7 @@ fn i16_field_simple() -> PanicOnError<()> {
             metadata: hash_map!(),
             nullable: false,
         }],
+        metadata: HashMap::new(),
     };
     let expected = json!({
         "fields": [
@@ -78,6 +80,7 @@ fn date64_field_complex() -> PanicOnError<()> {
             ),
             nullable: true,
         }],
+        metadata: HashMap::new(),
     };
     let expected = json!({
         "fields": [{
@@ -114,6 +117,7 @@ fn list_field_complex() -> PanicOnError<()> {
             metadata: hash_map!("foo" => "bar"),
             nullable: true,
         }],
+        metadata: HashMap::new(),
     };
     let expected = json!({
         "fields": [{
@@ -166,6 +170,7 @@ fn map_field_complex() -> PanicOnError<()> {
                 false,
             ),
         }],
+        metadata: HashMap::new(),
     };
     let expected = json!({
         "fields": [{
@@ -202,6 +207,7 @@ fn null_fields_are_nullable_implicitly() -> PanicOnError<()> {
             metadata: hash_map!(),
             nullable: true,
         }],
+        metadata: HashMap::new(),
     };
     let schema = json!({
         "fields": [
@@ -235,6 +241,7 @@ fn example() {
                 metadata: Default::default(),
             },
         ],
+        metadata: HashMap::new(),
     };
 
     let actual = serde_json::to_string(&schema).unwrap();
@@ -264,10 +271,11 @@ fn example_without_wrapper() {
                 metadata: Default::default(),
             },
         ],
+        metadata: HashMap::new(),
     };
 
     let input = r#"[{"name":"foo","data_type":"U8"},{"name":"bar","data_type":"Utf8"}]"#;
-    let actual: SerdeArrowSchema = serde_json::from_str(&input).unwrap();
+    let actual: SerdeArrowSchema = serde_json::from_str(input).unwrap();
     assert_eq!(actual, expected);
 }
 
@@ -285,6 +293,7 @@ fn list() {
             nullable: false,
             metadata: Default::default(),
         }],
+        metadata: HashMap::new(),
     };
 
     let actual = serde_json::to_string(&schema).unwrap();
@@ -306,7 +315,7 @@ fn doc_schema() {
         ]
     "#;
 
-    let actual: SerdeArrowSchema = serde_json::from_str(&schema).unwrap();
+    let actual: SerdeArrowSchema = serde_json::from_str(schema).unwrap();
 
     let expected = SerdeArrowSchema {
         fields: vec![
@@ -323,6 +332,7 @@ fn doc_schema() {
                 metadata: Default::default(),
             },
         ],
+        metadata: HashMap::new(),
     };
 
     assert_eq!(actual, expected);
@@ -337,6 +347,7 @@ fn date64_with_strategy() {
             nullable: false,
             metadata: hash_map!( STRATEGY_KEY => Strategy::NaiveStrAsDate64 ),
         }],
+        metadata: HashMap::new(),
     };
 
     let actual = serde_json::to_string(&schema).unwrap();
@@ -360,7 +371,7 @@ fn date64_with_strategy() {
 
 #[test]
 fn test_metadata_strategy_from_explicit() {
-    let schema = SerdeArrowSchema::from_value(&json!([
+    let schema = SerdeArrowSchema::from_value(json!([
         {
             "name": "example",
             "data_type": "Date64",
@@ -398,7 +409,7 @@ fn test_metadata_strategy_from_explicit() {
 
 #[test]
 fn test_metadata_strategy_from_metadata() {
-    let schema = SerdeArrowSchema::from_value(&json!([
+    let schema = SerdeArrowSchema::from_value(json!([
         {
             "name": "example",
             "data_type": "Date64",
@@ -438,7 +449,7 @@ fn test_metadata_strategy_from_metadata() {
 #[test]
 fn test_invalid_metadata() {
     // strategies cannot be given both in metadata and strategy field
-    let res = SerdeArrowSchema::from_value(&json!([
+    let res = SerdeArrowSchema::from_value(json!([
         {
             "name": "example",
             "data_type": "Date64",
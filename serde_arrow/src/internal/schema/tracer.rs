@@ -1,21 +1,26 @@
 use std::{
-    collections::{BTreeMap, HashMap, HashSet},
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     sync::Arc,
 };
 
+use serde::{Deserialize, Serialize};
+
 use crate::internal::{
     arrow::{DataType, Field, UnionMode},
-    error::{fail, set_default, Context, Result},
+    error::{fail, set_default, Context, ContextSupport, Result},
     schema::{
-        DataTypeDisplay, Overwrites, SerdeArrowSchema, Strategy, TracingMode, TracingOptions,
-        STRATEGY_KEY,
+        extensions::EXTENSION_NAME_KEY, validate_field, DataTypeDisplay, SerdeArrowSchema,
+        Strategy, TracingMode, TracingOptions, STRATEGY_KEY,
     },
+    utils::decimal,
 };
 
-// TODO: allow to customize
 const MAX_TYPE_DEPTH: usize = 20;
-const RECURSIVE_TYPE_WARNING: &str =
-    "Too deeply nested type detected: recursive types are not supported in schema tracing";
+const RECURSIVE_TYPE_WARNING: &str = concat!(
+    "Too deeply nested type detected: recursive types are not supported in schema tracing. ",
+    "Consider setting `max_depth` in `TracingOptions` to store deeper values as JSON.",
+);
 
 fn default_dictionary_field(name: &str, nullable: bool, string_type: DataType) -> Field {
     Field {
@@ -26,7 +31,7 @@ fn default_dictionary_field(name: &str, nullable: bool, string_type: DataType) -
     }
 }
 
-fn unknown_variant_field() -> Field {
+pub fn unknown_variant_field() -> Field {
     let mut metadata = HashMap::new();
     metadata.insert(STRATEGY_KEY.into(), Strategy::UnknownVariant.into());
     Field {
@@ -71,7 +76,7 @@ impl<'a> std::fmt::Display for EnumWithoutDataMessage<'a> {
 
 macro_rules! defined_tracer {
     ($($variant:ident($impl:ident)),* $(,)? ) => {
-        #[derive(Debug, PartialEq, Clone)]
+        #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
         pub enum Tracer {
             $($variant($impl),)*
         }
@@ -94,6 +99,7 @@ defined_tracer!(
     Struct(StructTracer),
     Tuple(TupleTracer),
     Union(UnionTracer),
+    Hinted(HintedTracer),
 );
 
 impl Tracer {
@@ -129,7 +135,10 @@ impl Tracer {
             ),
         };
 
-        Ok(SerdeArrowSchema { fields })
+        Ok(SerdeArrowSchema {
+            fields,
+            metadata: self.get_options().schema_metadata.clone(),
+        })
     }
 }
 
@@ -142,9 +151,13 @@ impl Tracer {
         dispatch_tracer!(self, tracer => tracer.get_type())
     }
 
+    pub fn get_path(&self) -> &str {
+        dispatch_tracer!(self, tracer => tracer.get_path())
+    }
+
     pub fn to_field(&self) -> Result<Field> {
         let path = dispatch_tracer!(self, tracer => &tracer.path);
-        if let Some(overwrite) =
+        let mut field = if let Some(overwrite) =
             dispatch_tracer!(self, tracer => tracer.options.get_overwrite(path))
         {
             let overwrite_name = &overwrite.name;
@@ -153,10 +166,31 @@ impl Tracer {
                 let path = path.strip_prefix("$.").unwrap_or(path);
                 fail!("Invalid name for overwritten field {path:?}: found {overwrite_name:?}, expected {tracer_name:?}");
             }
-            Ok(overwrite.clone())
+            overwrite.clone()
+        } else if let Some(overwrite) =
+            dispatch_tracer!(self, tracer => tracer.options.get_pattern_overwrite(path))
+        {
+            Field {
+                name: dispatch_tracer!(self, tracer => tracer.name.clone()),
+                ..overwrite.clone()
+            }
         } else {
-            dispatch_tracer!(self, tracer => tracer.to_field())
+            let mut field = dispatch_tracer!(self, tracer => tracer.to_field())?;
+            let data_type = self.get_options().substitute(field.data_type.clone());
+            if data_type != field.data_type {
+                field.data_type = data_type;
+                // the strategy of the traced field may not apply to the substituted type
+                validate_field(&field).ctx(self)?;
+            }
+            field
+        };
+
+        if let Some(metadata) = self.get_options().get_field_metadata(path) {
+            for (key, value) in metadata {
+                field.metadata.insert(key.clone(), value.clone());
+            }
         }
+        Ok(field)
     }
 
     pub fn get_options(&self) -> &TracingOptions {
@@ -176,15 +210,21 @@ impl Tracer {
             fail!("Check must be called on the root tracer");
         }
         let options = self.get_options();
-        self.check_overwrites(&options.overwrites)
+        self.check_paths("Overwritten fields", options.overwrites.0.keys())?;
+        self.check_paths("Fields with metadata", options.field_metadata.0.keys())
     }
 
-    pub fn check_overwrites(&self, overwrites: &Overwrites) -> Result<()> {
+    /// Check that all paths, e.g., of overwrites, refer to traced fields
+    pub fn check_paths<'a>(
+        &self,
+        label: &str,
+        keys: impl Iterator<Item = &'a String>,
+    ) -> Result<()> {
         let mut paths = HashSet::new();
         self.collect_paths(&mut paths);
 
         let mut missing = Vec::new();
-        for key in overwrites.0.keys() {
+        for key in keys {
             if !paths.contains(key) {
                 let key = key.strip_prefix("$.").unwrap_or(key);
                 missing.push(key);
@@ -200,7 +240,9 @@ impl Tracer {
                 .collect::<Vec<_>>();
             paths.sort();
 
-            fail!("Overwritten fields could not be found: missing fields {missing:?}, known fields: {paths:?}");
+            fail!(
+                "{label} could not be found: missing fields {missing:?}, known fields: {paths:?}"
+            );
         }
 
         Ok(())
@@ -244,6 +286,86 @@ impl Tracer {
                     field.collect_paths(target);
                 }
             }
+            Self::Hinted(tracer) => {
+                target.insert(&tracer.path);
+            }
+        }
+    }
+}
+
+impl Tracer {
+    /// Commit the state observed since the last checkpoint, see [`StringValues`]
+    pub fn commit(&mut self) {
+        match self {
+            Self::Unknown(_) | Self::Hinted(_) => {}
+            Self::Primitive(tracer) => {
+                if let Some(values) = tracer.string_values.as_mut() {
+                    values.commit();
+                }
+            }
+            Self::Map(tracer) => {
+                tracer.key_tracer.commit();
+                tracer.value_tracer.commit();
+            }
+            Self::List(tracer) => tracer.item_tracer.commit(),
+            Self::Struct(tracer) => {
+                for field in &mut tracer.fields {
+                    field.tracer.commit();
+                }
+            }
+            Self::Union(tracer) => {
+                for variant in tracer.variants.iter_mut().flatten() {
+                    variant.tracer.commit();
+                }
+            }
+            Self::Tuple(tracer) => {
+                for field in &mut tracer.field_tracers {
+                    field.commit();
+                }
+            }
+        }
+    }
+
+    /// Set the options of this tracer and all its children, e.g., after
+    /// deserializing the tracer
+    pub fn set_options(&mut self, options: &Arc<TracingOptions>) {
+        match self {
+            Self::Unknown(tracer) => {
+                tracer.options = options.clone();
+            }
+            Self::Primitive(tracer) => {
+                tracer.options = options.clone();
+            }
+            Self::Map(tracer) => {
+                tracer.options = options.clone();
+                tracer.key_tracer.set_options(options);
+                tracer.value_tracer.set_options(options);
+            }
+            Self::List(tracer) => {
+                tracer.options = options.clone();
+                tracer.item_tracer.set_options(options);
+            }
+            Self::Struct(tracer) => {
+                tracer.options = options.clone();
+                for field in &mut tracer.fields {
+                    field.tracer.set_options(options);
+                }
+            }
+            Self::Union(tracer) => {
+                tracer.options = options.clone();
+                for variant in tracer.variants.iter_mut().flatten() {
+                    variant.tracer.set_options(options);
+                }
+            }
+            Self::Tuple(tracer) => {
+                tracer.options = options.clone();
+                for field in &mut tracer.field_tracers {
+                    field.set_options(options);
+                }
+            }
+            Self::Hinted(tracer) => {
+                tracer.options = options.clone();
+            }
         }
     }
 }
@@ -254,12 +376,34 @@ impl Tracer {
     }
 
     pub fn enforce_depth_limit(&self) -> Result<()> {
-        if self.get_depth() >= MAX_TYPE_DEPTH {
+        // with a maximum depth, deeper values are stored as JSON before reaching the limit
+        if self.get_options().max_depth.is_none() && self.get_depth() >= MAX_TYPE_DEPTH {
             fail!("{RECURSIVE_TYPE_WARNING}");
         }
         Ok(())
     }
 
+    /// Get the field for values stored as JSON, if the tracer is nested deeper than `max_depth`
+    pub fn get_max_depth_field(&self) -> Option<Field> {
+        let options = self.get_options();
+        if self.get_depth() > options.max_depth? {
+            Some(options.max_depth_field())
+        } else {
+            None
+        }
+    }
+
+    /// Store the value as JSON, if the tracer is nested deeper than `max_depth`
+    ///
+    /// If `true` is returned, the value must not be traced any further.
+    pub fn ensure_max_depth(&mut self) -> Result<bool> {
+        let Some(field) = self.get_max_depth_field() else {
+            return Ok(false);
+        };
+        self.ensure_hinted(&field)?;
+        Ok(true)
+    }
+
     pub fn ensure_struct<S: std::fmt::Display>(
         &mut self,
         fields: &[S],
@@ -452,6 +596,32 @@ impl Tracer {
         Ok(())
     }
 
+    /// Use the field of a type hint instead of tracing the type
+    pub fn ensure_hinted(&mut self, field: &Field) -> Result<()> {
+        match self {
+            this @ Self::Unknown(_) => {
+                let tracer = dispatch_tracer!(this, tracer => HintedTracer {
+                    name: tracer.name.clone(),
+                    path: tracer.path.clone(),
+                    options: tracer.options.clone(),
+                    nullable: tracer.nullable,
+                    field: field.clone(),
+                });
+                *this = Self::Hinted(tracer);
+            }
+            Self::Hinted(tracer) => {
+                if tracer.field != *field {
+                    fail!("Conflicting type hints for the same field");
+                }
+            }
+            this => fail!(
+                "Mismatched types: previous {:?}, current type hint",
+                this.get_type()
+            ),
+        }
+        Ok(())
+    }
+
     pub fn ensure_utf8(&mut self, item_type: DataType, strategy: Option<Strategy>) -> Result<()> {
         self.ensure_primitive_with_strategy(item_type, strategy)
     }
@@ -464,6 +634,32 @@ impl Tracer {
         self.ensure_primitive_with_strategy(item_type, None)
     }
 
+    pub fn ensure_integer(&mut self, item_type: DataType, value: i128) -> Result<()> {
+        self.ensure_number(item_type)?;
+        if let Self::Primitive(tracer) = self {
+            if tracer.options.narrow_integers {
+                tracer.observe_integer(value);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn ensure_string(
+        &mut self,
+        item_type: DataType,
+        strategy: Option<Strategy>,
+        value: &str,
+    ) -> Result<()> {
+        self.ensure_primitive_with_strategy(item_type, strategy)?;
+        if let Self::Primitive(tracer) = self {
+            if tracer.options.dictionary_encoding_threshold.is_some() {
+                let max_values = tracer.options.dictionary_encoding_max_values;
+                tracer.observe_string(value, max_values);
+            }
+        }
+        Ok(())
+    }
+
     pub fn ensure_primitive_with_strategy(
         &mut self,
         item_type: DataType,
@@ -479,6 +675,8 @@ impl Tracer {
                     nullable: tracer.nullable || is_null_type,
                     item_type,
                     strategy,
+                    integer_range: None,
+                    string_values: None,
                 });
                 *this = Self::Primitive(tracer);
             }
@@ -486,7 +684,8 @@ impl Tracer {
             | Self::Map(_)
             | Self::Struct(_)
             | Self::Tuple(_)
-            | Self::Union(_)) => {
+            | Self::Union(_)
+            | Self::Hinted(_)) => {
                 if matches!(item_type, DataType::Null) {
                     dispatch_tracer!(this, tracer => { tracer.nullable = true });
                 } else {
@@ -518,14 +717,14 @@ impl Context for Tracer {
     }
 }
 
-fn coerce_primitive_type(
+pub(crate) fn coerce_primitive_type(
     prev: (&DataType, bool, Option<&Strategy>),
     curr: (DataType, Option<Strategy>),
     options: &TracingOptions,
 ) -> Result<(DataType, bool, Option<Strategy>)> {
     use DataType::{
-        Date64, Float32, Float64, Int16, Int32, Int64, Int8, LargeUtf8, Null, UInt16, UInt32,
-        UInt64, UInt8, Utf8,
+        Date64, Decimal128, Float32, Float64, Int16, Int32, Int64, Int8, LargeUtf8, Null, UInt16,
+        UInt32, UInt64, UInt8, Utf8,
     };
 
     let res = match (prev, curr) {
@@ -536,6 +735,26 @@ fn coerce_primitive_type(
         }
         ((Null, _, _), (curr_ty, curr_st)) => (curr_ty, true, curr_st),
         ((prev_ty, _, prev_st), (Null, _)) => (prev_ty.clone(), true, prev_st.cloned()),
+        // numbers guessed from strings
+        (
+            (Int64 | Float64, nullable, Some(Strategy::StrAsNumber)),
+            (Int64 | Float64, Some(Strategy::StrAsNumber)),
+        ) => (Float64, nullable, Some(Strategy::StrAsNumber)),
+        (
+            (Decimal128(prev_precision, prev_scale), nullable, None),
+            (Decimal128(precision, scale), None),
+        ) if options.guess_decimals => {
+            match widen_decimal((*prev_precision, *prev_scale), (precision, scale)) {
+                Some((precision, scale)) => (Decimal128(precision, scale), nullable, None),
+                None => (options.string_type(), nullable, None),
+            }
+        }
+        ((Decimal128(_, _), nullable, None), (Float64, Some(Strategy::StrAsNumber)))
+        | ((Float64, nullable, Some(Strategy::StrAsNumber)), (Decimal128(_, _), None))
+            if options.guess_decimals =>
+        {
+            (Float64, nullable, Some(Strategy::StrAsNumber))
+        }
         // unsigned x unsigned -> u64
         (
             (UInt8 | UInt16 | UInt32 | UInt64, nullable, _),
@@ -581,6 +800,17 @@ fn coerce_primitive_type(
         ((Date64, nullable, prev_st), (Date64, curr_st)) if prev_st != curr_st.as_ref() => {
             (options.string_type(), nullable, None)
         }
+        // different types guessed from strings, coerce to string
+        ((prev_ty, nullable, prev_st), (curr_ty, curr_st))
+            if (options.guess_numbers
+                || options.guess_decimals
+                || options.guess_uuids
+                || options.guess_ip_addresses)
+                && is_guessed_from_str(prev_ty, prev_st, options)
+                && is_guessed_from_str(&curr_ty, curr_st.as_ref(), options) =>
+        {
+            (options.string_type(), nullable, None)
+        }
         ((prev_ty, _, prev_st), (curr_ty, curr_st)) => {
             let extra = if is_numeric(prev_ty) && is_numeric(&curr_ty) {
                 ": consider setting `coerce_numbers` to `true` to coerce different numeric types."
@@ -597,6 +827,38 @@ fn coerce_primitive_type(
     Ok(res)
 }
 
+/// Widen two decimal types to a type that can represent the values of both
+fn widen_decimal(prev: (u8, i8), curr: (u8, i8)) -> Option<(u8, i8)> {
+    let integer_digits = std::cmp::max(
+        i16::from(prev.0) - i16::from(prev.1),
+        i16::from(curr.0) - i16::from(curr.1),
+    );
+    let scale = std::cmp::max(prev.1, curr.1);
+    let precision = integer_digits + i16::from(scale);
+    if precision > i16::from(decimal::MAX_PRECISION_I128) {
+        return None;
+    }
+    Some((u8::try_from(precision).ok()?, scale))
+}
+
+/// Whether the type may have been traced from a string with one of the `guess_*` options
+fn is_guessed_from_str(ty: &DataType, st: Option<&Strategy>, options: &TracingOptions) -> bool {
+    match (ty, st) {
+        (DataType::Utf8 | DataType::LargeUtf8, None) => true,
+        (DataType::Int64 | DataType::Float64, Some(Strategy::StrAsNumber)) => true,
+        (DataType::Decimal128(_, _), None) => options.guess_decimals,
+        (DataType::Date64, Some(Strategy::NaiveStrAsDate64 | Strategy::UtcStrAsDate64)) => true,
+        (DataType::Date32 | DataType::Time64(_), None) => options.guess_dates,
+        (DataType::FixedSizeBinary(16), Some(Strategy::UuidStrAsFixedSizeBinary)) => {
+            options.guess_uuids
+        }
+        (DataType::FixedSizeBinary(16), Some(Strategy::IpStrAsFixedSizeBinary)) => {
+            options.guess_ip_addresses
+        }
+        _ => false,
+    }
+}
+
 struct OptionalStrategyDisplay<'a>(Option<&'a Strategy>);
 
 impl<'a> std::fmt::Display for OptionalStrategyDisplay<'a> {
@@ -608,7 +870,7 @@ impl<'a> std::fmt::Display for OptionalStrategyDisplay<'a> {
     }
 }
 
-fn is_numeric(dt: &DataType) -> bool {
+pub(crate) fn is_numeric(dt: &DataType) -> bool {
     matches!(
         dt,
         DataType::UInt8
@@ -625,10 +887,11 @@ fn is_numeric(dt: &DataType) -> bool {
     )
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct UnknownTracer {
     pub name: String,
     pub path: String,
+    #[serde(skip)]
     pub options: Arc<TracingOptions>,
     pub nullable: bool,
 }
@@ -679,10 +942,11 @@ impl UnknownTracer {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct MapTracer {
     pub name: String,
     pub path: String,
+    #[serde(skip)]
     pub options: Arc<TracingOptions>,
     pub nullable: bool,
     pub key_tracer: Box<Tracer>,
@@ -735,10 +999,11 @@ impl MapTracer {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct ListTracer {
     pub name: String,
     pub path: String,
+    #[serde(skip)]
     pub options: Arc<TracingOptions>,
     pub nullable: bool,
     pub item_tracer: Box<Tracer>,
@@ -783,10 +1048,11 @@ impl ListTracer {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct TupleTracer {
     pub name: String,
     pub path: String,
+    #[serde(skip)]
     pub options: Arc<TracingOptions>,
     pub nullable: bool,
     pub field_tracers: Vec<Tracer>,
@@ -851,10 +1117,11 @@ impl TupleTracer {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct StructTracer {
     pub name: String,
     pub path: String,
+    #[serde(skip)]
     pub options: Arc<TracingOptions>,
     pub nullable: bool,
     pub fields: Vec<StructField>,
@@ -864,14 +1131,14 @@ pub struct StructTracer {
     pub seen_samples: usize,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct StructField {
     pub name: String,
     pub tracer: Tracer,
     pub last_seen_in_sample: usize,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub enum StructMode {
     Struct,
     Map,
@@ -942,7 +1209,11 @@ impl StructTracer {
     pub fn to_field(&self) -> Result<Field> {
         let mut fields = Vec::new();
         for field in &self.fields {
-            fields.push(field.tracer.to_field()?);
+            let mut field = field.tracer.to_field()?;
+            if let Cow::Owned(name) = self.options.field_names.to_arrow(&field.name) {
+                field.name = name;
+            }
+            fields.push(field);
         }
 
         let mut metadata = HashMap::new();
@@ -971,17 +1242,17 @@ impl StructTracer {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
-
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct UnionTracer {
     pub name: String,
     pub path: String,
+    #[serde(skip)]
     pub options: Arc<TracingOptions>,
     pub nullable: bool,
     pub variants: Vec<Option<UnionVariant>>,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct UnionVariant {
     pub name: String,
     pub tracer: Tracer,
@@ -1103,14 +1374,148 @@ impl UnionTracer {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+/// A tracer for a field given by a type hint, see
+/// [`TracingOptions::type_hint`]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct HintedTracer {
+    pub name: String,
+    pub path: String,
+    #[serde(skip)]
+    pub options: Arc<TracingOptions>,
+    pub nullable: bool,
+    pub field: Field,
+}
+
+impl Context for HintedTracer {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "path", &self.path);
+        set_default(annotations, "tracer_type", "Hinted");
+    }
+}
+
+impl HintedTracer {
+    pub fn to_field(&self) -> Result<Field> {
+        Ok(Field {
+            name: self.name.to_owned(),
+            nullable: self.nullable || self.field.nullable,
+            ..self.field.clone()
+        })
+    }
+
+    pub fn finish(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn is_complete(&self) -> bool {
+        true
+    }
+
+    pub fn get_type(&self) -> Option<&str> {
+        Some("Hinted")
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct PrimitiveTracer {
     pub name: String,
     pub path: String,
+    #[serde(skip)]
     pub options: Arc<TracingOptions>,
     pub nullable: bool,
     pub strategy: Option<Strategy>,
     pub item_type: DataType,
+    /// The minimum and maximum observed integer, if `narrow_integers` is set
+    #[serde(default)]
+    pub integer_range: Option<(i128, i128)>,
+    /// The observed string values, if `dictionary_encoding_threshold` is set
+    #[serde(default)]
+    pub string_values: Option<StringValues>,
+}
+
+/// The observed string values of a primitive tracer
+///
+/// New values are collected in `pending` and only moved into the shared
+/// `distinct` set by [`Tracer::commit`]. Therefore, cloning a tracer to
+/// checkpoint its state does not copy the values observed so far.
+///
+/// Once there are more distinct values than allowed by
+/// [`dictionary_encoding_max_values`][TracingOptions::dictionary_encoding_max_values],
+/// the tracer is marked as `exceeded` and the values are dropped.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StringValues {
+    pub count: usize,
+    pub distinct: Arc<BTreeSet<String>>,
+    pub pending: BTreeSet<String>,
+    pub exceeded: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StringValuesState<'a> {
+    count: usize,
+    distinct: Cow<'a, BTreeSet<String>>,
+    #[serde(default)]
+    exceeded: bool,
+}
+
+impl StringValues {
+    fn insert(&mut self, value: &str, max_values: usize) {
+        if self.exceeded {
+            return;
+        }
+        self.count += 1;
+        if !self.distinct.contains(value) && !self.pending.contains(value) {
+            self.pending.insert(value.to_owned());
+        }
+
+        let distinct = self.num_distinct();
+        if distinct > max_values || (distinct as u64) > (1 << 32) {
+            self.exceeded = true;
+            self.distinct = Arc::default();
+            self.pending = BTreeSet::new();
+        }
+    }
+
+    fn num_distinct(&self) -> usize {
+        self.distinct.len() + self.pending.len()
+    }
+
+    fn commit(&mut self) {
+        if !self.pending.is_empty() {
+            Arc::make_mut(&mut self.distinct).append(&mut self.pending);
+        }
+    }
+}
+
+impl Serialize for StringValues {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let distinct = if self.pending.is_empty() {
+            Cow::Borrowed(self.distinct.as_ref())
+        } else {
+            Cow::Owned(self.distinct.union(&self.pending).cloned().collect())
+        };
+        StringValuesState {
+            count: self.count,
+            distinct,
+            exceeded: self.exceeded,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StringValues {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = StringValuesState::deserialize(deserializer)?;
+        Ok(Self {
+            count: state.count,
+            distinct: Arc::new(state.distinct.into_owned()),
+            pending: BTreeSet::new(),
+            exceeded: state.exceeded,
+        })
+    }
 }
 
 impl Context for PrimitiveTracer {
@@ -1140,7 +1545,14 @@ impl PrimitiveTracer {
                 metadata: HashMap::new(),
             }),
             dt @ (D::LargeUtf8 | D::Utf8) => {
-                if !self.options.string_dictionary_encoding {
+                if let Some(key_type) = self.dictionary_key_type() {
+                    Ok(Field {
+                        name: self.name.to_owned(),
+                        data_type: D::Dictionary(Box::new(key_type), Box::new(dt.clone()), false),
+                        nullable: self.nullable,
+                        metadata: HashMap::new(),
+                    })
+                } else if !self.options.string_dictionary_encoding {
                     Ok(Field {
                         name: self.name.to_owned(),
                         data_type: dt.clone(),
@@ -1160,9 +1572,12 @@ impl PrimitiveTracer {
                 if let Some(strategy) = self.strategy.as_ref() {
                     metadata.insert(STRATEGY_KEY.to_string(), strategy.to_string());
                 }
+                if matches!(self.strategy, Some(Strategy::UuidStrAsFixedSizeBinary)) {
+                    metadata.insert(EXTENSION_NAME_KEY.into(), "arrow.uuid".into());
+                }
                 Ok(Field {
                     name: self.name.to_owned(),
-                    data_type: dt.clone(),
+                    data_type: self.narrow_integer_type(dt),
                     nullable: self.nullable,
                     metadata,
                 })
@@ -1171,6 +1586,101 @@ impl PrimitiveTracer {
     }
 }
 
+impl PrimitiveTracer {
+    fn observe_integer(&mut self, value: i128) {
+        self.integer_range = match self.integer_range {
+            Some((min, max)) => Some((min.min(value), max.max(value))),
+            None => Some((value, value)),
+        };
+    }
+
+    fn observe_string(&mut self, value: &str, max_values: usize) {
+        // only plain strings can be dictionary encoded, e.g., not guessed dates
+        if self.strategy.is_some()
+            || !matches!(self.item_type, DataType::Utf8 | DataType::LargeUtf8)
+        {
+            return;
+        }
+        self.string_values
+            .get_or_insert_with(StringValues::default)
+            .insert(value, max_values);
+    }
+
+    /// The key type to use for dictionary encoding based on the observed cardinality
+    fn dictionary_key_type(&self) -> Option<DataType> {
+        if self.options.string_dictionary_encoding {
+            return None;
+        }
+        let threshold = self.options.dictionary_encoding_threshold?;
+        let values = self.string_values.as_ref()?;
+        if values.exceeded || values.count == 0 {
+            return None;
+        }
+
+        let distinct = values.num_distinct();
+        if (distinct as f64) / (values.count as f64) >= threshold {
+            return None;
+        }
+
+        if distinct <= 1 << 8 {
+            Some(DataType::UInt8)
+        } else if distinct <= 1 << 16 {
+            Some(DataType::UInt16)
+        } else {
+            Some(DataType::UInt32)
+        }
+    }
+
+    fn narrow_integer_type(&self, data_type: &DataType) -> DataType {
+        use DataType::{Int16, Int32, Int64, Int8, UInt16, UInt32, UInt64, UInt8};
+
+        let Some((min, max)) = self.integer_range else {
+            return data_type.clone();
+        };
+        if !self.options.narrow_integers {
+            return data_type.clone();
+        }
+
+        let margin = self.options.integer_safety_margin;
+        let (min, max) = (apply_margin(min, margin), apply_margin(max, margin));
+        let candidates: &[(DataType, i128, i128)] = match data_type {
+            Int8 | Int16 | Int32 | Int64 => &[
+                (Int8, i8::MIN as i128, i8::MAX as i128),
+                (Int16, i16::MIN as i128, i16::MAX as i128),
+                (Int32, i32::MIN as i128, i32::MAX as i128),
+            ],
+            UInt8 | UInt16 | UInt32 | UInt64 => &[
+                (UInt8, 0, u8::MAX as i128),
+                (UInt16, 0, u16::MAX as i128),
+                (UInt32, 0, u32::MAX as i128),
+            ],
+            _ => return data_type.clone(),
+        };
+        for (candidate, lower, upper) in candidates {
+            if *lower <= min && max <= *upper {
+                return candidate.clone();
+            }
+        }
+        match data_type {
+            Int8 | Int16 | Int32 | Int64 => Int64,
+            _ => UInt64,
+        }
+    }
+}
+
+/// Scale the value by `1 + margin`, rounding away from zero
+fn apply_margin(value: i128, margin: f64) -> i128 {
+    if margin <= 0.0 || value == 0 {
+        return value;
+    }
+    let scaled = value as f64 * (1.0 + margin);
+    if scaled >= 0.0 {
+        scaled.ceil() as i128
+    } else {
+        scaled.floor() as i128
+    }
+}
+
 impl PrimitiveTracer {
     pub fn get_path(&self) -> &str {
         &self.path
@@ -1,11 +1,18 @@
 use std::collections::HashMap;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::internal::arrow::DataType;
-use crate::internal::{arrow::Field, error::Result, schema::transmute_field};
+use crate::internal::{
+    arrow::Field,
+    error::Result,
+    schema::{
+        path_pattern::PathPattern, serde::deserialize::build_data_type, transmute_field,
+        FieldNames, Strategy,
+    },
+};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum TracingMode {
     Unknown,
     FromType,
@@ -40,7 +47,8 @@ pub enum TracingMode {
 ///         .from_type_budget(100),
 /// );
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 #[non_exhaustive]
 pub struct TracingOptions {
     /// If `true`, accept null-only fields (e.g., fields with type `()` or fields
@@ -71,6 +79,29 @@ pub struct TracingOptions {
     ///indices are used, as they are the default index type in `polars`.
     pub string_dictionary_encoding: bool,
 
+    /// If set, trace string fields in `from_samples` with dictionary encoding if the ratio of
+    /// distinct values to non-null values is below the threshold. The default is `None`.
+    ///
+    /// The key type is the smallest unsigned integer type that can index all observed distinct
+    /// values, i.e., `Dictionary(UInt8, ..)` for up to 256 distinct values, `Dictionary(UInt16,
+    /// ..)` for up to 65536 distinct values and `Dictionary(UInt32, ..)` otherwise. This option
+    /// has no effect if [`string_dictionary_encoding`][TracingOptions::string_dictionary_encoding]
+    /// is set.
+    ///
+    /// The ratio is computed over all samples once the schema is built. Therefore, the distinct
+    /// values of string fields are kept in memory during tracing, up to
+    /// [`dictionary_encoding_max_values`][TracingOptions::dictionary_encoding_max_values]. Strings
+    /// traced with a strategy, e.g., guessed dates, are not tracked.
+    pub dictionary_encoding_threshold: Option<f64>,
+
+    /// The maximum number of distinct values tracked per string field with
+    /// [`dictionary_encoding_threshold`][TracingOptions::dictionary_encoding_threshold]. The
+    /// default is `65536`.
+    ///
+    /// Once a field has more distinct values, its values are dropped to bound the memory usage
+    /// and the field is not dictionary encoded, independent of the values observed afterwards.
+    pub dictionary_encoding_max_values: usize,
+
     /// If `true`, coerce different numeric types. The default is `false`.
     ///
     /// This option may be helpful when dealing with data formats with varying numeric types numeric
@@ -84,6 +115,23 @@ pub struct TracingOptions {
     /// - signed  + float -> f64
     pub coerce_numbers: bool,
 
+    /// If `true`, trace integer fields in `from_samples` as the narrowest integer type that fits
+    /// all observed values. The default is `false`.
+    ///
+    /// The signedness of the traced type is kept, e.g., a field of `i64` values between `-100`
+    /// and `100` is traced as `Int8` and a field of `u64` values up to `1000` as `UInt16`. This
+    /// option is helpful for data formats that use a single integer type for all numbers, e.g.,
+    /// JSON. Types traced with `from_type` are not affected.
+    pub narrow_integers: bool,
+
+    /// The relative margin added to the observed range with
+    /// [`narrow_integers`][TracingOptions::narrow_integers]. The default is `0.0`.
+    ///
+    /// The minimum and maximum observed values are scaled by `1 + margin` before selecting the
+    /// data type. For example with a margin of `0.5`, a maximum value of `100` requires values up
+    /// to `150` to fit and a maximum of `200` requires values up to `300` (i.e., `UInt16`) to fit.
+    pub integer_safety_margin: f64,
+
     /// If `true`, try to auto detect datetimes in string columns. The default is `false`.
     ///
     /// Currently the naive datetime (`YYYY-MM-DDThh:mm:ss`) and UTC datetimes
@@ -95,12 +143,142 @@ pub struct TracingOptions {
     /// [`UtcStrAsDate64`][crate::schema::Strategy::UtcStrAsDate64].
     pub guess_dates: bool,
 
+    /// If `true`, try to auto detect numbers in string fields. The default is `false`.
+    ///
+    /// String fields where all values are integers (e.g., `"42"`) are traced as `Int64`, string
+    /// fields with floats (e.g., `"12.5"`) as `Float64`. In both cases the strategy
+    /// [`StrAsNumber`][crate::schema::Strategy::StrAsNumber] is used to parse the strings during
+    /// serialization and to format the numbers as strings during deserialization. With
+    /// [`guess_decimals`][TracingOptions::guess_decimals], fixed-point numbers are traced as
+    /// decimals instead.
+    ///
+    /// Note: the original formatting of the strings is lost, e.g., `"0003"` is deserialized as
+    /// `"3"`, `"12.50"` as `"12.5"`, and `"1e-3"` as `"0.001"`. Use
+    /// [`exact_number_strings`][TracingOptions::exact_number_strings] to only guess numbers for
+    /// strings that are formatted back unchanged.
+    pub guess_numbers: bool,
+
+    /// If `true`, try to auto detect fixed-point decimals in string fields. The default is `false`.
+    ///
+    /// String fields where all values are of the form `ddd` or `ddd.ddd` (with an optional sign)
+    /// are traced as `Decimal128(precision, scale)`. The precision and scale are chosen to fit the
+    /// widest observed values, e.g., `"12.50"` and `"-100.5"` result in `Decimal128(5, 2)`.
+    ///
+    /// Note: the original formatting of the strings is lost, e.g., `"0003"` is deserialized as
+    /// `"3"`. With [`exact_number_strings`][TracingOptions::exact_number_strings], only strings
+    /// that are formatted back unchanged are guessed as decimals.
+    pub guess_decimals: bool,
+
+    /// If `true`, only guess numbers and decimals for strings that are formatted back into the
+    /// same string. The default is `false`.
+    ///
+    /// With this option, values such as `"0003"`, `"+1"` or `"1e-3"` keep the string type. The
+    /// option only has an effect in combination with
+    /// [`guess_numbers`][TracingOptions::guess_numbers] or
+    /// [`guess_decimals`][TracingOptions::guess_decimals].
+    pub exact_number_strings: bool,
+
+    /// If `true`, try to auto detect UUIDs in string fields. The default is `false`.
+    ///
+    /// String fields where all values are UUIDs in their canonical hyphenated form (e.g.,
+    /// `"67e55044-10b1-426f-9247-bb680e5fe0c8"`) are traced as `FixedSizeBinary(16)` with the
+    /// `arrow.uuid` extension type and the strategy
+    /// [`UuidStrAsFixedSizeBinary`][crate::schema::Strategy::UuidStrAsFixedSizeBinary].
+    ///
+    /// Note: UUIDs are deserialized in lower case, e.g., the upper case
+    /// `"67E55044-10B1-426F-9247-BB680E5FE0C8"` is deserialized as
+    /// `"67e55044-10b1-426f-9247-bb680e5fe0c8"`.
+    pub guess_uuids: bool,
+
+    /// If `true`, try to auto detect IP addresses in string fields. The default is `false`.
+    ///
+    /// String fields where all values are IPv4 or IPv6 addresses are traced as
+    /// `FixedSizeBinary(16)` with the strategy
+    /// [`IpStrAsFixedSizeBinary`][crate::schema::Strategy::IpStrAsFixedSizeBinary].
+    ///
+    /// Note: addresses are deserialized in their canonical form. IPv4-mapped IPv6 addresses are
+    /// deserialized as IPv4 addresses, e.g., `"::ffff:192.168.0.1"` as `"192.168.0.1"`, and IPv6
+    /// addresses are formatted in lower case with compressed zeros.
+    pub guess_ip_addresses: bool,
+
     /// How many tracing iterations to perform in `from_type`.
     ///
     /// The default value (`100`) may be too conservative for deeply nested types or enums with many
     /// variants.
     pub from_type_budget: usize,
 
+    /// If given, values nested deeper than `max_depth` are stored as JSON. The default is `None`.
+    ///
+    /// Without a maximum depth, recursive types (e.g., trees or ASTs) cannot be traced. With a
+    /// maximum depth, the recursion is unrolled up to the given depth and deeper values are
+    /// stored as JSON text with the strategy
+    /// [`ValueAsJson`][crate::schema::Strategy::ValueAsJson]. The depth counts the fields
+    /// between the record and the value, i.e., the top-level fields have depth `1`. The JSON
+    /// fields are always nullable and stored as strings or, with
+    /// [`max_depth_as_binary`](#structfield.max_depth_as_binary), as `LargeBinary`.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::schema::{SchemaLike, SerdeArrowSchema, TracingOptions};
+    /// # use serde::Deserialize;
+    /// # use serde_json::json;
+    /// #[derive(Deserialize)]
+    /// struct Comment {
+    ///     text: String,
+    ///     replies: Vec<Comment>,
+    /// }
+    ///
+    /// let schema = SerdeArrowSchema::from_type::<Comment>(TracingOptions::default().max_depth(Some(1)))?;
+    /// assert_eq!(schema, SerdeArrowSchema::from_value(&json!([
+    ///     {"name": "text", "data_type": "LargeUtf8"},
+    ///     {"name": "replies", "data_type": "LargeList", "children": [
+    ///         {
+    ///             "name": "element",
+    ///             "data_type": "LargeUtf8",
+    ///             "nullable": true,
+    ///             "strategy": "ValueAsJson",
+    ///         },
+    ///     ]},
+    /// ]))?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub max_depth: Option<usize>,
+
+    /// If `true`, store the values nested deeper than [`max_depth`](#structfield.max_depth) as
+    /// `LargeBinary` fields. If `false` (the default), they are stored as strings.
+    pub max_depth_as_binary: bool,
+
+    /// How to translate serde field names into Arrow field names. The default
+    /// ([`FieldNames::Unchanged`]) uses the serde field names as they are.
+    ///
+    /// The policy applies to the fields of structs and of maps traced as structs. Arrays built
+    /// for or deserialized from the resulting schema require the same policy, see
+    /// [`ArrayBuilder::with_field_names`][crate::ArrayBuilder::with_field_names] and
+    /// [`Deserializer::with_field_names`][crate::Deserializer::with_field_names].
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::schema::{FieldNames, SchemaLike, SerdeArrowSchema, TracingOptions};
+    /// # use serde::Deserialize;
+    /// # use serde_json::json;
+    /// #[derive(Deserialize)]
+    /// struct Record {
+    ///     user_id: u64,
+    ///     display_name: String,
+    /// }
+    ///
+    /// let options = TracingOptions::default().field_names(FieldNames::CamelCase);
+    /// let schema = SerdeArrowSchema::from_type::<Record>(options)?;
+    /// assert_eq!(schema, SerdeArrowSchema::from_value(&json!([
+    ///     {"name": "userId", "data_type": "U64"},
+    ///     {"name": "displayName", "data_type": "LargeUtf8"},
+    /// ]))?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub field_names: FieldNames,
+
     /// If `true`, encode enums without data as dictionary encoded strings. If `false` (the
     /// default), enums without data are encoded as Union arrays with `Null` fields.
     ///
@@ -217,6 +395,187 @@ pub struct TracingOptions {
     ///
     pub overwrites: Overwrites,
 
+    /// A list of path patterns and field definitions used for all matching fields
+    ///
+    /// Pattern overwrites can be added with `options.overwrite_pattern(pattern, field)`. Patterns
+    /// follow a subset of JSONPath: `.name` matches a child with the given name, `.*` matches any
+    /// child and `..name` matches a descendant with the given name at any depth. List items are
+    /// named `element`, map entries `key` and `value`. For example, `$..timestamp` matches all
+    /// fields named `timestamp` and `$.events.element.*` matches all fields of the structs inside
+    /// the `events` list.
+    ///
+    /// The name of the given field is replaced by the name of the matched field. Exact
+    /// [`overwrites`](#structfield.overwrites) take precedence, otherwise the first matching
+    /// pattern is used. In contrast to exact overwrites, patterns without any matching field are
+    /// not an error.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::schema::{SchemaLike, SerdeArrowSchema, TracingOptions};
+    /// # use serde_json::json;
+    /// # use serde::Serialize;
+    /// ##[derive(Serialize)]
+    /// struct Record {
+    ///     timestamp: i64,
+    ///     events: Vec<Event>,
+    /// }
+    ///
+    /// ##[derive(Serialize)]
+    /// struct Event {
+    ///     timestamp: i64,
+    /// }
+    ///
+    /// let options = TracingOptions::default().overwrite_pattern(
+    ///     "$..timestamp",
+    ///     json!({"name": "timestamp", "data_type": "Timestamp(Millisecond, None)"}),
+    /// )?;
+    /// let records = [Record { timestamp: 0, events: vec![Event { timestamp: 1 }] }];
+    /// let schema = SerdeArrowSchema::from_samples(&records, options)?;
+    ///
+    /// assert_eq!(schema, SerdeArrowSchema::from_value(&json!([
+    ///     {"name": "timestamp", "data_type": "Timestamp(Millisecond, None)"},
+    ///     {
+    ///         "name": "events",
+    ///         "data_type": "LargeList",
+    ///         "children": [{
+    ///             "name": "element",
+    ///             "data_type": "Struct",
+    ///             "children": [
+    ///                 {"name": "timestamp", "data_type": "Timestamp(Millisecond, None)"},
+    ///             ],
+    ///         }],
+    ///     },
+    /// ]))?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub pattern_overwrites: PatternOverwrites,
+
+    /// A list of data type substitutions applied to all traced fields
+    ///
+    /// Substitutions can be added with `options.substitute_data_type(from, to)`, e.g.,
+    /// `substitute_data_type("LargeUtf8", "Utf8")` to use `Utf8` for all string fields. Only data
+    /// types without children are supported. The substitutions also apply to the key and value
+    /// types of dictionaries. Fields given via overwrites are not modified. Substituting the data
+    /// type of a field with a strategy, e.g., a `Date64` field with guessed dates, is an error if
+    /// the strategy does not support the new data type.
+    pub data_type_substitutions: DataTypeSubstitutions,
+
+    /// A registry of fields used by `from_type` for types that cannot be traced
+    ///
+    /// Some types, e.g., the date and time types of `chrono` and `jiff`, are not self-describing:
+    /// they deserialize from strings, but reject the placeholder values used during tracing.
+    /// Type hints define the field for these types. Hints can be registered for a field path with
+    /// `options.path_type_hint(path, field)` or for the name of a type with
+    /// `options.type_hint(name, field)`. Type names are the names passed by serde to
+    /// `deserialize_newtype_struct`, `deserialize_struct`, `deserialize_tuple_struct`,
+    /// `deserialize_unit_struct` or `deserialize_enum`, i.e., the name of the Rust type for
+    /// derived implementations.
+    ///
+    /// For hinted fields, `from_type` uses placeholder values that match the data type of the
+    /// hint, e.g., `"1970-01-01T00:00:00Z"` for `Timestamp(.., Some(..))` fields. The name of the
+    /// hinted field is replaced by the name of the traced field.
+    ///
+    /// `from_type` cannot trace types using `#[serde(flatten)]` or `#[serde(untagged)]`: serde
+    /// buffers their content before deserializing the individual fields or variants, so their
+    /// types are never observed. These types can only be described by a hint for the complete
+    /// type, e.g., a `Struct` field including the flattened fields or a `Union` field. An empty
+    /// path registers a hint for the root type.
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// # use arrow::datatypes::FieldRef;
+    /// # use serde_arrow::schema::{SchemaLike, TracingOptions};
+    /// # use serde_json::json;
+    /// # use serde::Deserialize;
+    /// use chrono::{DateTime, NaiveDate, Utc};
+    ///
+    /// ##[derive(Deserialize)]
+    /// struct Example {
+    ///     timestamp: DateTime<Utc>,
+    ///     date: Date,
+    /// }
+    ///
+    /// ##[derive(Deserialize)]
+    /// struct Date(NaiveDate);
+    ///
+    /// let options = TracingOptions::default()
+    ///     .path_type_hint(
+    ///         "timestamp",
+    ///         json!({"name": "timestamp", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"}),
+    ///     )?
+    ///     .type_hint("Date", json!({"name": "date", "data_type": "Date32"}))?;
+    /// let fields = Vec::<FieldRef>::from_type::<Example>(options)?;
+    /// #
+    /// # assert_eq!(fields, Vec::<FieldRef>::from_value(&json!([
+    /// #     {"name": "timestamp", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+    /// #     {"name": "date", "data_type": "Date32"},
+    /// # ]))?);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub type_hints: TypeHints,
+
+    /// Additional metadata attached to the traced fields
+    ///
+    /// Metadata entries can be added with `options.field_metadata(path, key, value)`, e.g., to
+    /// document fields with descriptions, units or classification tags. Paths follow the
+    /// conventions of [`overwrites`](#structfield.overwrites). The entries are added to the
+    /// metadata determined during tracing, e.g., the strategy, and are also attached to
+    /// overwritten fields. As for overwrites, paths without a matching field are an error.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::schema::{SchemaLike, SerdeArrowSchema, TracingOptions};
+    /// # use serde_json::json;
+    /// # use serde::Deserialize;
+    /// ##[derive(Deserialize)]
+    /// struct Record {
+    ///     distance: f64,
+    ///     user: User,
+    /// }
+    ///
+    /// ##[derive(Deserialize)]
+    /// struct User {
+    ///     email: String,
+    /// }
+    ///
+    /// let options = TracingOptions::default()
+    ///     .field_metadata("distance", "unit", "km")
+    ///     .field_metadata("user.email", "pii", "true")
+    ///     .schema_metadata("owner", "core");
+    /// let schema = SerdeArrowSchema::from_type::<Record>(options)?;
+    ///
+    /// assert_eq!(schema, SerdeArrowSchema::from_value(&json!({
+    ///     "fields": [
+    ///         {"name": "distance", "data_type": "F64", "metadata": {"unit": "km"}},
+    ///         {
+    ///             "name": "user",
+    ///             "data_type": "Struct",
+    ///             "children": [
+    ///                 {"name": "email", "data_type": "LargeUtf8", "metadata": {"pii": "true"}},
+    ///             ],
+    ///         },
+    ///     ],
+    ///     "metadata": {"owner": "core"},
+    /// }))?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub field_metadata: FieldMetadata,
+
+    /// Metadata attached to the traced schema
+    ///
+    /// Metadata entries can be added with `options.schema_metadata(key, value)`. The metadata is
+    /// available via [`SerdeArrowSchema::metadata`][crate::schema::SerdeArrowSchema::metadata]
+    /// and is included in the schema of record batches built by an
+    /// [`ArrayBuilder`][crate::ArrayBuilder] constructed from the traced schema.
+    pub schema_metadata: HashMap<String, String>,
+
     /// Internal field to improve error messages for the different tracing
     /// functions
     pub(crate) tracing_mode: TracingMode,
@@ -228,11 +587,28 @@ impl Default for TracingOptions {
             allow_null_fields: false,
             map_as_struct: true,
             string_dictionary_encoding: false,
+            dictionary_encoding_threshold: None,
+            dictionary_encoding_max_values: 1 << 16,
             coerce_numbers: false,
+            narrow_integers: false,
+            integer_safety_margin: 0.0,
             guess_dates: false,
+            guess_numbers: false,
+            guess_decimals: false,
+            exact_number_strings: false,
+            guess_uuids: false,
+            guess_ip_addresses: false,
             from_type_budget: 100,
+            max_depth: None,
+            max_depth_as_binary: false,
+            field_names: FieldNames::Unchanged,
             enums_without_data_as_strings: false,
             overwrites: Overwrites::default(),
+            pattern_overwrites: PatternOverwrites::default(),
+            data_type_substitutions: DataTypeSubstitutions::default(),
+            type_hints: TypeHints::default(),
+            field_metadata: FieldMetadata::default(),
+            schema_metadata: HashMap::new(),
             sequence_as_large_list: true,
             string_as_large_utf8: true,
             tracing_mode: TracingMode::Unknown,
@@ -275,24 +651,96 @@ impl TracingOptions {
         self
     }
 
+    /// Set [`dictionary_encoding_threshold`](#structfield.dictionary_encoding_threshold)
+    pub fn dictionary_encoding_threshold(mut self, value: Option<f64>) -> Self {
+        self.dictionary_encoding_threshold = value;
+        self
+    }
+
+    /// Set [`dictionary_encoding_max_values`](#structfield.dictionary_encoding_max_values)
+    pub fn dictionary_encoding_max_values(mut self, value: usize) -> Self {
+        self.dictionary_encoding_max_values = value;
+        self
+    }
+
     /// Set [`coerce_numbers`](#structfield.coerce_numbers)
     pub fn coerce_numbers(mut self, value: bool) -> Self {
         self.coerce_numbers = value;
         self
     }
 
+    /// Set [`narrow_integers`](#structfield.narrow_integers)
+    pub fn narrow_integers(mut self, value: bool) -> Self {
+        self.narrow_integers = value;
+        self
+    }
+
+    /// Set [`integer_safety_margin`](#structfield.integer_safety_margin)
+    pub fn integer_safety_margin(mut self, value: f64) -> Self {
+        self.integer_safety_margin = value;
+        self
+    }
+
     /// Set [`try_parse_dates`](#structfield.try_parse_dates)
     pub fn guess_dates(mut self, value: bool) -> Self {
         self.guess_dates = value;
         self
     }
 
+    /// Set [`guess_numbers`](#structfield.guess_numbers)
+    pub fn guess_numbers(mut self, value: bool) -> Self {
+        self.guess_numbers = value;
+        self
+    }
+
+    /// Set [`guess_decimals`](#structfield.guess_decimals)
+    pub fn guess_decimals(mut self, value: bool) -> Self {
+        self.guess_decimals = value;
+        self
+    }
+
+    /// Set [`exact_number_strings`](#structfield.exact_number_strings)
+    pub fn exact_number_strings(mut self, value: bool) -> Self {
+        self.exact_number_strings = value;
+        self
+    }
+
+    /// Set [`guess_uuids`](#structfield.guess_uuids)
+    pub fn guess_uuids(mut self, value: bool) -> Self {
+        self.guess_uuids = value;
+        self
+    }
+
+    /// Set [`guess_ip_addresses`](#structfield.guess_ip_addresses)
+    pub fn guess_ip_addresses(mut self, value: bool) -> Self {
+        self.guess_ip_addresses = value;
+        self
+    }
+
     /// Set [`from_type_budget`](#structfield.from_type_budget)
     pub fn from_type_budget(mut self, value: usize) -> Self {
         self.from_type_budget = value;
         self
     }
 
+    /// Set [`max_depth`](#structfield.max_depth)
+    pub fn max_depth(mut self, value: Option<usize>) -> Self {
+        self.max_depth = value;
+        self
+    }
+
+    /// Set [`max_depth_as_binary`](#structfield.max_depth_as_binary)
+    pub fn max_depth_as_binary(mut self, value: bool) -> Self {
+        self.max_depth_as_binary = value;
+        self
+    }
+
+    /// Set [`field_names`](#structfield.field_names)
+    pub fn field_names(mut self, value: FieldNames) -> Self {
+        self.field_names = value;
+        self
+    }
+
     /// Set [`enums_without_data_as_strings`](#structfield.enums_without_data_as_strings)
     pub fn enums_without_data_as_strings(mut self, value: bool) -> Self {
         self.enums_without_data_as_strings = value;
@@ -308,6 +756,72 @@ impl TracingOptions {
         Ok(self)
     }
 
+    /// Add an overwrite to [`pattern_overwrites`](#structfield.pattern_overwrites)
+    pub fn overwrite_pattern<P: AsRef<str>, F: Serialize>(
+        mut self,
+        pattern: P,
+        field: F,
+    ) -> Result<Self> {
+        self.pattern_overwrites
+            .0
+            .push((PathPattern::new(pattern.as_ref())?, transmute_field(field)?));
+        Ok(self)
+    }
+
+    /// Add a substitution to [`data_type_substitutions`](#structfield.data_type_substitutions)
+    pub fn substitute_data_type(mut self, from: &str, to: &str) -> Result<Self> {
+        self.data_type_substitutions.0.push((
+            build_data_type(from.to_owned(), Vec::new())?,
+            build_data_type(to.to_owned(), Vec::new())?,
+        ));
+        Ok(self)
+    }
+
+    /// Add a type hint for a type name to [`type_hints`](#structfield.type_hints)
+    pub fn type_hint<N: Into<String>, F: Serialize>(mut self, name: N, field: F) -> Result<Self> {
+        self.type_hints
+            .types
+            .insert(name.into(), transmute_field(field)?);
+        Ok(self)
+    }
+
+    /// Add a type hint for a field path to [`type_hints`](#structfield.type_hints)
+    pub fn path_type_hint<P: Into<String>, F: Serialize>(
+        mut self,
+        path: P,
+        field: F,
+    ) -> Result<Self> {
+        let path = path.into();
+        let path = if path.is_empty() {
+            String::from("$")
+        } else {
+            format!("$.{path}")
+        };
+        self.type_hints.paths.insert(path, transmute_field(field)?);
+        Ok(self)
+    }
+
+    /// Add a metadata entry to [`field_metadata`](#structfield.field_metadata)
+    pub fn field_metadata<P: Into<String>, K: Into<String>, V: Into<String>>(
+        mut self,
+        path: P,
+        key: K,
+        value: V,
+    ) -> Self {
+        self.field_metadata
+            .0
+            .entry(format!("$.{path}", path = path.into()))
+            .or_default()
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Add a metadata entry to [`schema_metadata`](#structfield.schema_metadata)
+    pub fn schema_metadata<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.schema_metadata.insert(key.into(), value.into());
+        self
+    }
+
     pub(crate) fn tracing_mode(mut self, value: TracingMode) -> Self {
         self.tracing_mode = value;
         self
@@ -317,6 +831,55 @@ impl TracingOptions {
         self.overwrites.0.get(path)
     }
 
+    pub(crate) fn get_field_metadata(&self, path: &str) -> Option<&HashMap<String, String>> {
+        self.field_metadata.0.get(path)
+    }
+
+    pub(crate) fn get_type_hint(&self, path: &str, name: Option<&str>) -> Option<&Field> {
+        if let Some(field) = self.type_hints.paths.get(path) {
+            return Some(field);
+        }
+        self.type_hints.types.get(name?)
+    }
+
+    pub(crate) fn get_pattern_overwrite(&self, path: &str) -> Option<&Field> {
+        self.pattern_overwrites
+            .0
+            .iter()
+            .find(|(pattern, _)| pattern.matches(path))
+            .map(|(_, field)| field)
+    }
+
+    pub(crate) fn substitute(&self, data_type: DataType) -> DataType {
+        if let DataType::Dictionary(key, value, sorted) = data_type {
+            return DataType::Dictionary(
+                Box::new(self.substitute(*key)),
+                Box::new(self.substitute(*value)),
+                sorted,
+            );
+        }
+        for (from, to) in &self.data_type_substitutions.0 {
+            if *from == data_type {
+                return to.clone();
+            }
+        }
+        data_type
+    }
+
+    /// The field used for values nested deeper than `max_depth`
+    pub(crate) fn max_depth_field(&self) -> Field {
+        Field {
+            name: String::new(),
+            data_type: if self.max_depth_as_binary {
+                DataType::LargeBinary
+            } else {
+                self.string_type()
+            },
+            nullable: true,
+            metadata: Strategy::ValueAsJson.into(),
+        }
+    }
+
     pub(crate) fn string_type(&self) -> DataType {
         if self.string_as_large_utf8 {
             DataType::LargeUtf8
@@ -327,5 +890,24 @@ impl TracingOptions {
 }
 
 /// An opaque mapping of field paths to field definitions
-#[derive(Debug, Clone, Default, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Overwrites(pub(crate) HashMap<String, Field>);
+
+/// An opaque mapping of field paths to additional metadata entries
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FieldMetadata(pub(crate) HashMap<String, HashMap<String, String>>);
+
+/// An opaque list of path patterns and field definitions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PatternOverwrites(pub(crate) Vec<(PathPattern, Field)>);
+
+/// An opaque list of data type substitutions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DataTypeSubstitutions(pub(crate) Vec<(DataType, DataType)>);
+
+/// An opaque registry of type hints for `from_type`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TypeHints {
+    pub(crate) types: HashMap<String, Field>,
+    pub(crate) paths: HashMap<String, Field>,
+}
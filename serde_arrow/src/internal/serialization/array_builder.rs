@@ -14,9 +14,9 @@ use super::{
     dictionary_utf8_builder::DictionaryUtf8Builder, duration_builder::DurationBuilder,
     fixed_size_binary_builder::FixedSizeBinaryBuilder,
     fixed_size_list_builder::FixedSizeListBuilder, float_builder::FloatBuilder,
-    int_builder::IntBuilder, list_builder::ListBuilder, map_builder::MapBuilder,
-    null_builder::NullBuilder, simple_serializer::SimpleSerializer, struct_builder::StructBuilder,
-    time_builder::TimeBuilder, union_builder::UnionBuilder,
+    int_builder::IntBuilder, json_builder::JsonBuilder, list_builder::ListBuilder,
+    map_builder::MapBuilder, null_builder::NullBuilder, simple_serializer::SimpleSerializer,
+    struct_builder::StructBuilder, time_builder::TimeBuilder, union_builder::UnionBuilder,
     unknown_variant_builder::UnknownVariantBuilder, utf8_builder::Utf8Builder,
 };
 
@@ -54,6 +54,7 @@ pub enum ArrayBuilder {
     DictionaryUtf8(DictionaryUtf8Builder),
     Union(UnionBuilder),
     UnknownVariant(UnknownVariantBuilder),
+    Json(JsonBuilder),
 }
 
 macro_rules! dispatch {
@@ -91,6 +92,7 @@ macro_rules! dispatch {
             $wrapper::DictionaryUtf8($name) => $expr,
             $wrapper::Union($name) => $expr,
             $wrapper::UnknownVariant($name) => $expr,
+            $wrapper::Json($name) => $expr,
         }
     };
 }
@@ -272,10 +274,20 @@ impl SimpleSerializer for ArrayBuilder {
     }
 
     fn serialize_struct_variant_start<'this>(&'this mut self, name: &'static str, variant_index: u32, variant: &'static str, len: usize) -> Result<&'this mut ArrayBuilder> {
+        // JSON builders collect the fields themselves
+        if let Self::Json(builder) = self {
+            builder.start_struct_variant(name, variant_index, variant, len)?;
+            return Ok(self);
+        }
         dispatch!(self, Self(builder) => builder.serialize_struct_variant_start(name, variant_index, variant, len))
     }
 
     fn serialize_tuple_variant_start<'this> (&'this mut self, name: &'static str, variant_index: u32, variant: &'static str, len: usize) -> Result<&'this mut ArrayBuilder> {
+        // JSON builders collect the fields themselves
+        if let Self::Json(builder) = self {
+            builder.start_tuple_variant(name, variant_index, variant, len)?;
+            return Ok(self);
+        }
         dispatch!(self, Self(builder) => builder.serialize_tuple_variant_start(name, variant_index, variant, len))
     }
 }
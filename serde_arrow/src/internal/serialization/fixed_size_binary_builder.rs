@@ -5,9 +5,10 @@ use serde::Serialize;
 use crate::internal::{
     arrow::{Array, FixedSizeBinaryArray},
     error::{fail, set_default, try_, Context, ContextSupport, Result},
+    schema::Strategy,
     utils::{
         array_ext::{ArrayExt, CountArray, SeqArrayExt},
-        Mut,
+        str_formats, Mut,
     },
 };
 
@@ -21,6 +22,7 @@ pub struct FixedSizeBinaryBuilder {
     pub buffer: Vec<u8>,
     pub current_n: usize,
     pub n: usize,
+    pub strategy: Option<Strategy>,
 }
 
 impl FixedSizeBinaryBuilder {
@@ -31,9 +33,15 @@ impl FixedSizeBinaryBuilder {
             buffer: Vec::new(),
             n,
             current_n: 0,
+            strategy: None,
         }
     }
 
+    pub fn with_strategy(mut self, strategy: Option<Strategy>) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
     pub fn take(&mut self) -> ArrayBuilder {
         ArrayBuilder::FixedSizeBinary(Self {
             path: self.path.clone(),
@@ -41,6 +49,7 @@ impl FixedSizeBinaryBuilder {
             buffer: std::mem::take(&mut self.buffer),
             current_n: std::mem::take(&mut self.current_n),
             n: self.n,
+            strategy: self.strategy.clone(),
         })
     }
 
@@ -167,6 +176,26 @@ impl SimpleSerializer for FixedSizeBinaryBuilder {
             self.seq.end_seq()
         }).ctx(self)
     }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        try_(|| {
+            let bytes = match self.strategy {
+                Some(Strategy::UuidStrAsFixedSizeBinary) => match str_formats::parse_uuid(v) {
+                    Some(bytes) => bytes,
+                    None => fail!("Invalid UUID {v:?}"),
+                },
+                Some(Strategy::IpStrAsFixedSizeBinary) => match str_formats::parse_ip_address(v) {
+                    Some(bytes) => bytes,
+                    None => fail!("Invalid IP address {v:?}"),
+                },
+                _ => fail!(
+                    "serialize_str is only supported for FixedSizeBinary with a string strategy"
+                ),
+            };
+            self.serialize_bytes(&bytes)
+        })
+        .ctx(self)
+    }
 }
 
 struct U8Serializer(u8);
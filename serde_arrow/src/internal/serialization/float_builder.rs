@@ -4,7 +4,8 @@ use half::f16;
 
 use crate::internal::{
     arrow::{Array, PrimitiveArray},
-    error::{set_default, try_, Context, ContextSupport, Result},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    schema::Strategy,
     utils::{
         array_ext::{new_primitive_array, ArrayExt, ScalarArrayExt},
         Mut,
@@ -17,6 +18,7 @@ use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
 pub struct FloatBuilder<I> {
     path: String,
     array: PrimitiveArray<I>,
+    strategy: Option<Strategy>,
 }
 
 impl<F: Default + 'static> FloatBuilder<F> {
@@ -24,13 +26,20 @@ impl<F: Default + 'static> FloatBuilder<F> {
         Self {
             path,
             array: new_primitive_array(is_nullable),
+            strategy: None,
         }
     }
 
+    pub fn with_strategy(mut self, strategy: Option<Strategy>) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
     pub fn take_self(&mut self) -> Self {
         Self {
             path: self.path.clone(),
             array: self.array.take(),
+            strategy: self.strategy.clone(),
         }
     }
 
@@ -130,6 +139,16 @@ impl SimpleSerializer for FloatBuilder<f32> {
     fn serialize_f64(&mut self, v: f64) -> Result<()> {
         try_(|| self.array.push_scalar_value(v as f32)).ctx(self)
     }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        try_(|| {
+            if !matches!(self.strategy, Some(Strategy::StrAsNumber)) {
+                fail!("serialize_str is only supported for floats with strategy StrAsNumber");
+            }
+            self.array.push_scalar_value(v.parse::<f32>()?)
+        })
+        .ctx(self)
+    }
 }
 
 impl SimpleSerializer for FloatBuilder<f64> {
@@ -180,6 +199,16 @@ impl SimpleSerializer for FloatBuilder<f64> {
     fn serialize_f64(&mut self, v: f64) -> Result<()> {
         try_(|| self.array.push_scalar_value(v)).ctx(self)
     }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        try_(|| {
+            if !matches!(self.strategy, Some(Strategy::StrAsNumber)) {
+                fail!("serialize_str is only supported for floats with strategy StrAsNumber");
+            }
+            self.array.push_scalar_value(v.parse::<f64>()?)
+        })
+        .ctx(self)
+    }
 }
 
 impl SimpleSerializer for FloatBuilder<f16> {
@@ -2,7 +2,8 @@ use std::collections::BTreeMap;
 
 use crate::internal::{
     arrow::{Array, PrimitiveArray},
-    error::{set_default, try_, Context, ContextSupport, Error, Result},
+    error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
+    schema::Strategy,
     utils::{
         array_ext::{new_primitive_array, ArrayExt, ScalarArrayExt},
         NamedType,
@@ -15,6 +16,7 @@ use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
 pub struct IntBuilder<I> {
     path: String,
     array: PrimitiveArray<I>,
+    strategy: Option<Strategy>,
 }
 
 impl<I: Default + 'static> IntBuilder<I> {
@@ -22,13 +24,20 @@ impl<I: Default + 'static> IntBuilder<I> {
         Self {
             path,
             array: new_primitive_array(is_nullable),
+            strategy: None,
         }
     }
 
+    pub fn with_strategy(mut self, strategy: Option<Strategy>) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
     pub fn take_self(&mut self) -> Self {
         Self {
             path: self.path.clone(),
             array: self.array.take(),
+            strategy: self.strategy.clone(),
         }
     }
 
@@ -154,4 +163,18 @@ where
     fn serialize_char(&mut self, v: char) -> Result<()> {
         try_(|| self.array.push_scalar_value(I::try_from(u32::from(v))?)).ctx(self)
     }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        try_(|| {
+            if !matches!(self.strategy, Some(Strategy::StrAsNumber)) {
+                fail!("serialize_str is only supported for integers with strategy StrAsNumber");
+            }
+            let v = match v.parse::<i64>() {
+                Ok(v) => I::try_from(v)?,
+                Err(_) => I::try_from(v.parse::<u64>()?)?,
+            };
+            self.array.push_scalar_value(v)
+        })
+        .ctx(self)
+    }
 }
@@ -0,0 +1,394 @@
+use std::collections::BTreeMap;
+
+use serde::{
+    ser::{
+        SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+        SerializeTupleStruct, SerializeTupleVariant,
+    },
+    Serialize, Serializer,
+};
+
+use crate::internal::{
+    arrow::Array,
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    utils::{
+        json::write_json,
+        value::{
+            MapSerializer, SeqSerializer, StructSerializer, StructVariantSerializer,
+            TupleStructSerializer, TupleVariantSerializer, Value, ValueSerializer,
+        },
+    },
+};
+
+use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+
+/// Store arbitrary values as JSON text in a string or binary array
+///
+/// Used for fields with the `ValueAsJson` strategy, e.g., for values nested
+/// deeper than the `max_depth` of the tracing options.
+#[derive(Debug, Clone)]
+pub struct JsonBuilder {
+    path: String,
+    inner: Box<ArrayBuilder>,
+    as_bytes: bool,
+    pending: Option<Pending>,
+}
+
+/// A compound value that is currently being serialized
+#[derive(Debug, Clone)]
+enum Pending {
+    Seq(SeqSerializer),
+    Tuple(SeqSerializer),
+    TupleStruct(TupleStructSerializer),
+    Map(MapSerializer),
+    Struct(StructSerializer),
+    StructVariant(StructVariantSerializer),
+    TupleVariant(TupleVariantSerializer),
+}
+
+impl JsonBuilder {
+    pub fn new(path: String, inner: ArrayBuilder, as_bytes: bool) -> Self {
+        Self {
+            path,
+            inner: Box::new(inner),
+            as_bytes,
+            pending: None,
+        }
+    }
+
+    pub fn take(&mut self) -> ArrayBuilder {
+        ArrayBuilder::Json(Self {
+            path: self.path.clone(),
+            inner: Box::new(self.inner.take()),
+            as_bytes: self.as_bytes,
+            pending: None,
+        })
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.inner.is_nullable()
+    }
+
+    pub fn into_array(self) -> Result<Array> {
+        self.inner.into_array()
+    }
+}
+
+impl JsonBuilder {
+    fn push_value(&mut self, value: Value) -> Result<()> {
+        if matches!(value, Value::None) && self.inner.is_nullable() {
+            return self.inner.serialize_none();
+        }
+
+        let mut json = String::new();
+        write_json(&mut json, &value)?;
+
+        if self.as_bytes {
+            self.inner.serialize_bytes(json.as_bytes())
+        } else {
+            self.inner.serialize_str(&json)
+        }
+    }
+
+    fn start(&mut self, pending: Pending) -> Result<()> {
+        if self.pending.is_some() {
+            fail!("Cannot start a new value while another value is being serialized");
+        }
+        self.pending = Some(pending);
+        Ok(())
+    }
+
+    fn take_pending(&mut self) -> Result<Pending> {
+        let Some(pending) = self.pending.take() else {
+            fail!("No value is being serialized");
+        };
+        Ok(pending)
+    }
+
+    fn pending(&mut self) -> Result<&mut Pending> {
+        let Some(pending) = self.pending.as_mut() else {
+            fail!("No value is being serialized");
+        };
+        Ok(pending)
+    }
+
+    pub fn start_struct_variant(
+        &mut self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<()> {
+        try_(|| {
+            let serializer =
+                ValueSerializer.serialize_struct_variant(name, variant_index, variant, len)?;
+            self.start(Pending::StructVariant(serializer))
+        })
+        .ctx(self)
+    }
+
+    pub fn start_tuple_variant(
+        &mut self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<()> {
+        try_(|| {
+            let serializer =
+                ValueSerializer.serialize_tuple_variant(name, variant_index, variant, len)?;
+            self.start(Pending::TupleVariant(serializer))
+        })
+        .ctx(self)
+    }
+}
+
+impl Context for JsonBuilder {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        self.inner.annotate(annotations);
+    }
+}
+
+impl SimpleSerializer for JsonBuilder {
+    fn serialize_default(&mut self) -> Result<()> {
+        if self.inner.is_nullable() {
+            self.inner.serialize_default()
+        } else {
+            self.push_value(Value::Unit)
+        }
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        self.push_value(Value::None)
+    }
+
+    fn serialize_some<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        let value = value.serialize(ValueSerializer)?;
+        self.push_value(value)
+    }
+
+    fn serialize_unit(&mut self) -> Result<()> {
+        self.push_value(Value::Unit)
+    }
+
+    fn serialize_unit_struct(&mut self, name: &'static str) -> Result<()> {
+        self.push_value(Value::UnitStruct(name))
+    }
+
+    fn serialize_bool(&mut self, v: bool) -> Result<()> {
+        self.push_value(Value::Bool(v))
+    }
+
+    fn serialize_char(&mut self, v: char) -> Result<()> {
+        self.push_value(Value::Char(v))
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        self.push_value(Value::U8(v))
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        self.push_value(Value::U16(v))
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        self.push_value(Value::U32(v))
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        self.push_value(Value::U64(v))
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        self.push_value(Value::I8(v))
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        self.push_value(Value::I16(v))
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        self.push_value(Value::I32(v))
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        self.push_value(Value::I64(v))
+    }
+
+    fn serialize_f32(&mut self, v: f32) -> Result<()> {
+        self.push_value(ValueSerializer.serialize_f32(v)?)
+    }
+
+    fn serialize_f64(&mut self, v: f64) -> Result<()> {
+        self.push_value(ValueSerializer.serialize_f64(v)?)
+    }
+
+    fn serialize_bytes(&mut self, v: &[u8]) -> Result<()> {
+        self.push_value(Value::Bytes(v.to_vec()))
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        self.push_value(Value::String(v.to_owned()))
+    }
+
+    fn serialize_newtype_struct<V: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        self.push_value(ValueSerializer.serialize_newtype_struct(name, value)?)
+    }
+
+    fn serialize_newtype_variant<V: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        let value =
+            ValueSerializer.serialize_newtype_variant(name, variant_index, variant, value)?;
+        self.push_value(value)
+    }
+
+    fn serialize_unit_variant(
+        &mut self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.push_value(ValueSerializer.serialize_unit_variant(name, variant_index, variant)?)
+    }
+
+    fn serialize_seq_start(&mut self, len: Option<usize>) -> Result<()> {
+        try_(|| self.start(Pending::Seq(ValueSerializer.serialize_seq(len)?))).ctx(self)
+    }
+
+    fn serialize_seq_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| match self.pending()? {
+            Pending::Seq(serializer) => SerializeSeq::serialize_element(serializer, value),
+            _ => fail!("Unexpected sequence element"),
+        })
+        .ctx(self)
+    }
+
+    fn serialize_seq_end(&mut self) -> Result<()> {
+        try_(|| match self.take_pending()? {
+            Pending::Seq(serializer) => self.push_value(SerializeSeq::end(serializer)?),
+            _ => fail!("Unexpected end of sequence"),
+        })
+        .ctx(self)
+    }
+
+    fn serialize_tuple_start(&mut self, len: usize) -> Result<()> {
+        try_(|| self.start(Pending::Tuple(ValueSerializer.serialize_tuple(len)?))).ctx(self)
+    }
+
+    fn serialize_tuple_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| match self.pending()? {
+            Pending::Tuple(serializer) => SerializeTuple::serialize_element(serializer, value),
+            _ => fail!("Unexpected tuple element"),
+        })
+        .ctx(self)
+    }
+
+    fn serialize_tuple_end(&mut self) -> Result<()> {
+        try_(|| match self.take_pending()? {
+            Pending::Tuple(serializer) => self.push_value(SerializeTuple::end(serializer)?),
+            _ => fail!("Unexpected end of tuple"),
+        })
+        .ctx(self)
+    }
+
+    fn serialize_tuple_struct_start(&mut self, name: &'static str, len: usize) -> Result<()> {
+        try_(|| {
+            let serializer = ValueSerializer.serialize_tuple_struct(name, len)?;
+            self.start(Pending::TupleStruct(serializer))
+        })
+        .ctx(self)
+    }
+
+    fn serialize_tuple_struct_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| match self.pending()? {
+            Pending::TupleStruct(serializer) => serializer.serialize_field(value),
+            Pending::TupleVariant(serializer) => serializer.serialize_field(value),
+            _ => fail!("Unexpected tuple struct field"),
+        })
+        .ctx(self)
+    }
+
+    fn serialize_tuple_struct_end(&mut self) -> Result<()> {
+        try_(|| {
+            let value = match self.take_pending()? {
+                Pending::TupleStruct(serializer) => SerializeTupleStruct::end(serializer)?,
+                Pending::TupleVariant(serializer) => SerializeTupleVariant::end(serializer)?,
+                _ => fail!("Unexpected end of tuple struct"),
+            };
+            self.push_value(value)
+        })
+        .ctx(self)
+    }
+
+    fn serialize_map_start(&mut self, len: Option<usize>) -> Result<()> {
+        try_(|| self.start(Pending::Map(ValueSerializer.serialize_map(len)?))).ctx(self)
+    }
+
+    fn serialize_map_key<V: Serialize + ?Sized>(&mut self, key: &V) -> Result<()> {
+        try_(|| match self.pending()? {
+            Pending::Map(serializer) => serializer.serialize_key(key),
+            _ => fail!("Unexpected map key"),
+        })
+        .ctx(self)
+    }
+
+    fn serialize_map_value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| match self.pending()? {
+            Pending::Map(serializer) => serializer.serialize_value(value),
+            _ => fail!("Unexpected map value"),
+        })
+        .ctx(self)
+    }
+
+    fn serialize_map_end(&mut self) -> Result<()> {
+        try_(|| match self.take_pending()? {
+            Pending::Map(serializer) => self.push_value(SerializeMap::end(serializer)?),
+            _ => fail!("Unexpected end of map"),
+        })
+        .ctx(self)
+    }
+
+    fn serialize_struct_start(&mut self, name: &'static str, len: usize) -> Result<()> {
+        try_(|| {
+            self.start(Pending::Struct(
+                ValueSerializer.serialize_struct(name, len)?,
+            ))
+        })
+        .ctx(self)
+    }
+
+    fn serialize_struct_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        try_(|| match self.pending()? {
+            Pending::Struct(serializer) => serializer.serialize_field(key, value),
+            Pending::StructVariant(serializer) => serializer.serialize_field(key, value),
+            _ => fail!("Unexpected struct field"),
+        })
+        .ctx(self)
+    }
+
+    fn serialize_struct_end(&mut self) -> Result<()> {
+        try_(|| {
+            let value = match self.take_pending()? {
+                Pending::Struct(serializer) => SerializeStruct::end(serializer)?,
+                Pending::StructVariant(serializer) => SerializeStructVariant::end(serializer)?,
+                _ => fail!("Unexpected end of struct"),
+            };
+            self.push_value(value)
+        })
+        .ctx(self)
+    }
+}
@@ -5,7 +5,7 @@ use serde::Serialize;
 use crate::internal::{
     arrow::{DataType, Field, TimeUnit},
     error::{fail, Context, ContextSupport, Result},
-    schema::{get_strategy_from_metadata, SerdeArrowSchema, Strategy},
+    schema::{get_strategy_from_metadata, FieldNames, SerdeArrowSchema, Strategy},
     serialization::{
         binary_builder::BinaryBuilder, duration_builder::DurationBuilder,
         fixed_size_binary_builder::FixedSizeBinaryBuilder,
@@ -17,24 +17,31 @@ use crate::internal::{
 use super::{
     bool_builder::BoolBuilder, date32_builder::Date32Builder, date64_builder::Date64Builder,
     decimal_builder::DecimalBuilder, dictionary_utf8_builder::DictionaryUtf8Builder,
-    float_builder::FloatBuilder, int_builder::IntBuilder, list_builder::ListBuilder,
-    map_builder::MapBuilder, null_builder::NullBuilder, simple_serializer::SimpleSerializer,
-    struct_builder::StructBuilder, time_builder::TimeBuilder, union_builder::UnionBuilder,
-    unknown_variant_builder::UnknownVariantBuilder, utf8_builder::Utf8Builder, ArrayBuilder,
+    float_builder::FloatBuilder, int_builder::IntBuilder, json_builder::JsonBuilder,
+    list_builder::ListBuilder, map_builder::MapBuilder, null_builder::NullBuilder,
+    simple_serializer::SimpleSerializer, struct_builder::StructBuilder, time_builder::TimeBuilder,
+    union_builder::UnionBuilder, unknown_variant_builder::UnknownVariantBuilder,
+    utf8_builder::Utf8Builder, ArrayBuilder,
 };
 
 #[derive(Debug, Clone)]
 pub struct OuterSequenceBuilder(StructBuilder);
 
 impl OuterSequenceBuilder {
-    pub fn new(schema: &SerdeArrowSchema) -> Result<Self> {
+    pub fn new(schema: &SerdeArrowSchema, field_names: FieldNames) -> Result<Self> {
         Ok(Self(build_struct(
             String::from("$"),
             &schema.fields,
             false,
+            field_names,
         )?))
     }
 
+    /// The number of records pushed into the builder
+    pub fn len(&self) -> usize {
+        self.0.seq.len
+    }
+
     /// Extract the contained struct fields
     pub fn take_records(&mut self) -> Result<Vec<ArrayBuilder>> {
         let mut result = Vec::new();
@@ -109,19 +116,24 @@ impl SimpleSerializer for OuterSequenceBuilder {
     }
 }
 
-fn build_struct(path: String, struct_fields: &[Field], nullable: bool) -> Result<StructBuilder> {
+fn build_struct(
+    path: String,
+    struct_fields: &[Field],
+    nullable: bool,
+    field_names: FieldNames,
+) -> Result<StructBuilder> {
     let mut fields = Vec::new();
     for field in struct_fields {
         let field_path = format!("{path}.{field_name}", field_name = field.name);
         fields.push((
-            build_builder(field_path, field)?,
+            build_builder(field_path, field, field_names)?,
             meta_from_field(field.clone()),
         ));
     }
-    StructBuilder::new(path, fields, nullable)
+    Ok(StructBuilder::new(path, fields, nullable)?.with_field_names(field_names))
 }
 
-fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
+pub fn build_builder(path: String, field: &Field, field_names: FieldNames) -> Result<ArrayBuilder> {
     use {ArrayBuilder as A, DataType as T};
     let ctx: BTreeMap<String, String> = btree_map!("field" => path.clone());
 
@@ -131,17 +143,47 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
             _ => A::Null(NullBuilder::new(path)),
         },
         T::Boolean => A::Bool(BoolBuilder::new(path, field.nullable)),
-        T::Int8 => A::I8(IntBuilder::new(path, field.nullable)),
-        T::Int16 => A::I16(IntBuilder::new(path, field.nullable)),
-        T::Int32 => A::I32(IntBuilder::new(path, field.nullable)),
-        T::Int64 => A::I64(IntBuilder::new(path, field.nullable)),
-        T::UInt8 => A::U8(IntBuilder::new(path, field.nullable)),
-        T::UInt16 => A::U16(IntBuilder::new(path, field.nullable)),
-        T::UInt32 => A::U32(IntBuilder::new(path, field.nullable)),
-        T::UInt64 => A::U64(IntBuilder::new(path, field.nullable)),
+        T::Int8 => A::I8(
+            IntBuilder::new(path, field.nullable)
+                .with_strategy(get_strategy_from_metadata(&field.metadata)?),
+        ),
+        T::Int16 => A::I16(
+            IntBuilder::new(path, field.nullable)
+                .with_strategy(get_strategy_from_metadata(&field.metadata)?),
+        ),
+        T::Int32 => A::I32(
+            IntBuilder::new(path, field.nullable)
+                .with_strategy(get_strategy_from_metadata(&field.metadata)?),
+        ),
+        T::Int64 => A::I64(
+            IntBuilder::new(path, field.nullable)
+                .with_strategy(get_strategy_from_metadata(&field.metadata)?),
+        ),
+        T::UInt8 => A::U8(
+            IntBuilder::new(path, field.nullable)
+                .with_strategy(get_strategy_from_metadata(&field.metadata)?),
+        ),
+        T::UInt16 => A::U16(
+            IntBuilder::new(path, field.nullable)
+                .with_strategy(get_strategy_from_metadata(&field.metadata)?),
+        ),
+        T::UInt32 => A::U32(
+            IntBuilder::new(path, field.nullable)
+                .with_strategy(get_strategy_from_metadata(&field.metadata)?),
+        ),
+        T::UInt64 => A::U64(
+            IntBuilder::new(path, field.nullable)
+                .with_strategy(get_strategy_from_metadata(&field.metadata)?),
+        ),
         T::Float16 => A::F16(FloatBuilder::new(path, field.nullable)),
-        T::Float32 => A::F32(FloatBuilder::new(path, field.nullable)),
-        T::Float64 => A::F64(FloatBuilder::new(path, field.nullable)),
+        T::Float32 => A::F32(
+            FloatBuilder::new(path, field.nullable)
+                .with_strategy(get_strategy_from_metadata(&field.metadata)?),
+        ),
+        T::Float64 => A::F64(
+            FloatBuilder::new(path, field.nullable)
+                .with_strategy(get_strategy_from_metadata(&field.metadata)?),
+        ),
         T::Date32 => A::Date32(Date32Builder::new(path, field.nullable)),
         T::Date64 => A::Date64(Date64Builder::new(
             path,
@@ -174,6 +216,18 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
             *scale,
             field.nullable,
         )),
+        T::Utf8 | T::LargeUtf8 | T::Binary | T::LargeBinary
+            if get_strategy_from_metadata(&field.metadata)? == Some(Strategy::ValueAsJson) =>
+        {
+            let inner = match &field.data_type {
+                T::Utf8 => A::Utf8(Utf8Builder::new(path.clone(), field.nullable)),
+                T::LargeUtf8 => A::LargeUtf8(Utf8Builder::new(path.clone(), field.nullable)),
+                T::Binary => A::Binary(BinaryBuilder::new(path.clone(), field.nullable)),
+                _ => A::LargeBinary(BinaryBuilder::new(path.clone(), field.nullable)),
+            };
+            let as_bytes = matches!(field.data_type, T::Binary | T::LargeBinary);
+            A::Json(JsonBuilder::new(path, inner, as_bytes))
+        }
         T::Utf8 => A::Utf8(Utf8Builder::new(path, field.nullable)),
         T::LargeUtf8 => A::LargeUtf8(Utf8Builder::new(path, field.nullable)),
         T::List(child) => {
@@ -181,7 +235,7 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
             A::List(ListBuilder::new(
                 path,
                 meta_from_field(*child.clone()),
-                build_builder(child_path, child.as_ref())?,
+                build_builder(child_path, child.as_ref(), field_names)?,
                 field.nullable,
             ))
         }
@@ -190,7 +244,7 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
             A::LargeList(ListBuilder::new(
                 path,
                 meta_from_field(*child.clone()),
-                build_builder(child_path, child.as_ref())?,
+                build_builder(child_path, child.as_ref(), field_names)?,
                 field.nullable,
             ))
         }
@@ -200,7 +254,7 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
             A::FixedSizedList(FixedSizeListBuilder::new(
                 path,
                 meta_from_field(*child.clone()),
-                build_builder(child_path, child.as_ref())?,
+                build_builder(child_path, child.as_ref(), field_names)?,
                 n,
                 field.nullable,
             ))
@@ -209,7 +263,10 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
         T::LargeBinary => A::LargeBinary(BinaryBuilder::new(path, field.nullable)),
         T::FixedSizeBinary(n) => {
             let n = usize::try_from(*n).ctx(&ctx)?;
-            A::FixedSizeBinary(FixedSizeBinaryBuilder::new(path, n, field.nullable))
+            A::FixedSizeBinary(
+                FixedSizeBinaryBuilder::new(path, n, field.nullable)
+                    .with_strategy(get_strategy_from_metadata(&field.metadata)?),
+            )
         }
         T::Map(entry_field, _) => {
             let child_path = format!(
@@ -220,13 +277,15 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
                 MapBuilder::new(
                     path,
                     meta_from_field(*entry_field.clone()),
-                    build_builder(child_path, entry_field.as_ref())?,
+                    build_builder(child_path, entry_field.as_ref(), field_names)?,
                     field.nullable,
                 )
                 .ctx(&ctx)?,
             )
         }
-        T::Struct(children) => A::Struct(build_struct(path, children, field.nullable)?),
+        T::Struct(children) => {
+            A::Struct(build_struct(path, children, field.nullable, field_names)?)
+        }
         T::Dictionary(key, value, _) => {
             let key_path = format!("{path}.key");
             let key_field = Field {
@@ -246,8 +305,8 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
 
             A::DictionaryUtf8(DictionaryUtf8Builder::new(
                 path,
-                build_builder(key_path, &key_field)?,
-                build_builder(value_path, &value_field)?,
+                build_builder(key_path, &key_field, field_names)?,
+                build_builder(value_path, &value_field, field_names)?,
             ))
         }
         T::Union(union_fields, _) => {
@@ -259,7 +318,7 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
                 let field_path =
                     format!("{path}.{field_name}", field_name = ChildName(&field.name));
                 fields.push((
-                    build_builder(field_path, field)?,
+                    build_builder(field_path, field, field_names)?,
                     meta_from_field(field.clone()),
                 ));
             }
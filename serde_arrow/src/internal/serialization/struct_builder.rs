@@ -5,6 +5,7 @@ use serde::Serialize;
 use crate::internal::{
     arrow::{Array, FieldMeta, StructArray},
     error::{fail, set_default, try_, Context, ContextSupport, Result},
+    schema::FieldNames,
     utils::{
         array_ext::{ArrayExt, CountArray, SeqArrayExt},
         Mut,
@@ -43,6 +44,12 @@ impl StructBuilder {
         })
     }
 
+    /// Translate serde field names into Arrow field names with the given policy
+    pub fn with_field_names(mut self, field_names: FieldNames) -> Self {
+        self.lookup.field_names = field_names;
+        self
+    }
+
     pub fn take_self(&mut self) -> Self {
         Self {
             path: self.path.clone(),
@@ -247,6 +254,7 @@ impl SimpleSerializer for StructBuilder {
 pub struct FieldLookup {
     pub cached_names: Vec<Option<StaticFieldName>>,
     pub index: BTreeMap<String, usize>,
+    pub field_names: FieldNames,
 }
 
 /// A wrapper around a static field name that compares using ptr and length
@@ -271,6 +279,7 @@ impl FieldLookup {
         Ok(Self {
             cached_names: vec![None; index.len()],
             index,
+            field_names: FieldNames::Unchanged,
         })
     }
 
@@ -278,6 +287,7 @@ impl FieldLookup {
         Self {
             cached_names: std::mem::replace(&mut self.cached_names, vec![None; self.index.len()]),
             index: self.index.clone(),
+            field_names: self.field_names,
         }
     }
 
@@ -285,7 +295,7 @@ impl FieldLookup {
         if self.cached_names.get(guess) == Some(&Some(StaticFieldName(key))) {
             Some(guess)
         } else {
-            let &idx = self.index.get(key)?;
+            let &idx = self.index.get(self.field_names.to_arrow(key).as_ref())?;
             if self.cached_names[idx].is_none() {
                 self.cached_names[idx] = Some(StaticFieldName(key));
             }
@@ -294,23 +304,26 @@ impl FieldLookup {
     }
 
     pub fn lookup_serialize<V: Serialize + ?Sized>(&mut self, key: &V) -> Result<Option<usize>> {
-        KeyLookupSerializer::lookup(&self.index, key)
+        KeyLookupSerializer::lookup(&self.index, self.field_names, key)
     }
 }
 
 #[derive(Debug)]
 pub struct KeyLookupSerializer<'a> {
     index: &'a BTreeMap<String, usize>,
+    field_names: FieldNames,
     result: Option<usize>,
 }
 
 impl<'a> KeyLookupSerializer<'a> {
     pub fn lookup<K: Serialize + ?Sized>(
         index: &'a BTreeMap<String, usize>,
+        field_names: FieldNames,
         key: &K,
     ) -> Result<Option<usize>> {
         let mut this = Self {
             index,
+            field_names,
             result: None,
         };
         key.serialize(Mut(&mut this))?;
@@ -324,7 +337,10 @@ impl<'a> Context for KeyLookupSerializer<'a> {
 
 impl<'a> SimpleSerializer for KeyLookupSerializer<'a> {
     fn serialize_str(&mut self, v: &str) -> Result<()> {
-        self.result = self.index.get(v).copied();
+        self.result = self
+            .index
+            .get(self.field_names.to_arrow(v).as_ref())
+            .copied();
         Ok(())
     }
 }
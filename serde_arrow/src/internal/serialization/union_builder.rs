@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 use crate::internal::{
     arrow::{Array, DenseUnionArray, FieldMeta},
     error::{fail, set_default, try_, Context, ContextSupport, Result},
+    schema::UNION_NULL_VARIANT,
     utils::Mut,
 };
 
@@ -15,12 +16,17 @@ pub struct UnionBuilder {
     pub types: Vec<i8>,
     pub offsets: Vec<i32>,
     pub current_offset: Vec<i32>,
+    pub null_variant: Option<usize>,
 }
 
 impl UnionBuilder {
     pub fn new(path: String, fields: Vec<(ArrayBuilder, FieldMeta)>) -> Self {
+        let null_variant = fields.iter().position(|(builder, meta)| {
+            meta.name == UNION_NULL_VARIANT && matches!(builder, ArrayBuilder::Null(_))
+        });
         Self {
             path,
+            null_variant,
             current_offset: vec![0; fields.len()],
             types: Vec::new(),
             offsets: Vec::new(),
@@ -39,6 +45,7 @@ impl UnionBuilder {
             types: std::mem::take(&mut self.types),
             offsets: std::mem::take(&mut self.offsets),
             current_offset: std::mem::replace(&mut self.current_offset, vec![0; self.fields.len()]),
+            null_variant: self.null_variant,
         })
     }
 
@@ -83,6 +90,20 @@ impl Context for UnionBuilder {
 }
 
 impl SimpleSerializer for UnionBuilder {
+    fn serialize_none(&mut self) -> Result<()> {
+        let mut ctx = BTreeMap::new();
+        self.annotate(&mut ctx);
+
+        try_(|| {
+            let Some(variant_index) = self.null_variant else {
+                fail!("Union without a Null variant named {UNION_NULL_VARIANT:?} cannot serialize None");
+            };
+            self.serialize_variant(u32::try_from(variant_index)?)?
+                .serialize_unit()
+        })
+        .ctx(&ctx)
+    }
+
     fn serialize_unit_variant(
         &mut self,
         _: &'static str,
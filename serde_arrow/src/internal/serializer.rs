@@ -283,6 +283,7 @@ impl<A: AsMut<ArrayBuilder>> serde::ser::SerializeTupleVariant for CollectionSer
 }
 
 const _: () = {
+    #[allow(dead_code)]
     trait AssertSendSync: Send + Sync {}
     impl<A: Send + Sync> AssertSendSync for Serializer<A> {}
 };
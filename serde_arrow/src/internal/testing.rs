@@ -17,6 +17,13 @@ pub fn assert_error_contains<T, E: std::fmt::Display>(actual: &Result<T, E>, exp
     }
 }
 
+/// Build a schema from its serde representation, panicking on errors
+#[cfg(test)]
+pub fn schema<T: serde::Serialize>(value: T) -> crate::internal::schema::SerdeArrowSchema {
+    use crate::internal::schema::{SchemaLike, SerdeArrowSchema};
+    SerdeArrowSchema::from_value(value).unwrap()
+}
+
 macro_rules! hash_map {
     () => {
         ::std::collections::HashMap::new()
@@ -34,6 +41,7 @@ pub(crate) use hash_map;
 
 use super::utils::array_ext::get_bit_buffer;
 
+#[cfg_attr(not(all(has_arrow, has_arrow2)), allow(dead_code))]
 pub(crate) trait ArrayAccess {
     fn get_utf8(&self, idx: usize) -> Result<Option<&str>>;
 }
@@ -48,6 +56,7 @@ impl ArrayAccess for Array {
     }
 }
 
+#[cfg_attr(not(all(has_arrow, has_arrow2)), allow(dead_code))]
 fn get_utf8_impl<O>(array: &BytesArray<O>, idx: usize) -> Result<Option<&str>>
 where
     O: Copy,
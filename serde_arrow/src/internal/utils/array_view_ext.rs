@@ -1,7 +1,22 @@
-use crate::internal::arrow::ArrayView;
+use crate::internal::{
+    arrow::{
+        ArrayView, BitsWithOffset, BooleanArrayView, BytesArrayView, DecimalArrayView,
+        DenseUnionArrayView, DictionaryArrayView, FixedSizeBinaryArrayView, FixedSizeListArrayView,
+        ListArrayView, NullArrayView, PrimitiveArrayView, StructArrayView, TimeArrayView,
+        TimestampArrayView,
+    },
+    error::{fail, Result},
+};
 
-pub trait ArrayViewExt {
+pub trait ArrayViewExt: Sized {
     fn len(&self) -> usize;
+
+    /// Restrict the view to the elements `offset..offset + len`
+    ///
+    /// Offset based layouts (lists, maps, strings, unions) keep their child
+    /// arrays unchanged and only restrict their offsets. The children are
+    /// positioned when the corresponding deserializers are constructed.
+    fn slice(&self, offset: usize, len: usize) -> Result<Self>;
 }
 
 impl<'a> ArrayViewExt for ArrayView<'a> {
@@ -45,4 +60,165 @@ impl<'a> ArrayViewExt for ArrayView<'a> {
             V::Dictionary(view) => view.indices.len(),
         }
     }
+
+    fn slice(&self, offset: usize, len: usize) -> Result<Self> {
+        use ArrayView as V;
+
+        let Some(end) = offset.checked_add(len) else {
+            fail!("Invalid slice: offset ({offset}) + length ({len}) overflows");
+        };
+        if end > self.len() {
+            fail!(
+                "Invalid slice: range {offset}..{end} is out of bounds for an array of length {array_len}",
+                array_len = self.len(),
+            );
+        }
+
+        Ok(match self {
+            V::Null(_) => V::Null(NullArrayView { len }),
+            V::Boolean(view) => V::Boolean(BooleanArrayView {
+                len,
+                validity: slice_bits(view.validity, offset),
+                values: BitsWithOffset {
+                    offset: view.values.offset + offset,
+                    data: view.values.data,
+                },
+            }),
+            V::Int8(view) => V::Int8(slice_primitive(view, offset, end)),
+            V::Int16(view) => V::Int16(slice_primitive(view, offset, end)),
+            V::Int32(view) => V::Int32(slice_primitive(view, offset, end)),
+            V::Int64(view) => V::Int64(slice_primitive(view, offset, end)),
+            V::UInt8(view) => V::UInt8(slice_primitive(view, offset, end)),
+            V::UInt16(view) => V::UInt16(slice_primitive(view, offset, end)),
+            V::UInt32(view) => V::UInt32(slice_primitive(view, offset, end)),
+            V::UInt64(view) => V::UInt64(slice_primitive(view, offset, end)),
+            V::Float16(view) => V::Float16(slice_primitive(view, offset, end)),
+            V::Float32(view) => V::Float32(slice_primitive(view, offset, end)),
+            V::Float64(view) => V::Float64(slice_primitive(view, offset, end)),
+            V::Date32(view) => V::Date32(slice_primitive(view, offset, end)),
+            V::Date64(view) => V::Date64(slice_primitive(view, offset, end)),
+            V::Time32(view) => V::Time32(TimeArrayView {
+                unit: view.unit,
+                validity: slice_bits(view.validity, offset),
+                values: &view.values[offset..end],
+            }),
+            V::Time64(view) => V::Time64(TimeArrayView {
+                unit: view.unit,
+                validity: slice_bits(view.validity, offset),
+                values: &view.values[offset..end],
+            }),
+            V::Timestamp(view) => V::Timestamp(TimestampArrayView {
+                unit: view.unit,
+                timezone: view.timezone.clone(),
+                validity: slice_bits(view.validity, offset),
+                values: &view.values[offset..end],
+            }),
+            V::Duration(view) => V::Duration(TimeArrayView {
+                unit: view.unit,
+                validity: slice_bits(view.validity, offset),
+                values: &view.values[offset..end],
+            }),
+            V::Decimal128(view) => V::Decimal128(DecimalArrayView {
+                precision: view.precision,
+                scale: view.scale,
+                validity: slice_bits(view.validity, offset),
+                values: &view.values[offset..end],
+            }),
+            V::Utf8(view) => V::Utf8(slice_bytes(view, offset, end)?),
+            V::LargeUtf8(view) => V::LargeUtf8(slice_bytes(view, offset, end)?),
+            V::Binary(view) => V::Binary(slice_bytes(view, offset, end)?),
+            V::LargeBinary(view) => V::LargeBinary(slice_bytes(view, offset, end)?),
+            V::FixedSizeBinary(view) => {
+                let n = usize::try_from(view.n)?;
+                V::FixedSizeBinary(FixedSizeBinaryArrayView {
+                    n: view.n,
+                    validity: slice_bits(view.validity, offset),
+                    data: &view.data[offset * n..end * n],
+                })
+            }
+            V::FixedSizeList(view) => {
+                let n = usize::try_from(view.n)?;
+                V::FixedSizeList(FixedSizeListArrayView {
+                    len,
+                    n: view.n,
+                    validity: slice_bits(view.validity, offset),
+                    meta: view.meta.clone(),
+                    element: Box::new(view.element.slice(offset * n, len * n)?),
+                })
+            }
+            V::List(view) => V::List(slice_list(view, offset, end)?),
+            V::LargeList(view) => V::LargeList(slice_list(view, offset, end)?),
+            V::Map(view) => V::Map(slice_list(view, offset, end)?),
+            V::Struct(view) => {
+                let mut fields = Vec::new();
+                for (field_view, field_meta) in &view.fields {
+                    fields.push((field_view.slice(offset, len)?, field_meta.clone()));
+                }
+                V::Struct(StructArrayView {
+                    len,
+                    validity: slice_bits(view.validity, offset),
+                    fields,
+                })
+            }
+            V::Dictionary(view) => V::Dictionary(DictionaryArrayView {
+                indices: Box::new(view.indices.slice(offset, len)?),
+                values: view.values.clone(),
+            }),
+            V::DenseUnion(view) => V::DenseUnion(DenseUnionArrayView {
+                types: &view.types[offset..end],
+                offsets: &view.offsets[offset..end],
+                fields: view.fields.clone(),
+            }),
+        })
+    }
+}
+
+fn slice_bits(bits: Option<BitsWithOffset<'_>>, offset: usize) -> Option<BitsWithOffset<'_>> {
+    bits.map(|bits| BitsWithOffset {
+        offset: bits.offset + offset,
+        data: bits.data,
+    })
+}
+
+fn slice_primitive<'a, T>(
+    view: &PrimitiveArrayView<'a, T>,
+    offset: usize,
+    end: usize,
+) -> PrimitiveArrayView<'a, T> {
+    PrimitiveArrayView {
+        validity: slice_bits(view.validity, offset),
+        values: &view.values[offset..end],
+    }
+}
+
+fn slice_bytes<'a, O>(
+    view: &BytesArrayView<'a, O>,
+    offset: usize,
+    end: usize,
+) -> Result<BytesArrayView<'a, O>> {
+    Ok(BytesArrayView {
+        validity: slice_bits(view.validity, offset),
+        offsets: slice_offsets(view.offsets, offset, end)?,
+        data: view.data,
+    })
+}
+
+fn slice_list<'a, O>(
+    view: &ListArrayView<'a, O>,
+    offset: usize,
+    end: usize,
+) -> Result<ListArrayView<'a, O>> {
+    Ok(ListArrayView {
+        validity: slice_bits(view.validity, offset),
+        offsets: slice_offsets(view.offsets, offset, end)?,
+        meta: view.meta.clone(),
+        element: view.element.clone(),
+    })
+}
+
+fn slice_offsets<O>(offsets: &[O], offset: usize, end: usize) -> Result<&[O]> {
+    let Some(offsets) = offsets.get(offset..=end) else {
+        fail!("Invalid slice: offsets must contain at least one more element than the array");
+    };
+    Ok(offsets)
 }
@@ -355,6 +355,61 @@ fn test_copy_digits() {
     assert_eq!(copy_digits_str("42.00", 4, 2).unwrap(), "4200");
 }
 
+/// The maximum precision of 128 bit decimals
+pub const MAX_PRECISION_I128: u8 = 38;
+
+/// Determine the smallest precision and scale to represent a fixed-point number
+///
+/// Only strings of the form `[+-]ddd` or `[+-]ddd.ddd` are accepted. Leading
+/// zeros of the integer part are ignored, trailing zeros of the fraction are
+/// counted towards the scale. Returns `None` if the string is not a decimal or
+/// if it cannot be represented as a 128 bit decimal.
+pub fn infer_precision_and_scale(s: &[u8]) -> Option<(u8, i8)> {
+    let (s, _) = parse_sign(s);
+    let (before_period, after_period) = find_period(s);
+
+    let integer = &s[..before_period];
+    let fraction = &s[after_period..];
+    if integer.is_empty() || (after_period != before_period && fraction.is_empty()) {
+        return None;
+    }
+    if check_all_ascii_digit(integer).is_err() || check_all_ascii_digit(fraction).is_err() {
+        return None;
+    }
+
+    let integer_digits = integer.iter().skip_while(|c| **c == b'0').count();
+    let scale = fraction.len();
+    let precision = std::cmp::max(integer_digits + scale, 1);
+    if precision > MAX_PRECISION_I128 as usize {
+        return None;
+    }
+    Some((precision as u8, scale as i8))
+}
+
+#[test]
+fn test_infer_precision_and_scale() {
+    fn infer(s: &str) -> Option<(u8, i8)> {
+        infer_precision_and_scale(s.as_bytes())
+    }
+
+    assert_eq!(infer("0"), Some((1, 0)));
+    assert_eq!(infer("0003"), Some((1, 0)));
+    assert_eq!(infer("-42"), Some((2, 0)));
+    assert_eq!(infer("12.50"), Some((4, 2)));
+    assert_eq!(infer("+0.05"), Some((2, 2)));
+    assert_eq!(infer("123.4"), Some((4, 1)));
+    assert_eq!(infer(&"9".repeat(38)), Some((38, 0)));
+
+    assert_eq!(infer(""), None);
+    assert_eq!(infer("-"), None);
+    assert_eq!(infer(".5"), None);
+    assert_eq!(infer("5."), None);
+    assert_eq!(infer("1e5"), None);
+    assert_eq!(infer("1.2.3"), None);
+    assert_eq!(infer("abc"), None);
+    assert_eq!(infer(&"9".repeat(39)), None);
+}
+
 pub fn format_decimal(buffer: &mut [u8], val: i128, scale: i8) -> &str {
     fn write_val(buffer: &mut [u8], val: i128) -> usize {
         use std::io::Write;
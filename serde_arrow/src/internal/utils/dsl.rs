@@ -106,7 +106,7 @@ impl FromStr for Term {
     }
 }
 
-fn parse_term(s: &str) -> Result<(Term, &str)> {
+pub fn parse_term(s: &str) -> Result<(Term, &str)> {
     let s = s.trim_start();
     let (name, quoted, s) = parse_term_name(s)?;
     let s = s.trim_start();
@@ -0,0 +1,642 @@
+//! A minimal JSON implementation to store values as JSON text
+//!
+//! The encoding follows the conventions of `serde_json`: options are stored
+//! as their content or `null`, enums are externally tagged, bytes are arrays
+//! of numbers and map keys are strings.
+use serde::{
+    de::{DeserializeSeed, Visitor},
+    forward_to_deserialize_any,
+};
+
+use crate::internal::{
+    error::{fail, Error, Result},
+    utils::value::Value,
+};
+
+/// The maximum nesting depth of arrays and objects, the same limit as `serde_json`
+const RECURSION_LIMIT: usize = 128;
+
+/// Append the JSON representation of the value to `out`
+pub fn write_json(out: &mut String, value: &Value) -> Result<()> {
+    write_value(out, value, 0)
+}
+
+fn write_value(out: &mut String, value: &Value, depth: usize) -> Result<()> {
+    match value {
+        Value::None | Value::Unit | Value::UnitStruct(_) => out.push_str("null"),
+        Value::Bool(v) => out.push_str(if *v { "true" } else { "false" }),
+        Value::U8(v) => out.push_str(&v.to_string()),
+        Value::U16(v) => out.push_str(&v.to_string()),
+        Value::U32(v) => out.push_str(&v.to_string()),
+        Value::U64(v) => out.push_str(&v.to_string()),
+        Value::I8(v) => out.push_str(&v.to_string()),
+        Value::I16(v) => out.push_str(&v.to_string()),
+        Value::I32(v) => out.push_str(&v.to_string()),
+        Value::I64(v) => out.push_str(&v.to_string()),
+        Value::F32(v) if v.0.is_finite() => out.push_str(&format!("{:?}", v.0)),
+        Value::F64(v) if v.0.is_finite() => out.push_str(&format!("{:?}", v.0)),
+        Value::F32(_) | Value::F64(_) => out.push_str("null"),
+        Value::StaticStr(v) => write_str(out, v),
+        Value::String(v) => write_str(out, v),
+        Value::Char(v) => write_str(out, v.encode_utf8(&mut [0; 4])),
+        Value::Bytes(v) => {
+            out.push('[');
+            for (idx, byte) in v.iter().enumerate() {
+                if idx != 0 {
+                    out.push(',');
+                }
+                out.push_str(&byte.to_string());
+            }
+            out.push(']');
+        }
+        Value::Some(v) | Value::NewtypeStruct(_, v) => write_value(out, v, depth)?,
+        Value::Seq(values) | Value::Tuple(values) | Value::TupleStruct(_, values) => {
+            write_array(out, values, nested(depth)?)?
+        }
+        Value::Map(entries) => {
+            out.push('{');
+            for (idx, (key, value)) in entries.iter().enumerate() {
+                if idx != 0 {
+                    out.push(',');
+                }
+                write_key(out, key)?;
+                out.push(':');
+                write_value(out, value, nested(depth)?)?;
+            }
+            out.push('}');
+        }
+        Value::Struct(_, fields) => write_object(out, fields, nested(depth)?)?,
+        Value::UnitVariant(variant) => write_str(out, variant.1),
+        Value::NewtypeVariant(variant, value) => {
+            out.push('{');
+            write_str(out, variant.1);
+            out.push(':');
+            write_value(out, value, nested(depth)?)?;
+            out.push('}');
+        }
+        Value::TupleVariant(variant, values) => {
+            out.push('{');
+            write_str(out, variant.1);
+            out.push(':');
+            write_array(out, values, nested(nested(depth)?)?)?;
+            out.push('}');
+        }
+        Value::StructVariant(variant, fields) => {
+            out.push('{');
+            write_str(out, variant.1);
+            out.push(':');
+            write_object(out, fields, nested(nested(depth)?)?)?;
+            out.push('}');
+        }
+    }
+    Ok(())
+}
+
+/// The depth of the children of an array or object at `depth`
+fn nested(depth: usize) -> Result<usize> {
+    if depth >= RECURSION_LIMIT {
+        fail!("Cannot store value as JSON: recursion limit of {RECURSION_LIMIT} exceeded");
+    }
+    Ok(depth + 1)
+}
+
+fn write_array(out: &mut String, values: &[Value], depth: usize) -> Result<()> {
+    out.push('[');
+    for (idx, value) in values.iter().enumerate() {
+        if idx != 0 {
+            out.push(',');
+        }
+        write_value(out, value, depth)?;
+    }
+    out.push(']');
+    Ok(())
+}
+
+fn write_object(out: &mut String, fields: &[(&'static str, Value)], depth: usize) -> Result<()> {
+    out.push('{');
+    for (idx, (name, value)) in fields.iter().enumerate() {
+        if idx != 0 {
+            out.push(',');
+        }
+        write_str(out, name);
+        out.push(':');
+        write_value(out, value, depth)?;
+    }
+    out.push('}');
+    Ok(())
+}
+
+fn write_key(out: &mut String, key: &Value) -> Result<()> {
+    match key {
+        Value::StaticStr(v) => write_str(out, v),
+        Value::String(v) => write_str(out, v),
+        Value::Char(v) => write_str(out, v.encode_utf8(&mut [0; 4])),
+        Value::UnitVariant(variant) => write_str(out, variant.1),
+        Value::Bool(v) => write_str(out, if *v { "true" } else { "false" }),
+        Value::U8(v) => write_str(out, &v.to_string()),
+        Value::U16(v) => write_str(out, &v.to_string()),
+        Value::U32(v) => write_str(out, &v.to_string()),
+        Value::U64(v) => write_str(out, &v.to_string()),
+        Value::I8(v) => write_str(out, &v.to_string()),
+        Value::I16(v) => write_str(out, &v.to_string()),
+        Value::I32(v) => write_str(out, &v.to_string()),
+        Value::I64(v) => write_str(out, &v.to_string()),
+        Value::NewtypeStruct(_, v) => write_key(out, v)?,
+        key => {
+            fail!("Cannot store map key {key:?} as JSON: keys must be strings, numbers or bools")
+        }
+    }
+    Ok(())
+}
+
+fn write_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// A parsed JSON document
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    F64(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+/// Parse a JSON document
+pub fn parse_json(s: &str) -> Result<JsonValue> {
+    let mut parser = Parser {
+        data: s.as_bytes(),
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.pos != parser.data.len() {
+        fail!(
+            "Invalid JSON: trailing characters at position {pos}",
+            pos = parser.pos
+        );
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    data: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Result<()> {
+        self.skip_whitespace();
+        if self.peek() != Some(c) {
+            fail!(
+                "Invalid JSON: expected {c:?} at position {pos}",
+                c = c as char,
+                pos = self.pos
+            );
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn expect_literal(&mut self, literal: &str, value: JsonValue) -> Result<JsonValue> {
+        if !self.data[self.pos..].starts_with(literal.as_bytes()) {
+            fail!(
+                "Invalid JSON: expected {literal} at position {pos}",
+                pos = self.pos
+            );
+        }
+        self.pos += literal.len();
+        Ok(value)
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'n') => self.expect_literal("null", JsonValue::Null),
+            Some(b't') => self.expect_literal("true", JsonValue::Bool(true)),
+            Some(b'f') => self.expect_literal("false", JsonValue::Bool(false)),
+            Some(b'"') => Ok(JsonValue::String(self.parse_string()?)),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(b'[') => self.parse_nested(Self::parse_array),
+            Some(b'{') => self.parse_nested(Self::parse_object),
+            Some(_) => fail!(
+                "Invalid JSON: unexpected character at position {pos}",
+                pos = self.pos
+            ),
+            None => fail!("Invalid JSON: unexpected end of input"),
+        }
+    }
+
+    fn parse_nested(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<JsonValue>,
+    ) -> Result<JsonValue> {
+        if self.depth >= RECURSION_LIMIT {
+            fail!(
+                "Invalid JSON: recursion limit of {RECURSION_LIMIT} exceeded at position {pos}",
+                pos = self.pos
+            );
+        }
+        self.depth += 1;
+        let res = parse(self);
+        self.depth -= 1;
+        res
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue> {
+        self.pos += 1;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(JsonValue::Array(values));
+        }
+        loop {
+            values.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Array(values));
+                }
+                _ => fail!(
+                    "Invalid JSON: expected ',' or ']' at position {pos}",
+                    pos = self.pos
+                ),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue> {
+        self.pos += 1;
+        let mut entries = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(JsonValue::Object(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.expect(b':')?;
+            entries.push((key, self.parse_value()?));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Object(entries));
+                }
+                _ => fail!(
+                    "Invalid JSON: expected ',' or '}}' at position {pos}",
+                    pos = self.pos
+                ),
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue> {
+        let start = self.pos;
+        let mut is_float = false;
+        while let Some(c) = self.peek() {
+            match c {
+                b'-' | b'+' | b'0'..=b'9' => {}
+                b'.' | b'e' | b'E' => is_float = true,
+                _ => break,
+            }
+            self.pos += 1;
+        }
+        // the slice consists of ASCII characters only
+        let s = std::str::from_utf8(&self.data[start..self.pos])?;
+        if !is_float {
+            if let Ok(v) = s.parse::<u64>() {
+                return Ok(JsonValue::U64(v));
+            }
+            if let Ok(v) = s.parse::<i64>() {
+                return Ok(JsonValue::I64(v));
+            }
+        }
+        match s.parse::<f64>() {
+            Ok(v) => Ok(JsonValue::F64(v)),
+            Err(_) => fail!("Invalid JSON: invalid number {s:?}"),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String> {
+        if self.peek() != Some(b'"') {
+            fail!(
+                "Invalid JSON: expected string at position {pos}",
+                pos = self.pos
+            );
+        }
+        self.pos += 1;
+
+        let mut result = String::new();
+        let mut run_start = self.pos;
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    result.push_str(std::str::from_utf8(&self.data[run_start..self.pos])?);
+                    self.pos += 1;
+                    return Ok(result);
+                }
+                Some(b'\\') => {
+                    result.push_str(std::str::from_utf8(&self.data[run_start..self.pos])?);
+                    self.pos += 1;
+                    result.push(self.parse_escape()?);
+                    run_start = self.pos;
+                }
+                Some(_) => self.pos += 1,
+                None => fail!("Invalid JSON: unterminated string"),
+            }
+        }
+    }
+
+    fn parse_escape(&mut self) -> Result<char> {
+        let Some(c) = self.peek() else {
+            fail!("Invalid JSON: unterminated escape sequence");
+        };
+        self.pos += 1;
+        match c {
+            b'"' => Ok('"'),
+            b'\\' => Ok('\\'),
+            b'/' => Ok('/'),
+            b'b' => Ok('\u{8}'),
+            b'f' => Ok('\u{c}'),
+            b'n' => Ok('\n'),
+            b'r' => Ok('\r'),
+            b't' => Ok('\t'),
+            b'u' => {
+                let high = self.parse_hex4()?;
+                let code = if (0xD800..0xDC00).contains(&high) {
+                    if !self.data[self.pos..].starts_with(b"\\u") {
+                        fail!("Invalid JSON: unpaired surrogate in string");
+                    }
+                    self.pos += 2;
+                    let low = self.parse_hex4()?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        fail!("Invalid JSON: unpaired surrogate in string");
+                    }
+                    0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                } else {
+                    high
+                };
+                match char::from_u32(code) {
+                    Some(c) => Ok(c),
+                    None => fail!("Invalid JSON: invalid unicode escape"),
+                }
+            }
+            c => fail!("Invalid JSON: invalid escape sequence \\{c}", c = c as char),
+        }
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32> {
+        let Some(digits) = self.data.get(self.pos..self.pos + 4) else {
+            fail!("Invalid JSON: incomplete unicode escape");
+        };
+        let code = u32::from_str_radix(std::str::from_utf8(digits)?, 16)?;
+        self.pos += 4;
+        Ok(code)
+    }
+}
+
+/// Deserialize Rust values from a parsed JSON document
+pub struct JsonValueDeserializer<'a>(pub &'a JsonValue);
+
+impl<'de, 'a> serde::de::Deserializer<'de> for JsonValueDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.0 {
+            JsonValue::Null => visitor.visit_unit(),
+            &JsonValue::Bool(v) => visitor.visit_bool(v),
+            &JsonValue::U64(v) => visitor.visit_u64(v),
+            &JsonValue::I64(v) => visitor.visit_i64(v),
+            &JsonValue::F64(v) => visitor.visit_f64(v),
+            JsonValue::String(v) => visitor.visit_str(v),
+            JsonValue::Array(values) => visitor.visit_seq(JsonSeqAccess(values.iter())),
+            JsonValue::Object(entries) => visitor.visit_map(JsonMapAccess {
+                entries: entries.iter(),
+                next_value: None,
+            }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.0 {
+            JsonValue::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.0 {
+            JsonValue::String(variant) => visitor.visit_enum(JsonEnumAccess(variant, None)),
+            JsonValue::Object(entries) if entries.len() == 1 => {
+                let (variant, value) = &entries[0];
+                visitor.visit_enum(JsonEnumAccess(variant, Some(value)))
+            }
+            _ => fail!("Cannot deserialize enum: expected a string or an object with a single key"),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct JsonSeqAccess<'a>(std::slice::Iter<'a, JsonValue>);
+
+impl<'de, 'a> serde::de::SeqAccess<'de> for JsonSeqAccess<'a> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        match self.0.next() {
+            Some(value) => Ok(Some(seed.deserialize(JsonValueDeserializer(value))?)),
+            None => Ok(None),
+        }
+    }
+}
+
+struct JsonMapAccess<'a> {
+    entries: std::slice::Iter<'a, (String, JsonValue)>,
+    next_value: Option<&'a JsonValue>,
+}
+
+impl<'de, 'a> serde::de::MapAccess<'de> for JsonMapAccess<'a> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.next_value = Some(value);
+        Ok(Some(seed.deserialize(JsonKeyDeserializer(key))?))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let Some(value) = self.next_value.take() else {
+            fail!("Invalid usage: next_value_seed called without prior call to next_key_seed");
+        };
+        seed.deserialize(JsonValueDeserializer(value))
+    }
+}
+
+struct JsonEnumAccess<'a>(&'a str, Option<&'a JsonValue>);
+
+impl<'de, 'a> serde::de::EnumAccess<'de> for JsonEnumAccess<'a> {
+    type Error = Error;
+    type Variant = JsonVariantAccess<'a>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant)> {
+        let variant = seed.deserialize(JsonKeyDeserializer(self.0))?;
+        Ok((variant, JsonVariantAccess(self.1)))
+    }
+}
+
+struct JsonVariantAccess<'a>(Option<&'a JsonValue>);
+
+impl<'de, 'a> serde::de::VariantAccess<'de> for JsonVariantAccess<'a> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        match self.0 {
+            None | Some(JsonValue::Null) => Ok(()),
+            Some(_) => fail!("Invalid variant: expected unit variant, found variant with data"),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        match self.0 {
+            Some(value) => seed.deserialize(JsonValueDeserializer(value)),
+            None => fail!("Invalid variant: expected newtype variant, found unit variant"),
+        }
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        match self.0 {
+            Some(JsonValue::Array(values)) => visitor.visit_seq(JsonSeqAccess(values.iter())),
+            _ => fail!("Invalid variant: expected tuple variant"),
+        }
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.0 {
+            Some(JsonValue::Object(entries)) => visitor.visit_map(JsonMapAccess {
+                entries: entries.iter(),
+                next_value: None,
+            }),
+            _ => fail!("Invalid variant: expected struct variant"),
+        }
+    }
+}
+
+/// Deserialize map keys, numbers and bools are parsed on request
+struct JsonKeyDeserializer<'a>(&'a str);
+
+macro_rules! deserialize_parsed_key {
+    ($($method:ident => $visit:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                match self.0.parse::<$ty>() {
+                    Ok(v) => visitor.$visit(v),
+                    Err(_) => visitor.visit_str(self.0),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> serde::de::Deserializer<'de> for JsonKeyDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_enum(JsonEnumAccess(self.0, None))
+    }
+
+    deserialize_parsed_key!(
+        deserialize_bool => visit_bool(bool),
+        deserialize_i8 => visit_i8(i8),
+        deserialize_i16 => visit_i16(i16),
+        deserialize_i32 => visit_i32(i32),
+        deserialize_i64 => visit_i64(i64),
+        deserialize_u8 => visit_u8(u8),
+        deserialize_u16 => visit_u16(u16),
+        deserialize_u32 => visit_u32(u32),
+        deserialize_u64 => visit_u64(u64),
+    );
+
+    forward_to_deserialize_any! {
+        i128 u128 f32 f64 char str string bytes byte_buf unit unit_struct seq
+        tuple tuple_struct map struct identifier ignored_any
+    }
+}
@@ -2,6 +2,9 @@ pub mod array_ext;
 pub mod array_view_ext;
 pub mod decimal;
 pub mod dsl;
+pub mod json;
+pub mod slice;
+pub mod str_formats;
 pub mod value;
 
 #[cfg(test)]
@@ -111,7 +114,7 @@ impl<T: Serialize> Serialize for Items<Vec<T>> {
     }
 }
 
-impl<'a, T: Serialize> Serialize for Items<&'a Vec<T>> {
+impl<T: Serialize> Serialize for Items<&Vec<T>> {
     fn serialize<S: serde::Serializer>(
         &self,
         serializer: S,
@@ -129,7 +132,7 @@ impl<const N: usize, T: Serialize> Serialize for Items<[T; N]> {
     }
 }
 
-impl<'a, const N: usize, T: Serialize> Serialize for Items<&'a [T; N]> {
+impl<const N: usize, T: Serialize> Serialize for Items<&[T; N]> {
     fn serialize<S: serde::Serializer>(
         &self,
         serializer: S,
@@ -138,7 +141,7 @@ impl<'a, const N: usize, T: Serialize> Serialize for Items<&'a [T; N]> {
     }
 }
 
-impl<'a, T: Serialize> Serialize for Items<&'a [T]> {
+impl<T: Serialize> Serialize for Items<&[T]> {
     fn serialize<S: serde::Serializer>(
         &self,
         serializer: S,
@@ -0,0 +1,123 @@
+use std::marker::PhantomData;
+
+use half::f16;
+use serde::{de::Visitor, Deserialize};
+
+pub trait Sealed {}
+
+/// Primitive types that can be borrowed from arrow arrays via [`Slice`]
+pub trait SlicePrimitive: Sealed + Sized + 'static {
+    #[doc(hidden)]
+    const SLICE_NAME: &'static str;
+
+    #[doc(hidden)]
+    fn cast_slice(bytes: &[u8]) -> Option<&[Self]>;
+}
+
+macro_rules! impl_slice_primitive {
+    ($($ty:ty),*) => {
+        $(
+            impl Sealed for $ty {}
+
+            impl SlicePrimitive for $ty {
+                const SLICE_NAME: &'static str = concat!("serde_arrow::Slice<", stringify!($ty), ">");
+
+                fn cast_slice(bytes: &[u8]) -> Option<&[Self]> {
+                    bytemuck::try_cast_slice(bytes).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_slice_primitive!(i8, i16, i32, i64, u8, u16, u32, u64, f16, f32, f64);
+
+/// The prefix of the newtype names used to request zero-copy slices
+pub const SLICE_NAME_PREFIX: &str = "serde_arrow::Slice<";
+
+/// A zero-copy view of a primitive column
+///
+/// It can be used as a field when deserializing columns with
+/// [`Deserializer::deserialize_columns`][crate::Deserializer::deserialize_columns]
+/// to borrow the values of non-nullable primitive columns instead of copying
+/// them. The element type must match the data type of the column exactly and
+/// the column must not contain nulls. Zero-copy slices are only supported
+/// when deserializing from a single record batch.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     Deserializer,
+///     schema::{SchemaLike, TracingOptions},
+///     utils::Slice,
+/// };
+///
+/// ##[derive(Serialize, Deserialize)]
+/// struct Record {
+///     a: f32,
+///     b: i32,
+/// }
+///
+/// ##[derive(Deserialize)]
+/// struct Columns<'a> {
+///     ##[serde(borrow)]
+///     a: Slice<'a, f32>,
+///     b: Vec<i32>,
+/// }
+///
+/// let items = vec![Record { a: 1.0, b: 2 }, Record { a: 3.0, b: 4 }];
+/// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+/// let record_batch = serde_arrow::to_record_batch(&fields, &items)?;
+///
+/// let columns: Columns = Deserializer::from_record_batch(&record_batch)?.deserialize_columns()?;
+/// assert_eq!(&*columns.a, &[1.0, 3.0]);
+/// assert_eq!(columns.b, vec![2, 4]);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Slice<'a, T>(
+    /// The borrowed values
+    pub &'a [T],
+);
+
+impl<'a, T> std::ops::Deref for Slice<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<'de: 'a, 'a, T: SlicePrimitive> Deserialize<'de> for Slice<'a, T> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct(T::SLICE_NAME, SliceVisitor(PhantomData))
+    }
+}
+
+struct SliceVisitor<'a, T>(PhantomData<&'a [T]>);
+
+impl<'de: 'a, 'a, T: SlicePrimitive> Visitor<'de> for SliceVisitor<'a, T> {
+    type Value = Slice<'a, T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a zero-copy slice of a primitive arrow array")
+    }
+
+    fn visit_borrowed_bytes<E: serde::de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        match T::cast_slice(v) {
+            Some(values) => Ok(Slice(values)),
+            None => Err(E::custom(
+                "Cannot cast the array buffer to the element type",
+            )),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Conversion between common string formats and their binary representation
+//!
+//! UUIDs and IP addresses are stored as 16 bytes. IPv4 addresses are stored as
+//! IPv4-mapped IPv6 addresses (`::ffff:a.b.c.d`).
+
+use std::net::{IpAddr, Ipv6Addr};
+
+use crate::internal::error::{fail, Result};
+
+/// Parse a UUID in its canonical hyphenated form (e.g., `"67e55044-10b1-426f-9247-bb680e5fe0c8"`)
+pub fn parse_uuid(s: &str) -> Option<[u8; 16]> {
+    const GROUPS: [usize; 5] = [8, 4, 4, 4, 12];
+
+    let mut res = [0_u8; 16];
+    let mut idx = 0;
+    for (group_idx, group) in s.split('-').enumerate() {
+        if group_idx >= GROUPS.len() || group.len() != GROUPS[group_idx] {
+            return None;
+        }
+        for pair in group.as_bytes().chunks(2) {
+            let hi = hex_value(pair[0])?;
+            let lo = hex_value(pair[1])?;
+            res[idx] = (hi << 4) | lo;
+            idx += 1;
+        }
+    }
+    if idx != 16 {
+        return None;
+    }
+    Some(res)
+}
+
+fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Format 16 bytes as a lower case hyphenated UUID
+pub fn format_uuid(bytes: &[u8]) -> Result<String> {
+    if bytes.len() != 16 {
+        fail!(
+            "Invalid UUID: expected 16 bytes, got {len}",
+            len = bytes.len()
+        );
+    }
+
+    let mut res = String::with_capacity(36);
+    for (idx, byte) in bytes.iter().enumerate() {
+        if matches!(idx, 4 | 6 | 8 | 10) {
+            res.push('-');
+        }
+        res.push_str(&format!("{byte:02x}"));
+    }
+    Ok(res)
+}
+
+/// Parse an IPv4 or IPv6 address
+pub fn parse_ip_address(s: &str) -> Option<[u8; 16]> {
+    match s.parse::<IpAddr>().ok()? {
+        IpAddr::V4(addr) => Some(addr.to_ipv6_mapped().octets()),
+        IpAddr::V6(addr) => Some(addr.octets()),
+    }
+}
+
+/// Format 16 bytes as an IP address, IPv4-mapped addresses are formatted as IPv4 addresses
+pub fn format_ip_address(bytes: &[u8]) -> Result<String> {
+    let Ok(octets) = <[u8; 16]>::try_from(bytes) else {
+        fail!(
+            "Invalid IP address: expected 16 bytes, got {len}",
+            len = bytes.len()
+        );
+    };
+    let addr = Ipv6Addr::from(octets);
+    match addr.to_ipv4_mapped() {
+        Some(addr) => Ok(addr.to_string()),
+        None => Ok(addr.to_string()),
+    }
+}
+
+#[test]
+fn test_uuid() {
+    let s = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    let bytes = parse_uuid(s).unwrap();
+    assert_eq!(bytes[0], 0x67);
+    assert_eq!(bytes[15], 0xc8);
+    assert_eq!(format_uuid(&bytes).unwrap(), s);
+
+    assert_eq!(
+        parse_uuid("67E55044-10B1-426F-9247-BB680E5FE0C8"),
+        Some(bytes)
+    );
+
+    assert_eq!(parse_uuid(""), None);
+    assert_eq!(parse_uuid("67e5504410b1426f9247bb680e5fe0c8"), None);
+    assert_eq!(parse_uuid("67e55044-10b1-426f-9247-bb680e5fe0c"), None);
+    assert_eq!(parse_uuid("67e55044-10b1-426f-9247-bb680e5fe0c8-00"), None);
+    assert_eq!(parse_uuid("g7e55044-10b1-426f-9247-bb680e5fe0c8"), None);
+    assert!(format_uuid(&[0; 4]).is_err());
+}
+
+#[test]
+fn test_ip_address() {
+    let bytes = parse_ip_address("192.168.0.1").unwrap();
+    assert_eq!(&bytes[10..], &[0xff, 0xff, 192, 168, 0, 1]);
+    assert_eq!(format_ip_address(&bytes).unwrap(), "192.168.0.1");
+
+    let bytes = parse_ip_address("2001:db8::1").unwrap();
+    assert_eq!(format_ip_address(&bytes).unwrap(), "2001:db8::1");
+
+    assert_eq!(parse_ip_address("192.168.0"), None);
+    assert_eq!(parse_ip_address("1.5"), None);
+    assert_eq!(parse_ip_address("foo"), None);
+    assert!(format_ip_address(&[0; 4]).is_err());
+}
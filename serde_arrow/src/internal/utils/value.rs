@@ -4,7 +4,7 @@ use serde::{de::DeserializeOwned, forward_to_deserialize_any, Serialize};
 use crate::internal::error::{fail, Error, Result};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Variant(u32, &'static str);
+pub struct Variant(pub u32, pub &'static str);
 
 /// A in-memory representation of a Serde value
 ///
@@ -49,7 +49,7 @@ pub enum Value {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct HashF32(f32);
+pub struct HashF32(pub f32);
 
 impl std::cmp::PartialEq<HashF32> for HashF32 {
     fn eq(&self, other: &Self) -> bool {
@@ -66,7 +66,7 @@ impl std::hash::Hash for HashF32 {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct HashF64(f64);
+pub struct HashF64(pub f64);
 
 impl std::cmp::PartialEq<HashF64> for HashF64 {
     fn eq(&self, other: &Self) -> bool {
@@ -294,6 +294,7 @@ impl serde::ser::Serializer for ValueSerializer {
     }
 }
 
+#[derive(Debug, Clone)]
 pub struct SeqSerializer(Vec<Value>);
 
 impl SeqSerializer {
@@ -330,7 +331,7 @@ impl serde::ser::SerializeTuple for SeqSerializer {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TupleStructSerializer {
     name: &'static str,
     values: Vec<Value>,
@@ -359,6 +360,7 @@ impl serde::ser::SerializeTupleStruct for TupleStructSerializer {
     }
 }
 
+#[derive(Debug, Clone)]
 pub struct MapSerializer {
     next_key: Option<Value>,
     entries: Vec<(Value, Value)>,
@@ -404,6 +406,7 @@ impl serde::ser::SerializeMap for MapSerializer {
     }
 }
 
+#[derive(Debug, Clone)]
 pub struct StructSerializer {
     name: &'static str,
     entries: Vec<(&'static str, Value)>,
@@ -437,6 +440,7 @@ impl serde::ser::SerializeStruct for StructSerializer {
     }
 }
 
+#[derive(Debug, Clone)]
 pub struct StructVariantSerializer {
     variant_index: u32,
     variant_name: &'static str,
@@ -475,6 +479,7 @@ impl serde::ser::SerializeStructVariant for StructVariantSerializer {
     }
 }
 
+#[derive(Debug, Clone)]
 pub struct TupleVariantSerializer {
     variant_index: u32,
     variant_name: &'static str,
@@ -299,6 +299,19 @@ pub mod _impl {
         pub mod status {}
     }
 
+    /// Support for the `ArrowSchema` derive macro
+    #[doc(hidden)]
+    pub mod derive {
+        pub use crate::internal::{
+            arrow::Field,
+            schema::derive::{
+                custom_field, flattened_fields, null_field, nullable, rename_field, rename_variant,
+                schema_from_field, struct_field, tuple_field, union_field, unknown_variant,
+                with_strategy, ArrowField,
+            },
+        };
+    }
+
     // Reexport for tests
     #[doc(hidden)]
     pub use crate::internal::{
@@ -313,6 +326,10 @@ mod test_with_arrow;
 #[cfg(test)]
 mod test;
 
+// allow to use the derive macro inside the crate's tests
+#[cfg(test)]
+extern crate self as serde_arrow;
+
 pub use crate::internal::error::{Error, Result};
 
 pub use crate::internal::deserializer::Deserializer;
@@ -324,7 +341,10 @@ pub use crate::internal::array_builder::ArrayBuilder;
 mod arrow_impl;
 
 #[cfg(has_arrow)]
-pub use arrow_impl::api::{from_arrow, from_record_batch, to_arrow, to_record_batch};
+pub use arrow_impl::api::{
+    from_arrow, from_record_batch, from_record_batches, iter_from_record_batches, to_arrow,
+    to_record_batch, to_record_batch_with_schema,
+};
 
 #[cfg(has_arrow2)]
 mod arrow2_impl;
@@ -335,7 +355,10 @@ pub use arrow2_impl::api::{from_arrow2, to_arrow2};
 #[deny(missing_docs)]
 /// Helpers that may be useful when using `serde_arrow`
 pub mod utils {
-    pub use crate::internal::utils::{Item, Items};
+    pub use crate::internal::utils::{
+        slice::{Slice, SlicePrimitive},
+        Item, Items,
+    };
 }
 
 /// The mapping between Rust and Arrow types
@@ -381,13 +404,18 @@ pub mod utils {
 /// # #[cfg(not(feature="has_arrow2"))]
 /// # fn main() {}
 /// ```
-
 #[deny(missing_docs)]
 pub mod schema {
     pub use crate::internal::schema::{
-        Overwrites, SchemaLike, SerdeArrowSchema, Strategy, TracingOptions, STRATEGY_KEY,
+        ArrowSchema, Compatibility, DataTypeSubstitutions, FieldMetadata, FieldNames, Overwrites,
+        PatternOverwrites, SchemaChange, SchemaChangeKind, SchemaDiff, SchemaLike, SchemaTracer,
+        SerdeArrowSchema, Strategy, TracingOptions, TypeHints, DEFAULT_VALUE_KEY, STRATEGY_KEY,
     };
 
+    /// Derive [`ArrowSchema`] for a type (*requires the `derive` feature*)
+    #[cfg(feature = "derive")]
+    pub use serde_arrow_derive::ArrowSchema;
+
     /// Support for [canonical extension types][ext-docs]. This module is experimental without semver guarantees.
     ///
     /// [ext-docs]: https://arrow.apache.org/docs/format/CanonicalExtensions.html
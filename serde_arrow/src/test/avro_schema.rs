@@ -0,0 +1,132 @@
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SchemaLike, SerdeArrowSchema},
+    testing::assert_error_contains,
+};
+
+#[test]
+fn primitives_and_logical_types() -> PanicOnError<()> {
+    let actual = SerdeArrowSchema::from_avro_schema(json!({
+        "type": "record",
+        "name": "Record",
+        "namespace": "com.example",
+        "fields": [
+            {"name": "null", "type": "null"},
+            {"name": "bool", "type": "boolean"},
+            {"name": "int", "type": "int"},
+            {"name": "long", "type": {"type": "long"}},
+            {"name": "float", "type": "float"},
+            {"name": "double", "type": "double"},
+            {"name": "bytes", "type": "bytes"},
+            {"name": "string", "type": "string", "doc": "a string"},
+            {"name": "date", "type": {"type": "int", "logicalType": "date"}},
+            {"name": "time_millis", "type": {"type": "int", "logicalType": "time-millis"}},
+            {"name": "time_micros", "type": {"type": "long", "logicalType": "time-micros"}},
+            {"name": "ts_micros", "type": {"type": "long", "logicalType": "timestamp-micros"}},
+            {"name": "local_ts", "type": {"type": "long", "logicalType": "local-timestamp-millis"}},
+            {"name": "decimal", "type": {"type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2}},
+            {"name": "uuid", "type": {"type": "string", "logicalType": "uuid"}},
+        ],
+    }))?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "null", "data_type": "Null", "nullable": true},
+        {"name": "bool", "data_type": "Bool"},
+        {"name": "int", "data_type": "I32"},
+        {"name": "long", "data_type": "I64"},
+        {"name": "float", "data_type": "F32"},
+        {"name": "double", "data_type": "F64"},
+        {"name": "bytes", "data_type": "LargeBinary"},
+        {"name": "string", "data_type": "LargeUtf8"},
+        {"name": "date", "data_type": "Date32"},
+        {"name": "time_millis", "data_type": "Time32(Millisecond)"},
+        {"name": "time_micros", "data_type": "Time64(Microsecond)"},
+        {"name": "ts_micros", "data_type": "Timestamp(Microsecond, Some(\"UTC\"))"},
+        {"name": "local_ts", "data_type": "Timestamp(Millisecond, None)"},
+        {"name": "decimal", "data_type": "Decimal128(10, 2)"},
+        {"name": "uuid", "data_type": "LargeUtf8"},
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn complex_and_named_types() -> PanicOnError<()> {
+    let actual = SerdeArrowSchema::from_avro_schema(json!({
+        "type": "record",
+        "name": "Record",
+        "namespace": "com.example",
+        "fields": [
+            {
+                "name": "point",
+                "type": {
+                    "type": "record",
+                    "name": "Point",
+                    "fields": [{"name": "x", "type": "double"}, {"name": "y", "type": "double"}],
+                },
+            },
+            {"name": "other_point", "type": ["null", "Point"]},
+            {"name": "qualified_point", "type": "com.example.Point"},
+            {"name": "suit", "type": {"type": "enum", "name": "Suit", "symbols": ["SPADES", "HEARTS"]}},
+            {"name": "hash", "type": {"type": "fixed", "name": "Hash", "size": 16}},
+            {"name": "hashes", "type": {"type": "array", "items": "Hash"}},
+            {"name": "counts", "type": {"type": "map", "values": ["null", "long"]}},
+            {"name": "value", "type": ["null", "long", "string", "Point"]},
+        ],
+    }))?;
+
+    let point = json!([{"name": "x", "data_type": "F64"}, {"name": "y", "data_type": "F64"}]);
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "point", "data_type": "Struct", "children": point},
+        {"name": "other_point", "data_type": "Struct", "nullable": true, "children": point},
+        {"name": "qualified_point", "data_type": "Struct", "children": point},
+        {"name": "suit", "data_type": "Dictionary", "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ]},
+        {"name": "hash", "data_type": "FixedSizeBinary(16)"},
+        {"name": "hashes", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "FixedSizeBinary(16)"},
+        ]},
+        {"name": "counts", "data_type": "Map", "children": [
+            {"name": "entries", "data_type": "Struct", "children": [
+                {"name": "key", "data_type": "LargeUtf8"},
+                {"name": "value", "data_type": "I64", "nullable": true},
+            ]},
+        ]},
+        {"name": "value", "data_type": "Union", "children": [
+            {"name": "long", "data_type": "I64"},
+            {"name": "string", "data_type": "LargeUtf8"},
+            {"name": "Point", "data_type": "Struct", "children": point},
+            {"name": "null", "data_type": "Null", "nullable": true},
+        ]},
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let res = SerdeArrowSchema::from_avro_schema(json!({
+        "type": "record",
+        "name": "Node",
+        "fields": [
+            {"name": "value", "type": "long"},
+            {"name": "next", "type": ["null", "Node"]},
+        ],
+    }));
+    assert_error_contains(&res, "Recursive types are not supported");
+    assert_error_contains(&res, "field: \"$.next\"");
+
+    let res = SerdeArrowSchema::from_avro_schema(json!({
+        "type": "record",
+        "name": "Record",
+        "fields": [{"name": "value", "type": "Unknown"}],
+    }));
+    assert_error_contains(&res, "Unknown type \"Unknown\"");
+    assert_error_contains(&res, "field: \"$.value\"");
+
+    let res = SerdeArrowSchema::from_avro_schema(json!("long"));
+    assert_error_contains(&res, "The root of an Avro schema must be a record");
+}
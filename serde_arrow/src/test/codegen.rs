@@ -0,0 +1,111 @@
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SchemaLike, SerdeArrowSchema},
+};
+
+#[test]
+fn example_schema() -> PanicOnError<()> {
+    let schema = SerdeArrowSchema::from_value(json!([
+        {"name": "id", "data_type": "U64"},
+        {"name": "createdAt", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+        {"name": "type", "data_type": "Dictionary", "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ]},
+        {"name": "date", "data_type": "Date32", "nullable": true},
+        {"name": "tags", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "LargeUtf8"},
+        ]},
+        {"name": "location", "data_type": "Struct", "nullable": true, "children": [
+            {"name": "lat", "data_type": "F64"},
+            {"name": "lon", "data_type": "F64"},
+        ]},
+        {"name": "pair", "data_type": "Struct", "strategy": "TupleAsStruct", "children": [
+            {"name": "0", "data_type": "U8"},
+            {"name": "1", "data_type": "Bool"},
+        ]},
+        {"name": "attributes", "data_type": "Map", "children": [
+            {"name": "entries", "data_type": "Struct", "children": [
+                {"name": "key", "data_type": "Utf8"},
+                {"name": "value", "data_type": "I32", "nullable": true},
+            ]},
+        ]},
+        {"name": "status", "data_type": "Union", "children": [
+            {"name": "Active", "data_type": "Null"},
+            {"name": "archived", "data_type": "Struct", "children": [
+                {"name": "reason", "data_type": "Utf8"},
+            ]},
+            {"name": "", "data_type": "Null", "strategy": "UnknownVariant"},
+            {"name": "Other", "data_type": "I64"},
+        ]},
+    ]))?;
+
+    let actual = schema.generate_rust_types("record")?;
+    let expected = r#"use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub id: u64,
+    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub r#type: String,
+    pub date: Option<chrono::NaiveDate>,
+    pub tags: Vec<String>,
+    pub location: Option<RecordLocation>,
+    pub pair: (u8, bool),
+    pub attributes: std::collections::HashMap<String, Option<i32>>,
+    pub status: RecordStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordLocation {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RecordStatus {
+    Active,
+    #[serde(rename = "archived")]
+    Archived(RecordStatusArchived),
+    #[serde(skip)]
+    Unknown2,
+    Other(i64),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordStatusArchived {
+    pub reason: String,
+}
+"#;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn item_schema() -> PanicOnError<()> {
+    let schema = SerdeArrowSchema::from_value(json!([
+        {"name": "item", "data_type": "LargeList", "nullable": true, "children": [
+            {"name": "element", "data_type": "Struct", "children": [
+                {"name": "a", "data_type": "Date64", "strategy": "NaiveStrAsDate64"},
+                {"name": "b", "data_type": "Timestamp(Microsecond, None)"},
+            ]},
+        ]},
+    ]))?;
+
+    let actual = schema.generate_rust_types("Values")?;
+    let expected = r#"use serde::{Deserialize, Serialize};
+
+pub type Values = serde_arrow::utils::Item<Option<Vec<ValuesItem>>>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValuesItem {
+    pub a: chrono::NaiveDateTime,
+    pub b: chrono::NaiveDateTime,
+}
+"#;
+    assert_eq!(actual, expected);
+    Ok(())
+}
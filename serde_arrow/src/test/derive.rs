@@ -0,0 +1,277 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_arrow_derive::ArrowSchema;
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{ArrowSchema, SchemaLike, SerdeArrowSchema, TracingOptions},
+    testing::assert_error_contains,
+};
+
+#[test]
+fn serde_renames() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Serialize, ArrowSchema)]
+    #[serde(rename_all = "camelCase")]
+    struct Example {
+        record_id: u64,
+        #[serde(rename = "Value")]
+        value: f32,
+        #[serde(rename(serialize = "ser_name", deserialize = "de_name"))]
+        renamed: bool,
+        r#type: String,
+        #[serde(skip)]
+        skipped: HashMap<String, u8>,
+    }
+
+    let actual = Example::arrow_schema()?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "recordId", "data_type": "U64"},
+        {"name": "Value", "data_type": "F32"},
+        {"name": "ser_name", "data_type": "Bool"},
+        {"name": "type", "data_type": "LargeUtf8"},
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn arrow_attributes() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Serialize, ArrowSchema)]
+    struct Example {
+        #[arrow(data_type = "Timestamp(Millisecond, Some(\"UTC\"))")]
+        #[serde(with = "chrono::serde::ts_milliseconds")]
+        timestamp: chrono::DateTime<chrono::Utc>,
+        #[arrow(data_type = "Date64", strategy = "NaiveStrAsDate64")]
+        date_time: Option<chrono::NaiveDateTime>,
+        #[arrow(data_type = "Utf8")]
+        name: String,
+        #[arrow(strategy = "StrAsNumber")]
+        number: u32,
+    }
+
+    let actual = Example::arrow_schema()?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "timestamp", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+        {
+            "name": "date_time",
+            "data_type": "Date64",
+            "strategy": "NaiveStrAsDate64",
+            "nullable": true,
+        },
+        {"name": "name", "data_type": "Utf8"},
+        {"name": "number", "data_type": "U32", "strategy": "StrAsNumber"},
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn invalid_arrow_attributes() {
+    #[allow(unused)]
+    #[derive(Serialize, ArrowSchema)]
+    struct InvalidStrategy {
+        #[arrow(data_type = "Utf8", strategy = "NaiveStrAsDate64")]
+        value: String,
+    }
+
+    let res = InvalidStrategy::arrow_schema();
+    assert_error_contains(&res, "invalid strategy for Utf8");
+    assert_error_contains(&res, "field: \"value\"");
+}
+
+#[test]
+fn nested_types_match_from_type() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Serialize, Deserialize, ArrowSchema)]
+    struct Outer {
+        id: i64,
+        inner: Inner,
+        items: Vec<Option<Inner>>,
+        pair: (u8, String),
+        newtype: Newtype,
+        tuple_struct: TupleStruct,
+        unit: (),
+        character: char,
+        value: Enum,
+    }
+
+    #[allow(unused)]
+    #[derive(Serialize, Deserialize, ArrowSchema)]
+    struct Inner {
+        a: Option<bool>,
+        b: Vec<f64>,
+    }
+
+    #[allow(unused)]
+    #[derive(Serialize, Deserialize, ArrowSchema)]
+    struct Newtype(u16);
+
+    #[allow(unused)]
+    #[derive(Serialize, Deserialize, ArrowSchema)]
+    struct TupleStruct(i8, Option<u32>);
+
+    #[allow(unused)]
+    #[derive(Serialize, Deserialize, ArrowSchema)]
+    enum Enum {
+        Unit,
+        Newtype(i32),
+        Tuple(u8, u8),
+        Struct { a: String },
+    }
+
+    let actual = Outer::arrow_schema()?;
+    let expected =
+        SerdeArrowSchema::from_type::<Outer>(TracingOptions::default().allow_null_fields(true))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn maps() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Serialize, ArrowSchema)]
+    struct Example {
+        map: HashMap<String, Option<i32>>,
+    }
+
+    let actual = Example::arrow_schema()?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {
+            "name": "map",
+            "data_type": "Map",
+            "children": [
+                {
+                    "name": "entries",
+                    "data_type": "Struct",
+                    "children": [
+                        {"name": "key", "data_type": "LargeUtf8"},
+                        {"name": "value", "data_type": "I32", "nullable": true},
+                    ],
+                },
+            ],
+        },
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn flatten_and_skip_serializing_if() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Serialize, ArrowSchema)]
+    struct Example {
+        id: u32,
+        #[serde(flatten)]
+        inner: Inner,
+        #[serde(flatten)]
+        optional: Option<Optional>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        comment: Option<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+    }
+
+    #[allow(unused)]
+    #[derive(Serialize, ArrowSchema)]
+    struct Inner {
+        a: u8,
+        b: u16,
+    }
+
+    #[allow(unused)]
+    #[derive(Serialize, ArrowSchema)]
+    struct Optional {
+        c: bool,
+    }
+
+    let actual = Example::arrow_schema()?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "id", "data_type": "U32"},
+        {"name": "a", "data_type": "U8"},
+        {"name": "b", "data_type": "U16"},
+        {"name": "c", "data_type": "Bool", "nullable": true},
+        {"name": "comment", "data_type": "LargeUtf8", "nullable": true},
+        {
+            "name": "tags",
+            "data_type": "LargeList",
+            "nullable": true,
+            "children": [{"name": "element", "data_type": "LargeUtf8"}],
+        },
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn transparent_and_generic_types() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Serialize, ArrowSchema)]
+    #[serde(transparent)]
+    struct Wrapper<T> {
+        value: T,
+    }
+
+    #[allow(unused)]
+    #[derive(Serialize, ArrowSchema)]
+    struct Example<T> {
+        wrapped: Wrapper<T>,
+        values: Vec<T>,
+    }
+
+    let actual = Example::<i16>::arrow_schema()?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "wrapped", "data_type": "I16"},
+        {
+            "name": "values",
+            "data_type": "LargeList",
+            "children": [{"name": "element", "data_type": "I16"}],
+        },
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn enum_renames_and_skipped_variants() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Serialize, ArrowSchema)]
+    struct Example {
+        value: Enum,
+    }
+
+    #[allow(unused)]
+    #[derive(Serialize, ArrowSchema)]
+    #[serde(rename_all = "snake_case")]
+    enum Enum {
+        FirstVariant(u8),
+        #[serde(skip)]
+        Skipped(u8),
+        #[serde(rename_all = "UPPERCASE")]
+        LastVariant {
+            field_name: u8,
+        },
+    }
+
+    let actual = Example::arrow_schema()?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {
+            "name": "value",
+            "data_type": "Union",
+            "children": [
+                {"name": "first_variant", "data_type": "U8"},
+                {"name": "", "data_type": "Null", "strategy": "UnknownVariant"},
+                {
+                    "name": "last_variant",
+                    "data_type": "Struct",
+                    "children": [{"name": "FIELD_NAME", "data_type": "U8"}],
+                },
+            ],
+        },
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::internal::{
     arrow::{ArrayView, FieldMeta, ListArrayView, PrimitiveArrayView},
     deserialization::array_deserializer::ArrayDeserializer,
+    schema::FieldNames,
     testing::assert_error_contains,
 };
 
@@ -25,7 +26,7 @@ fn invalid_offsets() {
         ..reference.clone()
     });
     assert_error_contains(
-        &ArrayDeserializer::new(String::from("foo"), None, view),
+        &ArrayDeserializer::new(String::from("foo"), None, view, FieldNames::Unchanged),
         "non empty",
     );
 
@@ -34,7 +35,7 @@ fn invalid_offsets() {
         ..reference.clone()
     });
     assert_error_contains(
-        &ArrayDeserializer::new(String::from("foo"), None, view),
+        &ArrayDeserializer::new(String::from("foo"), None, view, FieldNames::Unchanged),
         "monotonically increasing",
     );
 }
@@ -1,6 +1,7 @@
 use crate::internal::{
     arrow::{ArrayView, DenseUnionArrayView, FieldMeta, PrimitiveArrayView},
     deserialization::array_deserializer::ArrayDeserializer,
+    schema::FieldNames,
     testing::assert_error_contains,
 };
 
@@ -40,7 +41,7 @@ fn non_consecutive_offsets() {
         fields: fields.clone(),
     });
     assert_error_contains(
-        &ArrayDeserializer::new(String::from("foo"), None, view),
+        &ArrayDeserializer::new(String::from("foo"), None, view, FieldNames::Unchanged),
         "consecutive offsets",
     );
 
@@ -51,7 +52,7 @@ fn non_consecutive_offsets() {
         fields: fields.clone(),
     });
     assert_error_contains(
-        &ArrayDeserializer::new(String::from("foo"), None, view),
+        &ArrayDeserializer::new(String::from("foo"), None, view, FieldNames::Unchanged),
         "consecutive offsets",
     );
 }
@@ -1,10 +1,25 @@
-use crate::internal::error::Error;
+use serde_json::json;
+
+use crate::internal::{
+    error::Error,
+    schema::{SerdeArrowSchema, TracingOptions},
+    testing::{assert_error_contains, schema},
+};
 
 #[test]
 fn backtrace_on_debug() {
     let err = Error::custom(String::from("foo bar"));
 
     // NOTE: the exact message depends on the ability of Rust to capture a backtrace
-    assert_eq!(format!("{}", err).contains("Backtrace"), false);
-    assert_eq!(format!("{:?}", err).contains("Backtrace"), true);
+    assert!(!format!("{}", err).contains("Backtrace"));
+    assert!(format!("{:?}", err).contains("Backtrace"));
+}
+
+#[test]
+fn decimal_data_types_in_messages() {
+    let schema_a = schema(json!([{"name": "a", "data_type": "Decimal128(10, 2)"}]));
+    let schema_b = schema(json!([{"name": "a", "data_type": "Utf8"}]));
+
+    let res = SerdeArrowSchema::merge(&schema_a, &schema_b, TracingOptions::default());
+    assert_error_contains(&res, "incompatible data types Decimal128(10, 2) and Utf8");
 }
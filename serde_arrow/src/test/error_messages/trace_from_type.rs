@@ -63,3 +63,40 @@ fn unsupported_recursive_types() {
     // NOTE: do not check the complete path, it depends on the recursion limit
     assert_error_contains(&res, "path: \"$.left.left.left.left.left.left");
 }
+
+#[test]
+fn flatten_structs_require_type_hints() {
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Example {
+        a: i64,
+        #[serde(flatten)]
+        inner: Inner,
+    }
+
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Inner {
+        b: i64,
+    }
+
+    let res = SerdeArrowSchema::from_type::<Example>(TracingOptions::default());
+    assert_error_contains(&res, "`#[serde(flatten)]`");
+    assert_error_contains(&res, "TracingOptions::path_type_hint");
+    assert_error_contains(&res, "path: \"$\"");
+}
+
+#[test]
+fn untagged_enums_require_type_hints() {
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        Number(f64),
+        Text(String),
+    }
+
+    let res = SerdeArrowSchema::from_type::<Item<Value>>(TracingOptions::default());
+    assert_error_contains(&res, "`#[serde(untagged)]`");
+    assert_error_contains(&res, "path: \"$.item\"");
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions, STRATEGY_KEY},
+    testing::{assert_error_contains, hash_map},
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u64,
+    date: String,
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Tag {
+    name: String,
+}
+
+fn items() -> Vec<Record> {
+    vec![Record {
+        id: 1,
+        date: String::from("2024-01-01T12:00:00"),
+        tags: vec![Tag {
+            name: String::from("a"),
+        }],
+    }]
+}
+
+#[test]
+fn nested_fields() -> PanicOnError<()> {
+    let options = TracingOptions::default()
+        .field_metadata("id", "description", "The id")
+        .field_metadata("id", "pii", "false")
+        .field_metadata("tags.element.name", "description", "The name of the tag");
+
+    let expected = SerdeArrowSchema::from_value(json!([
+        {
+            "name": "id",
+            "data_type": "U64",
+            "metadata": {"description": "The id", "pii": "false"},
+        },
+        {"name": "date", "data_type": "LargeUtf8"},
+        {
+            "name": "tags",
+            "data_type": "LargeList",
+            "children": [{
+                "name": "element",
+                "data_type": "Struct",
+                "children": [{
+                    "name": "name",
+                    "data_type": "LargeUtf8",
+                    "metadata": {"description": "The name of the tag"},
+                }],
+            }],
+        },
+    ]))?;
+
+    let from_type = SerdeArrowSchema::from_type::<Record>(options.clone())?;
+    assert_eq!(from_type, expected);
+
+    let items = items();
+    let from_samples = SerdeArrowSchema::from_samples(&items, options)?;
+    assert_eq!(from_samples, expected);
+    Ok(())
+}
+
+#[test]
+fn combined_with_strategies_and_overwrites() -> PanicOnError<()> {
+    let options = TracingOptions::default()
+        .guess_dates(true)
+        .overwrite("id", json!({"name": "id", "data_type": "U32"}))?
+        .field_metadata("id", "unit", "count")
+        .field_metadata("date", "description", "The date");
+
+    let items = items();
+    let schema = SerdeArrowSchema::from_samples(&items, options)?;
+    assert_eq!(schema.fields[0].metadata, hash_map!("unit" => "count"));
+    assert_eq!(
+        schema.fields[1].metadata,
+        hash_map!(STRATEGY_KEY => "NaiveStrAsDate64", "description" => "The date"),
+    );
+    Ok(())
+}
+
+#[test]
+fn unknown_paths_are_an_error() {
+    let options = TracingOptions::default().field_metadata("tags.name", "description", "...");
+    let res = SerdeArrowSchema::from_type::<Record>(options);
+    assert_error_contains(&res, "Fields with metadata could not be found");
+    assert_error_contains(&res, "tags.name");
+}
+
+#[test]
+fn schema_metadata() -> PanicOnError<()> {
+    let options = TracingOptions::default()
+        .schema_metadata("owner", "core")
+        .schema_metadata("version", "2");
+    let schema = SerdeArrowSchema::from_type::<Record>(options)?;
+    assert_eq!(
+        schema.metadata(),
+        &hash_map!("owner" => "core", "version" => "2")
+    );
+
+    let roundtripped = SerdeArrowSchema::from_value(&schema)?;
+    assert_eq!(roundtripped, schema);
+
+    let without_metadata = SerdeArrowSchema::from_type::<Record>(TracingOptions::default())?;
+    assert!(without_metadata.metadata().is_empty());
+    assert_eq!(
+        serde_json::to_value(&without_metadata)?.get("metadata"),
+        None
+    );
+    Ok(())
+}
+
+#[test]
+fn merged_schema_metadata() -> PanicOnError<()> {
+    let schema_a = SerdeArrowSchema::from_value(json!({
+        "fields": [{"name": "a", "data_type": "U8"}],
+        "metadata": {"owner": "a", "source": "a"},
+    }))?;
+    let schema_b = SerdeArrowSchema::from_value(json!({
+        "fields": [{"name": "a", "data_type": "U8"}],
+        "metadata": {"owner": "b", "version": "1"},
+    }))?;
+
+    let merged = SerdeArrowSchema::merge(&schema_a, &schema_b, TracingOptions::default())?;
+    assert_eq!(
+        merged.metadata(),
+        &hash_map!("owner" => "a", "source" => "a", "version" => "1")
+    );
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{FieldNames, SchemaLike, SerdeArrowSchema, TracingOptions},
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    user_id: u64,
+    home_address: Address,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Address {
+    street_name: String,
+    zip: u32,
+}
+
+#[test]
+fn conversions() {
+    let cases = [
+        (FieldNames::Unchanged, "user_id"),
+        (FieldNames::LowerCase, "user_id"),
+        (FieldNames::UpperCase, "USER_ID"),
+        (FieldNames::PascalCase, "UserId"),
+        (FieldNames::CamelCase, "userId"),
+        (FieldNames::SnakeCase, "user_id"),
+        (FieldNames::ScreamingSnakeCase, "USER_ID"),
+        (FieldNames::KebabCase, "user-id"),
+        (FieldNames::ScreamingKebabCase, "USER-ID"),
+    ];
+    for (field_names, expected) in cases {
+        assert_eq!(field_names.to_arrow("user_id"), expected, "{field_names:?}");
+        assert_eq!(field_names.to_serde(expected), "user_id", "{field_names:?}");
+    }
+}
+
+#[test]
+fn conversions_follow_serde() {
+    assert_eq!(FieldNames::CamelCase.to_arrow("_user_id"), "userId");
+    assert_eq!(FieldNames::PascalCase.to_arrow("_user_id"), "UserId");
+    assert_eq!(FieldNames::SnakeCase.variant_to_arrow("UserId"), "user_id");
+    assert_eq!(FieldNames::CamelCase.variant_to_arrow("UserId"), "userId");
+    assert_eq!(
+        FieldNames::ScreamingKebabCase.variant_to_arrow("UserId"),
+        "USER-ID"
+    );
+}
+
+#[test]
+fn from_type_camel_case() -> PanicOnError<()> {
+    let actual = SerdeArrowSchema::from_type::<Record>(
+        TracingOptions::default().field_names(FieldNames::CamelCase),
+    )?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "userId", "data_type": "U64"},
+        {
+            "name": "homeAddress",
+            "data_type": "Struct",
+            "children": [
+                {"name": "streetName", "data_type": "LargeUtf8"},
+                {"name": "zip", "data_type": "U32"},
+            ],
+        },
+        {
+            "name": "tags",
+            "data_type": "LargeList",
+            "children": [{"name": "element", "data_type": "LargeUtf8"}],
+        },
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn from_samples_matches_from_type() -> PanicOnError<()> {
+    let items = [Record {
+        user_id: 1,
+        home_address: Address {
+            street_name: String::from("Main Street"),
+            zip: 12345,
+        },
+        tags: vec![String::from("a")],
+    }];
+    let options = TracingOptions::default().field_names(FieldNames::ScreamingSnakeCase);
+    let from_samples = SerdeArrowSchema::from_samples(&items, options.clone())?;
+    let from_type = SerdeArrowSchema::from_type::<Record>(options)?;
+    assert_eq!(from_samples, from_type);
+    assert_eq!(from_samples.fields[0].name, "USER_ID");
+    Ok(())
+}
+
+#[test]
+fn map_as_struct_keys() -> PanicOnError<()> {
+    let items = [BTreeMap::from([("first_key", 1_u8), ("second_key", 2_u8)])];
+    let actual = SerdeArrowSchema::from_samples(
+        &items,
+        TracingOptions::default().field_names(FieldNames::KebabCase),
+    )?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "first-key", "data_type": "U8"},
+        {"name": "second-key", "data_type": "U8"},
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn enum_variants_are_not_renamed() -> PanicOnError<()> {
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    enum Event {
+        PageView { page_url: String },
+    }
+
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        event_data: Event,
+    }
+
+    let actual = SerdeArrowSchema::from_type::<Record>(
+        TracingOptions::default().field_names(FieldNames::CamelCase),
+    )?;
+    let expected = SerdeArrowSchema::from_value(json!([{
+        "name": "eventData",
+        "data_type": "Union",
+        "children": [{
+            "name": "PageView",
+            "data_type": "Struct",
+            "children": [{"name": "pageUrl", "data_type": "LargeUtf8"}],
+        }],
+    }]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SchemaLike, SchemaTracer, SerdeArrowSchema, TracingOptions},
+    testing::assert_error_contains,
+};
+
+#[test]
+fn evidence_accumulates_across_calls() -> PanicOnError<()> {
+    let mut tracer = SchemaTracer::new(TracingOptions::default().coerce_numbers(true));
+    tracer.trace_samples(json!([{"a": 1, "b": "foo"}]))?;
+    tracer.trace_samples(json!([{"a": -2, "c": [true]}]))?;
+    tracer.trace_samples(json!([{"a": 0.5, "b": null, "c": []}]))?;
+
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "a", "data_type": "F64"},
+        {"name": "b", "data_type": "LargeUtf8", "nullable": true},
+        {
+            "name": "c",
+            "data_type": "LargeList",
+            "nullable": true,
+            "children": [{"name": "element", "data_type": "Bool"}],
+        },
+    ]))?;
+    assert_eq!(tracer.to_schema()?, expected);
+    Ok(())
+}
+
+#[test]
+fn incremental_tracing_matches_from_samples() -> PanicOnError<()> {
+    let samples = json!([
+        {"a": 1, "b": {"x": "foo"}},
+        {"a": 2, "b": null, "c": {"k": 1}},
+        {"a": 3, "b": {"x": null}, "c": {"k": 2, "l": 3}},
+    ]);
+    let options = TracingOptions::default();
+
+    let mut tracer = SchemaTracer::new(options.clone());
+    for sample in samples.as_array().unwrap() {
+        tracer.trace_samples([sample])?;
+    }
+
+    let expected = SerdeArrowSchema::from_samples(&samples, options)?;
+    assert_eq!(tracer.to_schema()?, expected);
+    Ok(())
+}
+
+#[test]
+fn serialized_state_resumes_tracing() -> PanicOnError<()> {
+    let options = TracingOptions::default()
+        .allow_null_fields(true)
+        .string_dictionary_encoding(true);
+
+    let mut tracer = SchemaTracer::new(options.clone());
+    tracer.trace_samples(json!([{"a": "foo", "b": null}]))?;
+
+    let state = serde_json::to_string(&tracer)?;
+    let mut resumed: SchemaTracer = serde_json::from_str(&state)?;
+    assert_eq!(resumed, tracer);
+    assert!(resumed.get_options().string_dictionary_encoding);
+
+    resumed.trace_samples(json!([{"a": "bar", "b": 13}]))?;
+    tracer.trace_samples(json!([{"a": "bar", "b": 13}]))?;
+    assert_eq!(resumed.to_schema()?, tracer.to_schema()?);
+    Ok(())
+}
+
+#[test]
+fn failed_tracing_keeps_the_state() -> PanicOnError<()> {
+    let mut tracer = SchemaTracer::new(TracingOptions::default());
+    tracer.trace_samples(json!([{"a": 1}]))?;
+    let before = tracer.clone();
+
+    let res = tracer.trace_samples(json!([{"b": 1}, {"b": "foo"}]));
+    assert!(res.is_err());
+    assert_eq!(tracer, before);
+    Ok(())
+}
+
+#[test]
+fn failed_tracing_keeps_the_observed_string_values() -> PanicOnError<()> {
+    let options = TracingOptions::default().dictionary_encoding_threshold(Some(0.6));
+    let mut tracer = SchemaTracer::new(options);
+    tracer.trace_samples(json!([{"a": "foo"}, {"a": "bar"}]))?;
+    let before = tracer.clone();
+
+    let res = tracer.trace_samples(json!([{"a": "baz"}, {"a": "qux"}, {"a": 1}]));
+    assert!(res.is_err());
+    assert_eq!(tracer, before);
+
+    let state = serde_json::to_string(&tracer)?;
+    let mut resumed: SchemaTracer = serde_json::from_str(&state)?;
+    assert_eq!(resumed, tracer);
+
+    for tracer in [&mut tracer, &mut resumed] {
+        tracer.trace_samples(json!([{"a": "foo"}, {"a": "bar"}]))?;
+        let expected = SerdeArrowSchema::from_value(json!([{
+            "name": "a",
+            "data_type": "Dictionary",
+            "children": [
+                {"name": "key", "data_type": "U8"},
+                {"name": "value", "data_type": "LargeUtf8"},
+            ],
+        }]))?;
+        assert_eq!(tracer.to_schema()?, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn empty_tracer() {
+    let tracer = SchemaTracer::new(TracingOptions::default());
+    assert_error_contains(&tracer.to_schema(), "null only field");
+}
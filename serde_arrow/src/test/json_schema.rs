@@ -0,0 +1,205 @@
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SchemaLike, SerdeArrowSchema},
+    testing::assert_error_contains,
+};
+
+#[test]
+fn primitives_and_required() -> PanicOnError<()> {
+    let actual = SerdeArrowSchema::from_json_schema(json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "Event",
+        "type": "object",
+        "properties": {
+            "flag": {"type": "boolean"},
+            "count": {"type": "integer", "minimum": 0},
+            "value": {"type": ["number", "null"]},
+            "name": {"type": "string", "description": "the name"},
+            "date": {"type": "string", "format": "date"},
+            "timestamp": {"type": "string", "format": "date-time"},
+            "id": {"type": "string", "format": "uuid"},
+            "address": {"type": "string", "format": "ipv4"},
+            "nothing": {"type": "null"},
+            "optional": {"type": "string", "nullable": true},
+        },
+        "required": ["flag", "count", "value", "name", "date", "timestamp", "id", "address", "optional"],
+    }))?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "address", "data_type": "FixedSizeBinary(16)", "strategy": "IpStrAsFixedSizeBinary"},
+        {"name": "count", "data_type": "I64"},
+        {"name": "date", "data_type": "Date32"},
+        {"name": "flag", "data_type": "Bool"},
+        {
+            "name": "id",
+            "data_type": "FixedSizeBinary(16)",
+            "strategy": "UuidStrAsFixedSizeBinary",
+            "metadata": {"ARROW:extension:name": "arrow.uuid"},
+        },
+        {"name": "name", "data_type": "LargeUtf8"},
+        {"name": "nothing", "data_type": "Null", "nullable": true},
+        {"name": "optional", "data_type": "LargeUtf8", "nullable": true},
+        {
+            "name": "timestamp",
+            "data_type": "Timestamp(Millisecond, Some(\"UTC\"))",
+            "strategy": "UtcStrAsDate64",
+        },
+        {"name": "value", "data_type": "F64", "nullable": true},
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn nested_types() -> PanicOnError<()> {
+    let actual = SerdeArrowSchema::from_json_schema(json!({
+        "type": "object",
+        "properties": {
+            "list": {"type": "array", "items": {"type": ["integer", "null"]}},
+            "tuple": {"type": "array", "prefixItems": [{"type": "integer"}, {"type": "string"}]},
+            "old_tuple": {"type": "array", "items": [{"type": "boolean"}]},
+            "map": {"type": "object", "additionalProperties": {"type": "number"}},
+            "nested": {
+                "type": "object",
+                "properties": {"a": {"type": "string"}},
+                "required": ["a"],
+                "additionalProperties": false,
+            },
+            "kind": {"type": "string", "enum": ["a", "b", null]},
+            "version": {"const": 2},
+        },
+        "required": ["list", "tuple", "old_tuple", "map", "nested", "kind", "version"],
+    }))?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "kind", "data_type": "Dictionary", "nullable": true, "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ]},
+        {"name": "list", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "I64", "nullable": true},
+        ]},
+        {"name": "map", "data_type": "Map", "children": [
+            {"name": "entries", "data_type": "Struct", "children": [
+                {"name": "key", "data_type": "LargeUtf8"},
+                {"name": "value", "data_type": "F64"},
+            ]},
+        ]},
+        {"name": "nested", "data_type": "Struct", "children": [
+            {"name": "a", "data_type": "LargeUtf8"},
+        ]},
+        {"name": "old_tuple", "data_type": "Struct", "strategy": "TupleAsStruct", "children": [
+            {"name": "0", "data_type": "Bool"},
+        ]},
+        {"name": "tuple", "data_type": "Struct", "strategy": "TupleAsStruct", "children": [
+            {"name": "0", "data_type": "I64"},
+            {"name": "1", "data_type": "LargeUtf8"},
+        ]},
+        {"name": "version", "data_type": "I64"},
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+/// The JSON Schema generated by schemars for externally tagged enums
+#[test]
+fn one_of_with_definitions() -> PanicOnError<()> {
+    let actual = SerdeArrowSchema::from_json_schema(json!({
+        "title": "Record",
+        "type": "object",
+        "required": ["status"],
+        "properties": {
+            "status": {"$ref": "#/definitions/Status"},
+            "previous": {
+                "anyOf": [{"$ref": "#/definitions/Status"}, {"type": "null"}],
+            },
+            "value": {
+                "oneOf": [
+                    {"title": "int", "type": "integer"},
+                    {"title": "str", "type": "string"},
+                ],
+            },
+        },
+        "definitions": {
+            "Status": {
+                "oneOf": [
+                    {"type": "string", "enum": ["Active", "Inactive"]},
+                    {
+                        "type": "object",
+                        "required": ["Archived"],
+                        "properties": {"Archived": {"allOf": [{"$ref": "#/$defs/Reason"}]}},
+                        "additionalProperties": false,
+                    },
+                ],
+            },
+        },
+        "$defs": {
+            "Reason": {
+                "type": "object",
+                "properties": {"reason": {"type": "string"}},
+                "required": ["reason"],
+            },
+        },
+    }))?;
+
+    let status = json!({"data_type": "Union", "children": [
+        {"name": "Active", "data_type": "Null"},
+        {"name": "Inactive", "data_type": "Null"},
+        {"name": "Archived", "data_type": "Struct", "children": [
+            {"name": "reason", "data_type": "LargeUtf8"},
+        ]},
+    ]});
+    let mut previous = status.clone();
+    previous["name"] = json!("previous");
+    previous["children"]
+        .as_array_mut()
+        .unwrap()
+        .push(json!({"name": "null", "data_type": "Null", "nullable": true}));
+    let mut status = status;
+    status["name"] = json!("status");
+
+    let expected = SerdeArrowSchema::from_value(json!([
+        previous,
+        status,
+        {"name": "value", "data_type": "Union", "children": [
+            {"name": "int", "data_type": "I64"},
+            {"name": "str", "data_type": "LargeUtf8"},
+            {"name": "null", "data_type": "Null", "nullable": true},
+        ]},
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let res = SerdeArrowSchema::from_json_schema(json!({
+        "type": "object",
+        "properties": {"node": {"$ref": "#/definitions/Node"}},
+        "definitions": {
+            "Node": {
+                "type": "object",
+                "properties": {"children": {"type": "array", "items": {"$ref": "#/definitions/Node"}}},
+            },
+        },
+    }));
+    assert_error_contains(&res, "Recursive references are not supported");
+    assert_error_contains(&res, "field: \"$.node.children.element\"");
+
+    let res = SerdeArrowSchema::from_json_schema(json!({
+        "type": "object",
+        "properties": {"a": {"type": ["integer", "string"]}},
+    }));
+    assert_error_contains(&res, "Multiple types are not supported");
+    assert_error_contains(&res, "field: \"$.a\"");
+
+    let res = SerdeArrowSchema::from_json_schema(json!({
+        "type": "object",
+        "properties": {"a": {"$ref": "https://example.com/schema.json"}},
+    }));
+    assert_error_contains(&res, "only references to definitions are supported");
+
+    let res =
+        SerdeArrowSchema::from_json_schema(json!({"type": "array", "items": {"type": "integer"}}));
+    assert_error_contains(&res, "The root of a JSON Schema must be an object");
+}
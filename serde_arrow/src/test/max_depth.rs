@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    testing::assert_error_contains,
+    utils::{
+        json::{parse_json, write_json, JsonValue, JsonValueDeserializer},
+        value::{Value, ValueSerializer},
+        Item,
+    },
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Comment {
+    text: String,
+    replies: Vec<Comment>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Expr {
+    Literal(i64),
+    Neg(Box<Expr>),
+    Add { lhs: Box<Expr>, rhs: Box<Expr> },
+}
+
+fn comment(text: &str, replies: Vec<Comment>) -> Comment {
+    Comment {
+        text: text.to_string(),
+        replies,
+    }
+}
+
+#[test]
+fn recursive_types_require_max_depth() {
+    let res = SerdeArrowSchema::from_type::<Comment>(TracingOptions::default());
+    assert_error_contains(&res, "Too deeply nested type detected");
+    assert_error_contains(&res, "max_depth");
+}
+
+#[test]
+fn struct_tree_from_type() -> PanicOnError<()> {
+    let actual =
+        SerdeArrowSchema::from_type::<Comment>(TracingOptions::default().max_depth(Some(3)))?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "text", "data_type": "LargeUtf8"},
+        {
+            "name": "replies",
+            "data_type": "LargeList",
+            "children": [{
+                "name": "element",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "text", "data_type": "LargeUtf8"},
+                    {
+                        "name": "replies",
+                        "data_type": "LargeList",
+                        "children": [{
+                            "name": "element",
+                            "data_type": "LargeUtf8",
+                            "nullable": true,
+                            "strategy": "ValueAsJson",
+                        }],
+                    },
+                ],
+            }],
+        },
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn struct_tree_from_samples() -> PanicOnError<()> {
+    let items = vec![
+        comment("a", vec![comment("b", vec![comment("c", vec![])])]),
+        comment("d", vec![]),
+    ];
+    let from_samples =
+        SerdeArrowSchema::from_samples(&items, TracingOptions::default().max_depth(Some(3)))?;
+    let from_type =
+        SerdeArrowSchema::from_type::<Comment>(TracingOptions::default().max_depth(Some(3)))?;
+    assert_eq!(from_samples, from_type);
+    Ok(())
+}
+
+#[test]
+fn binary_fallback() -> PanicOnError<()> {
+    let actual = SerdeArrowSchema::from_type::<Comment>(
+        TracingOptions::default()
+            .max_depth(Some(1))
+            .max_depth_as_binary(true),
+    )?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "text", "data_type": "LargeUtf8"},
+        {
+            "name": "replies",
+            "data_type": "LargeList",
+            "children": [{
+                "name": "element",
+                "data_type": "LargeBinary",
+                "nullable": true,
+                "strategy": "ValueAsJson",
+            }],
+        },
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn enum_tree_from_type() -> PanicOnError<()> {
+    let actual =
+        SerdeArrowSchema::from_type::<Item<Expr>>(TracingOptions::default().max_depth(Some(2)))?;
+    let json_field = |name: &str| {
+        json!({
+            "name": name,
+            "data_type": "LargeUtf8",
+            "nullable": true,
+            "strategy": "ValueAsJson",
+        })
+    };
+    let expected = SerdeArrowSchema::from_value(json!([{
+        "name": "item",
+        "data_type": "Union",
+        "children": [
+            {"name": "Literal", "data_type": "I64"},
+            {
+                "name": "Neg",
+                "data_type": "Union",
+                "children": [json_field("Literal"), json_field("Neg"), json_field("Add")],
+            },
+            {
+                "name": "Add",
+                "data_type": "Struct",
+                "children": [json_field("lhs"), json_field("rhs")],
+            },
+        ],
+    }]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn json_roundtrip() -> PanicOnError<()> {
+    let value = Expr::Add {
+        lhs: Box::new(Expr::Literal(-1)),
+        rhs: Box::new(Expr::Neg(Box::new(Expr::Literal(2)))),
+    };
+
+    let mut json = String::new();
+    write_json(&mut json, &value.serialize(ValueSerializer)?)?;
+    assert_eq!(
+        json,
+        r#"{"Add":{"lhs":{"Literal":-1},"rhs":{"Neg":{"Literal":2}}}}"#
+    );
+
+    let parsed = parse_json(&json)?;
+    let actual = Expr::deserialize(JsonValueDeserializer(&parsed))?;
+    assert_eq!(actual, value);
+
+    let value = ("a\"\n\u{1F600}", [1.5, -0.0], Option::<u8>::None).serialize(ValueSerializer)?;
+    json.clear();
+    write_json(&mut json, &value)?;
+    assert_eq!(json, "[\"a\\\"\\n\u{1F600}\",[1.5,-0.0],null]");
+
+    Ok(())
+}
+
+#[test]
+fn json_recursion_limit() -> PanicOnError<()> {
+    let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+
+    let parsed = parse_json(&nested(128))?;
+    let mut json = String::new();
+    write_json(&mut json, &parsed_to_value(&parsed)?)?;
+    assert_eq!(json, nested(128));
+
+    let res = parse_json(&nested(129));
+    assert_error_contains(&res, "recursion limit of 128 exceeded");
+
+    let mut value = Expr::Literal(0);
+    for _ in 0..128 {
+        value = Expr::Neg(Box::new(value));
+    }
+    let res = write_json(&mut String::new(), &value.serialize(ValueSerializer)?);
+    assert_error_contains(&res, "recursion limit of 128 exceeded");
+
+    Ok(())
+}
+
+fn parsed_to_value(parsed: &JsonValue) -> PanicOnError<Value> {
+    Ok(
+        serde_json::Value::deserialize(JsonValueDeserializer(parsed))?
+            .serialize(ValueSerializer)?,
+    )
+}
@@ -1,6 +1,20 @@
 mod api_chrono;
+mod avro_schema;
+mod codegen;
+mod derive;
 mod deserialization;
 mod error_messages;
+mod field_metadata;
+mod field_names;
+mod incremental_schema_tracing;
 mod jiff;
+mod json_schema;
+mod max_depth;
+mod schema_diff;
+mod schema_docs;
+mod schema_dsl;
+mod schema_fingerprint;
 mod schema_like;
+mod schema_merge;
 mod schema_tracing;
+mod type_hints;
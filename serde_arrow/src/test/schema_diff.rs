@@ -0,0 +1,186 @@
+use serde_json::json;
+
+use crate::internal::{
+    schema::{Compatibility, SchemaChange, SchemaChangeKind},
+    testing::schema,
+};
+
+#[test]
+fn identical_schemas() {
+    let a = schema(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "Struct", "children": [{"name": "c", "data_type": "Utf8"}]},
+    ]));
+    let diff = a.diff(&a);
+    assert!(diff.is_empty());
+    assert_eq!(diff.compatibility(), Compatibility::Full);
+}
+
+#[test]
+fn added_and_removed_fields() {
+    let old = schema(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "I32", "nullable": true},
+    ]));
+    let new = schema(json!([
+        {"name": "c", "data_type": "Bool", "nullable": true},
+        {"name": "d", "data_type": "Bool"},
+    ]));
+
+    let diff = old.diff(&new);
+    assert_eq!(
+        diff.changes,
+        vec![
+            SchemaChange {
+                path: String::from("$.a"),
+                kind: SchemaChangeKind::FieldRemoved { nullable: false },
+                compatibility: Compatibility::Backward,
+            },
+            SchemaChange {
+                path: String::from("$.b"),
+                kind: SchemaChangeKind::FieldRemoved { nullable: true },
+                compatibility: Compatibility::Full,
+            },
+            SchemaChange {
+                path: String::from("$.c"),
+                kind: SchemaChangeKind::FieldAdded { nullable: true },
+                compatibility: Compatibility::Full,
+            },
+            SchemaChange {
+                path: String::from("$.d"),
+                kind: SchemaChangeKind::FieldAdded { nullable: false },
+                compatibility: Compatibility::Forward,
+            },
+        ]
+    );
+    assert_eq!(diff.compatibility(), Compatibility::Breaking);
+}
+
+#[test]
+fn widening_and_narrowing_data_types() {
+    let old = schema(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "LargeUtf8"},
+        {"name": "c", "data_type": "Bool"},
+    ]));
+    let new = schema(json!([
+        {"name": "a", "data_type": "I64"},
+        {"name": "b", "data_type": "Utf8"},
+        {"name": "c", "data_type": "F32"},
+    ]));
+
+    let diff = old.diff(&new);
+    let actual = diff
+        .changes
+        .iter()
+        .map(|change| (change.path.as_str(), change.compatibility))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        actual,
+        vec![
+            ("$.a", Compatibility::Backward),
+            ("$.b", Compatibility::Forward),
+            ("$.c", Compatibility::Breaking),
+        ]
+    );
+    assert_eq!(
+        diff.changes[0].kind,
+        SchemaChangeKind::DataTypeChanged {
+            old: String::from("Int32"),
+            new: String::from("Int64"),
+        }
+    );
+    assert_eq!(diff.breaking_changes().count(), 1);
+}
+
+#[test]
+fn nested_changes_include_the_path() {
+    let old = schema(json!([{
+        "name": "a",
+        "data_type": "List",
+        "children": [{
+            "name": "element",
+            "data_type": "Struct",
+            "children": [{"name": "b", "data_type": "U8"}],
+        }],
+    }]));
+    let new = schema(json!([{
+        "name": "a",
+        "data_type": "LargeList",
+        "children": [{
+            "name": "element",
+            "data_type": "Struct",
+            "children": [{"name": "b", "data_type": "U8", "nullable": true}],
+        }],
+    }]));
+
+    let diff = old.diff(&new);
+    assert_eq!(
+        diff.changes,
+        vec![
+            SchemaChange {
+                path: String::from("$.a"),
+                kind: SchemaChangeKind::DataTypeChanged {
+                    old: String::from("List"),
+                    new: String::from("LargeList"),
+                },
+                compatibility: Compatibility::Backward,
+            },
+            SchemaChange {
+                path: String::from("$.a.element.b"),
+                kind: SchemaChangeKind::NullabilityChanged {
+                    old: false,
+                    new: true,
+                },
+                compatibility: Compatibility::Backward,
+            },
+        ]
+    );
+    assert_eq!(diff.compatibility(), Compatibility::Backward);
+}
+
+#[test]
+fn metadata_and_strategy_changes() {
+    let old = schema(json!([
+        {"name": "a", "data_type": "Date64", "strategy": "NaiveStrAsDate64"},
+        {"name": "b", "data_type": "I32", "metadata": {"comment": "foo"}},
+    ]));
+    let new = schema(json!([
+        {"name": "a", "data_type": "Date64", "strategy": "UtcStrAsDate64"},
+        {"name": "b", "data_type": "I32", "metadata": {"comment": "bar"}},
+    ]));
+
+    let diff = old.diff(&new);
+    assert_eq!(
+        diff.changes,
+        vec![
+            SchemaChange {
+                path: String::from("$.a"),
+                kind: SchemaChangeKind::StrategyChanged {
+                    old: Some(String::from("NaiveStrAsDate64")),
+                    new: Some(String::from("UtcStrAsDate64")),
+                },
+                compatibility: Compatibility::Breaking,
+            },
+            SchemaChange {
+                path: String::from("$.b"),
+                kind: SchemaChangeKind::MetadataChanged {
+                    key: String::from("comment"),
+                    old: Some(String::from("foo")),
+                    new: Some(String::from("bar")),
+                },
+                compatibility: Compatibility::Full,
+            },
+        ]
+    );
+}
+
+#[test]
+fn empty_names_in_paths() {
+    let old = schema(json!([{"name": "", "data_type": "I32"}]));
+    let new = schema(json!([{"name": "", "data_type": "I64"}]));
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.changes.len(), 1);
+    assert_eq!(diff.changes[0].path, "$.<empty>");
+}
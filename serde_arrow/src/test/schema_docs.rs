@@ -0,0 +1,104 @@
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SchemaLike, SerdeArrowSchema},
+};
+
+fn example_schema() -> PanicOnError<SerdeArrowSchema> {
+    Ok(SerdeArrowSchema::from_value(json!([
+        {
+            "name": "id",
+            "data_type": "U64",
+            "metadata": {"description": "Unique id", "owner": "core", "pii": "false"},
+        },
+        {"name": "type", "data_type": "Dictionary", "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ]},
+        {"name": "location", "data_type": "Struct", "nullable": true, "children": [
+            {"name": "lat", "data_type": "F64", "metadata": {"description": "Latitude | degrees"}},
+            {"name": "lon", "data_type": "F64"},
+        ]},
+        {"name": "attributes", "data_type": "Map", "children": [
+            {"name": "entries", "data_type": "Struct", "children": [
+                {"name": "key", "data_type": "LargeUtf8"},
+                {"name": "value", "data_type": "Date64", "strategy": "NaiveStrAsDate64"},
+            ]},
+        ]},
+        {"name": "event", "data_type": "Union", "children": [
+            {"name": "", "data_type": "Null", "nullable": true},
+            {"name": "Click", "data_type": "LargeList", "children": [
+                {"name": "element", "data_type": "I32"},
+            ]},
+        ]},
+    ]))?)
+}
+
+#[test]
+fn markdown() -> PanicOnError<()> {
+    let actual = example_schema()?.to_markdown();
+    let expected = concat!(
+        "| Path | Type | Nullable | Strategy | Description | Metadata |\n",
+        "|---|---|---|---|---|---|\n",
+        "| `id` | `UInt64` | no |  | Unique id | `owner=core`, `pii=false` |\n",
+        "| `type` | `Dictionary(UInt32, LargeUtf8, false)` | no |  |  |  |\n",
+        "| `location` | `Struct` | yes |  |  |  |\n",
+        "| `location.lat` | `Float64` | no |  | Latitude \\| degrees |  |\n",
+        "| `location.lon` | `Float64` | no |  |  |  |\n",
+        "| `attributes` | `Map(false)` | no |  |  |  |\n",
+        "| `attributes.entries` | `Struct` | no |  |  |  |\n",
+        "| `attributes.entries.key` | `LargeUtf8` | no |  |  |  |\n",
+        "| `attributes.entries.value` | `Date64` | no | `NaiveStrAsDate64` |  |  |\n",
+        "| `event` | `Union(Dense)` | no |  |  |  |\n",
+        "| `event.<empty>` | `Null` | yes |  |  |  |\n",
+        "| `event.Click` | `LargeList` | no |  |  |  |\n",
+        "| `event.Click.element` | `Int32` | no |  |  |  |\n",
+    );
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn html() -> PanicOnError<()> {
+    let actual = example_schema()?.to_html();
+    let lines = actual.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 20);
+    assert_eq!(lines[0], "<table>");
+    assert_eq!(
+        lines[2],
+        "    <tr><th>Path</th><th>Type</th><th>Nullable</th><th>Strategy</th><th>Description</th><th>Metadata</th></tr>",
+    );
+    assert_eq!(
+        lines[5],
+        "    <tr><td><code>id</code></td><td><code>UInt64</code></td><td>no</td><td></td><td>Unique id</td><td><code>owner=core</code>, <code>pii=false</code></td></tr>",
+    );
+    assert_eq!(
+        lines[15],
+        "    <tr><td><code>event.&lt;empty&gt;</code></td><td><code>Null</code></td><td>yes</td><td></td><td></td><td></td></tr>",
+    );
+    assert_eq!(lines[19], "</table>");
+    Ok(())
+}
+
+#[test]
+fn from_type() -> PanicOnError<()> {
+    #[derive(serde::Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        name: String,
+        scores: Option<Vec<f32>>,
+    }
+
+    let schema = SerdeArrowSchema::from_type::<Record>(Default::default())?;
+    let expected = concat!(
+        "| Path | Type | Nullable | Strategy | Description | Metadata |\n",
+        "|---|---|---|---|---|---|\n",
+        "| `name` | `LargeUtf8` | no |  |  |  |\n",
+        "| `scores` | `LargeList` | yes |  |  |  |\n",
+        "| `scores.element` | `Float32` | no |  |  |  |\n",
+    );
+    assert_eq!(schema.to_markdown(), expected);
+    Ok(())
+}
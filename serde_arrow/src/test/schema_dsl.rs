@@ -0,0 +1,213 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    testing::assert_error_contains,
+};
+
+fn assert_roundtrip(schema: &SerdeArrowSchema) -> PanicOnError<()> {
+    let text = schema.to_string();
+    let parsed = text.parse::<SerdeArrowSchema>()?;
+    assert_eq!(&parsed, schema, "text form: {text}");
+    Ok(())
+}
+
+#[test]
+fn primitives() -> PanicOnError<()> {
+    let actual: SerdeArrowSchema = "
+        a: I32?,
+        b: Bool,
+        c: Null,
+        d: Timestamp(Millisecond, Some(\"UTC\")),
+        e: Decimal128(10, 2)?,
+        f: FixedSizeBinary(16),
+    "
+    .parse()?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "a", "data_type": "I32", "nullable": true},
+        {"name": "b", "data_type": "Bool"},
+        {"name": "c", "data_type": "Null", "nullable": true},
+        {"name": "d", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+        {"name": "e", "data_type": "Decimal128(10, 2)", "nullable": true},
+        {"name": "f", "data_type": "FixedSizeBinary(16)"},
+    ]))?;
+    assert_eq!(actual, expected);
+    assert_eq!(
+        actual.to_string(),
+        concat!(
+            "a: Int32?, b: Boolean, c: Null, d: Timestamp(Millisecond, Some(\"UTC\")), ",
+            "e: Decimal128(10, 2)?, f: FixedSizeBinary(16)",
+        ),
+    );
+    assert_roundtrip(&actual)
+}
+
+#[test]
+fn empty_schema() -> PanicOnError<()> {
+    let actual: SerdeArrowSchema = "".parse()?;
+    assert_eq!(actual, SerdeArrowSchema::default());
+    assert_eq!(actual.to_string(), "");
+    Ok(())
+}
+
+#[test]
+fn nested_types() -> PanicOnError<()> {
+    let actual: SerdeArrowSchema = "
+        list: List<item: I32?>?,
+        large_list: LargeList<Struct{}>,
+        fixed: FixedSizeList(3)<F32>,
+        sorted_map: Map(true)<LargeUtf8, List<U8>>,
+        dict: Dictionary<U32, Utf8>?,
+    "
+    .parse()?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "list", "data_type": "List", "nullable": true, "children": [
+            {"name": "item", "data_type": "I32", "nullable": true},
+        ]},
+        {"name": "large_list", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "Struct"},
+        ]},
+        {"name": "fixed", "data_type": "FixedSizeList(3)", "children": [
+            {"name": "element", "data_type": "F32"},
+        ]},
+        {"name": "sorted_map", "data_type": "Map", "children": [
+            {"name": "entries", "data_type": "Struct", "children": [
+                {"name": "key", "data_type": "LargeUtf8"},
+                {"name": "value", "data_type": "List", "children": [
+                    {"name": "element", "data_type": "U8"},
+                ]},
+            ]},
+        ]},
+        {"name": "dict", "data_type": "Dictionary", "nullable": true, "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "Utf8"},
+        ]},
+    ]))?;
+
+    // the JSON form does not support sorted maps
+    let mut expected = expected;
+    let crate::internal::arrow::DataType::Map(_, sorted) = &mut expected.fields[3].data_type else {
+        panic!();
+    };
+    *sorted = true;
+
+    assert_eq!(actual, expected);
+    assert_eq!(
+        actual.to_string(),
+        concat!(
+            "list: List<item: Int32?>?, large_list: LargeList<Struct{}>, ",
+            "fixed: FixedSizeList(3)<Float32>, sorted_map: Map(true)<LargeUtf8, List<UInt8>>, ",
+            "dict: Dictionary<UInt32, Utf8>?",
+        ),
+    );
+    assert_roundtrip(&actual)
+}
+
+#[test]
+fn names_and_metadata() -> PanicOnError<()> {
+    let actual: SerdeArrowSchema = r#"
+        "with space": Date64 [strategy: NaiveStrAsDate64, "key": "value \"quoted\""],
+        "": Utf8,
+        "\\": Struct{"0": U8, "1": Bool} [strategy: TupleAsStruct],
+    "#
+    .parse()?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {
+            "name": "with space",
+            "data_type": "Date64",
+            "strategy": "NaiveStrAsDate64",
+            "metadata": {"key": "value \"quoted\""},
+        },
+        {"name": "", "data_type": "Utf8"},
+        {"name": "\\", "data_type": "Struct", "strategy": "TupleAsStruct", "children": [
+            {"name": "0", "data_type": "U8"},
+            {"name": "1", "data_type": "Bool"},
+        ]},
+    ]))?;
+    assert_eq!(actual, expected);
+    assert_eq!(
+        actual.to_string(),
+        concat!(
+            r#""with space": Date64 [strategy: NaiveStrAsDate64, "key": "value \"quoted\""], "#,
+            r#""": Utf8, "\\": Struct{0: UInt8, 1: Boolean} [strategy: TupleAsStruct]"#,
+        ),
+    );
+    assert_roundtrip(&actual)
+}
+
+#[test]
+fn roundtrip_traced_schema() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Record {
+        a: Option<u8>,
+        b: Vec<(i32, String)>,
+        c: std::collections::BTreeMap<String, Option<f64>>,
+        d: Enum,
+        e: (),
+    }
+
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    enum Enum {
+        Unit,
+        Struct { value: bool },
+    }
+
+    let schema = SerdeArrowSchema::from_type::<Record>(
+        TracingOptions::default()
+            .allow_null_fields(true)
+            .map_as_struct(false)
+            .enums_without_data_as_strings(false),
+    )?;
+    assert_eq!(
+        schema.to_string(),
+        concat!(
+            "a: UInt8?, ",
+            "b: LargeList<Struct{0: Int32, 1: LargeUtf8} [strategy: TupleAsStruct]>, ",
+            "c: Map<LargeUtf8, Float64?>, ",
+            "d: Union{Unit: Null, Struct: Struct{value: Boolean}}, ",
+            "e: Null",
+        ),
+    );
+    assert_roundtrip(&schema)
+}
+
+#[test]
+fn deserialize_from_string() -> PanicOnError<()> {
+    let actual = SerdeArrowSchema::from_value("a: U8, b: List<Utf8?>")?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "a", "data_type": "U8"},
+        {"name": "b", "data_type": "List", "children": [
+            {"name": "element", "data_type": "Utf8", "nullable": true},
+        ]},
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let res = "a: I32, b".parse::<SerdeArrowSchema>();
+    assert_error_contains(&res, "Expected ':'");
+
+    let res = "a: NotAType".parse::<SerdeArrowSchema>();
+    assert_error_contains(&res, "invalid data type NotAType");
+
+    let res = "a: List<I32".parse::<SerdeArrowSchema>();
+    assert_error_contains(&res, "Expected '>'");
+
+    let res = "a: Struct{b: I32".parse::<SerdeArrowSchema>();
+    assert_error_contains(&res, "Expected '}'");
+
+    let res = "a: I32 b: I32".parse::<SerdeArrowSchema>();
+    assert_error_contains(&res, "Trailing content in schema");
+
+    let res = "a: Utf8 [strategy: NaiveStrAsDate64]".parse::<SerdeArrowSchema>();
+    assert_error_contains(&res, "invalid strategy for Utf8");
+
+    let res = "\"a: I32".parse::<SerdeArrowSchema>();
+    assert_error_contains(&res, "Missing end quote");
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use crate::internal::{error::PanicOnError, schema::SerdeArrowSchema, testing::schema};
+
+fn list_schema(item_name: &str) -> SerdeArrowSchema {
+    schema(json!([{
+        "name": "values",
+        "data_type": "LargeList",
+        "children": [{"name": item_name, "data_type": "I32", "nullable": true}],
+    }]))
+}
+
+fn map_schema(entries_name: &str, key_name: &str, value_name: &str) -> SerdeArrowSchema {
+    schema(json!([{
+        "name": "attributes",
+        "data_type": "Map",
+        "children": [{
+            "name": entries_name,
+            "data_type": "Struct",
+            "children": [
+                {"name": key_name, "data_type": "Utf8"},
+                {"name": value_name, "data_type": "F64", "nullable": true},
+            ],
+        }],
+    }]))
+}
+
+#[test]
+fn canonical_string() {
+    let actual = schema(json!([
+        {"name": "id", "data_type": "U64"},
+        {"name": "ts", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))", "nullable": true},
+        {"name": "point", "data_type": "Struct", "strategy": "TupleAsStruct", "children": [
+            {"name": "0", "data_type": "F32"},
+            {"name": "1", "data_type": "F32", "metadata": {"unit": "m"}},
+        ]},
+        {"name": "event", "data_type": "Union", "children": [
+            {"name": "A", "data_type": "Null", "nullable": true},
+            {"name": "B", "data_type": "Bool"},
+        ]},
+    ]))
+    .to_canonical_string();
+    let expected = concat!(
+        r#""id": UInt64, "#,
+        r#""ts": Timestamp(Millisecond, Some("UTC"))?, "#,
+        r#""point": Struct ["SERDE_ARROW:strategy": "TupleAsStruct"] <"0": Float32, "1": Float32 ["unit": "m"]>, "#,
+        r#""event": Union(Dense) <0="A": Null?, 1="B": Boolean>"#,
+    );
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn metadata_order_is_ignored() {
+    let entries = (0..20)
+        .map(|idx| (format!("key{idx}"), format!("value{idx}")))
+        .collect::<Vec<_>>();
+
+    let forward = entries.iter().cloned().collect::<HashMap<_, _>>();
+    let backward = entries.iter().rev().cloned().collect::<HashMap<_, _>>();
+
+    let schema_a = schema(json!([{"name": "a", "data_type": "U8", "metadata": forward}]))
+        .with_metadata(forward.clone());
+    let schema_b = schema(json!([{"name": "a", "data_type": "U8", "metadata": backward}]))
+        .with_metadata(backward.clone());
+
+    assert_eq!(
+        schema_a.to_canonical_string(),
+        schema_b.to_canonical_string()
+    );
+    assert_eq!(schema_a.fingerprint(), schema_b.fingerprint());
+}
+
+#[test]
+fn list_item_names_are_ignored() {
+    let schema_a = list_schema("item");
+    let schema_b = list_schema("element");
+
+    assert_ne!(schema_a, schema_b);
+    assert!(schema_a.is_equivalent(&schema_b));
+    assert_eq!(schema_a.fingerprint(), schema_b.fingerprint());
+}
+
+#[test]
+fn map_entry_names_are_ignored() {
+    let schema_a = map_schema("entries", "key", "value");
+    let schema_b = map_schema("key_value", "keys", "values");
+
+    assert_ne!(schema_a, schema_b);
+    assert!(schema_a.is_equivalent(&schema_b));
+    assert_eq!(schema_a.fingerprint(), schema_b.fingerprint());
+}
+
+#[test]
+fn differences_are_detected() {
+    let base = json!({"name": "a", "data_type": "I32"});
+    let variants = [
+        json!({"name": "b", "data_type": "I32"}),
+        json!({"name": "a", "data_type": "I64"}),
+        json!({"name": "a", "data_type": "I32", "nullable": true}),
+        json!({"name": "a", "data_type": "I32", "metadata": {"unit": "m"}}),
+        json!({"name": "a", "data_type": "Date64", "strategy": "NaiveStrAsDate64"}),
+    ];
+
+    let base = schema(json!([base]));
+    for variant in variants {
+        let variant = schema(json!([variant]));
+        assert!(!base.is_equivalent(&variant), "{variant}");
+        assert_ne!(base.fingerprint(), variant.fingerprint(), "{variant}");
+    }
+
+    let with_metadata = base
+        .clone()
+        .with_metadata(HashMap::from([(String::from("k"), String::from("v"))]));
+    assert!(!base.is_equivalent(&with_metadata));
+    assert!(!list_schema("item").is_equivalent(&map_schema("entries", "key", "value")));
+}
+
+#[test]
+fn union_type_ids_are_part_of_the_fingerprint() -> PanicOnError<()> {
+    let schema_a = schema(json!([{"name": "a", "data_type": "Union", "children": [
+        {"name": "A", "data_type": "U8"},
+        {"name": "B", "data_type": "U8"},
+    ]}]));
+    let mut schema_b = schema_a.clone();
+    let crate::internal::arrow::DataType::Union(variants, _) = &mut schema_b.fields[0].data_type
+    else {
+        unreachable!()
+    };
+    variants[1].0 = 5;
+
+    assert!(!schema_a.is_equivalent(&schema_b));
+    assert_ne!(schema_a.fingerprint(), schema_b.fingerprint());
+    Ok(())
+}
+
+#[test]
+fn fingerprint_is_stable() {
+    assert_eq!(
+        SerdeArrowSchema::default().fingerprint(),
+        0xcbf2_9ce4_8422_2325
+    );
+    assert_eq!(
+        schema(json!([{"name": "a", "data_type": "I32"}])).fingerprint(),
+        0x5aae_0e5b_2f4a_120c,
+    );
+}
+
+#[test]
+fn fingerprint_of_nested_schema_is_stable() {
+    let actual = schema(json!([
+        {"name": "id", "data_type": "U64", "metadata": {"note": "a \"quoted\"\tvalue"}},
+        {"name": "ts", "data_type": "Timestamp(Microsecond, Some(\"Europe/Berlin\"))", "nullable": true},
+        {"name": "t", "data_type": "Time64(Nanosecond)"},
+        {"name": "d", "data_type": "Duration(Second)"},
+        {"name": "price", "data_type": "Decimal128(10, -2)"},
+        {"name": "hash", "data_type": "FixedSizeBinary(16)"},
+        {"name": "label", "data_type": "Dictionary", "children": [
+            {"name": "key", "data_type": "U16"},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ]},
+        {"name": "coords", "data_type": "FixedSizeList(3)", "children": [
+            {"name": "item", "data_type": "F64"},
+        ]},
+        {"name": "attributes", "data_type": "Map", "children": [
+            {"name": "entries", "data_type": "Struct", "children": [
+                {"name": "key", "data_type": "Utf8"},
+                {"name": "value", "data_type": "Date32", "nullable": true},
+            ]},
+        ]},
+        {"name": "event", "data_type": "Union", "children": [
+            {"name": "A", "data_type": "Null", "nullable": true},
+            {"name": "B", "data_type": "Struct", "children": [
+                {"name": "x", "data_type": "I8"},
+            ]},
+        ]},
+    ]))
+    .with_metadata(HashMap::from([(String::from("source"), String::from("test"))]));
+
+    assert_eq!(actual.fingerprint(), 0xd61f_0763_8265_1cea);
+}
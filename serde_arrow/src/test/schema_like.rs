@@ -7,7 +7,7 @@ use crate::internal::{
 
 #[test]
 fn extra_attributes_trailing() {
-    let schema = SerdeArrowSchema::from_value(&json!({
+    let schema = SerdeArrowSchema::from_value(json!({
         "fields": [
             {"name": "foo", "data_type": "F32"},
         ],
@@ -27,7 +27,7 @@ fn extra_attributes_trailing() {
 
 #[test]
 fn extra_attributes_leading() {
-    let schema = SerdeArrowSchema::from_value(&json!({
+    let schema = SerdeArrowSchema::from_value(json!({
         "leading": null,
         "fields": [
             {"name": "foo", "data_type": "F32"},
@@ -47,7 +47,7 @@ fn extra_attributes_leading() {
 
 #[test]
 fn invalid_top_level() {
-    let err = SerdeArrowSchema::from_value(&json!(true)).expect_err("Expected error");
+    let err = SerdeArrowSchema::from_value(json!(true)).expect_err("Expected error");
     let err = err.to_string();
 
     println!("Actual error: {err}");
@@ -56,7 +56,7 @@ fn invalid_top_level() {
 
 #[test]
 fn list_missing_dat_tpye() {
-    let err = SerdeArrowSchema::from_value(&json!([
+    let err = SerdeArrowSchema::from_value(json!([
         {"name": "foo"},
     ]))
     .expect_err("Expected error");
@@ -68,7 +68,7 @@ fn list_missing_dat_tpye() {
 
 #[test]
 fn struct_missing_fields() {
-    let err = SerdeArrowSchema::from_value(&json!({})).expect_err("Expected error");
+    let err = SerdeArrowSchema::from_value(json!({})).expect_err("Expected error");
     let err = err.to_string();
 
     println!("Actual error: {err}");
@@ -77,7 +77,7 @@ fn struct_missing_fields() {
 
 #[test]
 fn struct_missing_data_type() {
-    let err = SerdeArrowSchema::from_value(&json!({
+    let err = SerdeArrowSchema::from_value(json!({
         "fields": [{"name": "foo"}]
     }))
     .expect_err("Expected error");
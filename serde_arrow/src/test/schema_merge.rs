@@ -0,0 +1,189 @@
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SerdeArrowSchema, TracingOptions},
+    testing::{assert_error_contains, schema},
+};
+
+#[test]
+fn missing_fields_are_marked_nullable() -> PanicOnError<()> {
+    let a = schema(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "Bool"},
+    ]));
+    let b = schema(json!([
+        {"name": "c", "data_type": "Utf8"},
+        {"name": "a", "data_type": "I32"},
+    ]));
+
+    let actual = SerdeArrowSchema::merge(&a, &b, TracingOptions::default())?;
+    let expected = schema(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "Bool", "nullable": true},
+        {"name": "c", "data_type": "Utf8", "nullable": true},
+    ]));
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn numbers_are_widened() -> PanicOnError<()> {
+    let a = schema(json!([
+        {"name": "a", "data_type": "U8"},
+        {"name": "b", "data_type": "I16"},
+        {"name": "c", "data_type": "F32"},
+    ]));
+    let b = schema(json!([
+        {"name": "a", "data_type": "U16", "nullable": true},
+        {"name": "b", "data_type": "U32"},
+        {"name": "c", "data_type": "I64"},
+    ]));
+
+    let options = TracingOptions::default().coerce_numbers(true);
+    let actual = SerdeArrowSchema::merge(&a, &b, options)?;
+    let expected = schema(json!([
+        {"name": "a", "data_type": "U64", "nullable": true},
+        {"name": "b", "data_type": "I64"},
+        {"name": "c", "data_type": "F64"},
+    ]));
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn null_fields_take_the_other_type() -> PanicOnError<()> {
+    let a = schema(json!([{"name": "a", "data_type": "Null", "nullable": true}]));
+    let b = schema(json!([{
+        "name": "a",
+        "data_type": "Struct",
+        "children": [{"name": "x", "data_type": "F32"}],
+    }]));
+
+    let actual = SerdeArrowSchema::merge(&a, &b, TracingOptions::default())?;
+    let expected = schema(json!([{
+        "name": "a",
+        "data_type": "Struct",
+        "nullable": true,
+        "children": [{"name": "x", "data_type": "F32"}],
+    }]));
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn strings_and_lists_are_unified() -> PanicOnError<()> {
+    let a = schema(json!([
+        {"name": "s", "data_type": "Utf8"},
+        {
+            "name": "l",
+            "data_type": "List",
+            "children": [{"name": "element", "data_type": "Utf8"}],
+        },
+    ]));
+    let b = schema(json!([
+        {"name": "s", "data_type": "LargeUtf8"},
+        {
+            "name": "l",
+            "data_type": "LargeList",
+            "children": [{"name": "element", "data_type": "Utf8", "nullable": true}],
+        },
+    ]));
+
+    let actual = SerdeArrowSchema::merge(&a, &b, TracingOptions::default())?;
+    let expected = schema(json!([
+        {"name": "s", "data_type": "LargeUtf8"},
+        {
+            "name": "l",
+            "data_type": "LargeList",
+            "children": [{"name": "element", "data_type": "Utf8", "nullable": true}],
+        },
+    ]));
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn nested_structs_are_merged() -> PanicOnError<()> {
+    let a = schema(json!([{
+        "name": "a",
+        "data_type": "Struct",
+        "children": [
+            {"name": "x", "data_type": "U8"},
+            {"name": "y", "data_type": "Utf8"},
+        ],
+    }]));
+    let b = schema(json!([{
+        "name": "a",
+        "data_type": "Struct",
+        "children": [
+            {"name": "x", "data_type": "U8"},
+            {"name": "z", "data_type": "Bool"},
+        ],
+    }]));
+
+    let actual = SerdeArrowSchema::merge(&a, &b, TracingOptions::default())?;
+    let expected = schema(json!([{
+        "name": "a",
+        "data_type": "Struct",
+        "children": [
+            {"name": "x", "data_type": "U8"},
+            {"name": "y", "data_type": "Utf8", "nullable": true},
+            {"name": "z", "data_type": "Bool", "nullable": true},
+        ],
+    }]));
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn conflicts_report_the_field_path() {
+    let a = schema(json!([{
+        "name": "a",
+        "data_type": "Struct",
+        "children": [{"name": "b", "data_type": "Utf8"}],
+    }]));
+    let b = schema(json!([{
+        "name": "a",
+        "data_type": "Struct",
+        "children": [{"name": "b", "data_type": "Bool"}],
+    }]));
+
+    let res = SerdeArrowSchema::merge(&a, &b, TracingOptions::default());
+    assert_error_contains(
+        &res,
+        "Cannot merge incompatible data types Utf8 and Boolean",
+    );
+    assert_error_contains(&res, "field: \"$.a.b\"");
+}
+
+#[test]
+fn numeric_conflicts_without_coercion() {
+    let a = schema(json!([{"name": "a", "data_type": "U8"}]));
+    let b = schema(json!([{"name": "a", "data_type": "I8"}]));
+
+    let res = SerdeArrowSchema::merge(&a, &b, TracingOptions::default());
+    assert_error_contains(&res, "consider setting `coerce_numbers` to `true`");
+    assert_error_contains(&res, "field: \"$.a\"");
+}
+
+#[test]
+fn guessed_uuids_are_only_coerced_to_strings_with_guess_uuids() -> PanicOnError<()> {
+    let a = schema(json!([{
+        "name": "a",
+        "data_type": "FixedSizeBinary(16)",
+        "strategy": "UuidStrAsFixedSizeBinary",
+    }]));
+    let b = schema(json!([{"name": "a", "data_type": "LargeUtf8"}]));
+
+    let res = SerdeArrowSchema::merge(&a, &b, TracingOptions::default().guess_numbers(true));
+    assert_error_contains(&res, "Cannot merge incompatible data types");
+
+    let options = TracingOptions::default().guess_uuids(true);
+    let actual = SerdeArrowSchema::merge(&a, &b, options)?;
+    assert_eq!(
+        actual,
+        schema(json!([{"name": "a", "data_type": "LargeUtf8"}]))
+    );
+    Ok(())
+}
@@ -2,7 +2,7 @@ use serde_json::json;
 
 use crate::internal::{
     error::PanicOnError,
-    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    schema::{SchemaLike, SchemaTracer, SerdeArrowSchema, TracingOptions},
 };
 
 /// A mixture of nulls and strings is parsed as a nullable LargeUtf8
@@ -443,3 +443,436 @@ mod untagged_enum_number_coercion {
         [(Num::U8(0),), (Num::U16(0),), (Num::Null(()),)]
     );
 }
+
+/// With `narrow_integers` the narrowest integer type with the traced signedness is used
+mod json_narrow_integers {
+    use super::*;
+
+    macro_rules! test {
+        ($name:ident, $options:expr, $data_type:expr, $($data:tt)*) => {
+            #[test]
+            fn $name() -> PanicOnError<()> {
+                let expected = SerdeArrowSchema::from_value(&json!([
+                    {"name": "value", "data_type": $data_type, "nullable": true},
+                ]))?;
+
+                let data = json!($($data)*);
+                let actual = SerdeArrowSchema::from_samples(&data, $options)?;
+                assert_eq!(actual, expected);
+                Ok(())
+            }
+        };
+    }
+
+    fn options() -> TracingOptions {
+        TracingOptions::default()
+            .narrow_integers(true)
+            .coerce_numbers(true)
+    }
+
+    test!(u8, options(), "U8", [{"value": 0}, {"value": null}, {"value": 255}]);
+    test!(u16, options(), "U16", [{"value": 256}, {"value": null}, {"value": 0}]);
+    test!(u32, options(), "U32", [{"value": 65536}, {"value": null}]);
+    test!(u64, options(), "U64", [{"value": 4294967296_u64}, {"value": null}]);
+    test!(i8, options(), "I8", [{"value": -128}, {"value": null}, {"value": 127}]);
+    test!(i16, options(), "I16", [{"value": -129}, {"value": null}, {"value": 13}]);
+    test!(i32, options(), "I32", [{"value": null}, {"value": -32769}]);
+    test!(i64, options(), "I64", [{"value": null}, {"value": -2147483649_i64}]);
+    test!(floats_unaffected, options(), "F64", [{"value": 1}, {"value": null}, {"value": 0.5}]);
+    test!(
+        margin_u8,
+        options().integer_safety_margin(0.5),
+        "U8",
+        [{"value": 100}, {"value": null}]
+    );
+    test!(
+        margin_u16,
+        options().integer_safety_margin(0.5),
+        "U16",
+        [{"value": 200}, {"value": null}]
+    );
+    test!(
+        margin_i16,
+        options().integer_safety_margin(0.5),
+        "I16",
+        [{"value": -100}, {"value": null}, {"value": 10}]
+    );
+    test!(
+        disabled,
+        TracingOptions::default().coerce_numbers(true),
+        "I64",
+        [{"value": -1}, {"value": null}, {"value": 1}]
+    );
+}
+
+/// With `dictionary_encoding_threshold` low cardinality strings are dictionary encoded
+mod json_dictionary_encoding_threshold {
+    use super::*;
+
+    fn trace(threshold: f64, data: serde_json::Value) -> PanicOnError<SerdeArrowSchema> {
+        let options = TracingOptions::default().dictionary_encoding_threshold(Some(threshold));
+        Ok(SerdeArrowSchema::from_samples(&data, options)?)
+    }
+
+    #[test]
+    fn low_cardinality() -> PanicOnError<()> {
+        let actual = trace(
+            0.5,
+            json!([{"s": "a"}, {"s": "b"}, {"s": null}, {"s": "a"}, {"s": "a"}, {"s": "b"}]),
+        )?;
+        let expected = SerdeArrowSchema::from_value(json!([{
+            "name": "s",
+            "data_type": "Dictionary",
+            "nullable": true,
+            "children": [
+                {"name": "key", "data_type": "U8"},
+                {"name": "value", "data_type": "LargeUtf8"},
+            ],
+        }]))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn high_cardinality() -> PanicOnError<()> {
+        let actual = trace(0.5, json!([{"s": "a"}, {"s": "b"}, {"s": "c"}, {"s": "a"}]))?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "s", "data_type": "LargeUtf8"},
+        ]))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn key_type_fits_the_cardinality() -> PanicOnError<()> {
+        let data = (0..1000)
+            .map(|idx| json!({"s": (idx % 300).to_string()}))
+            .collect::<Vec<_>>();
+        let actual = trace(0.5, serde_json::Value::Array(data))?;
+        let expected = SerdeArrowSchema::from_value(json!([{
+            "name": "s",
+            "data_type": "Dictionary",
+            "children": [
+                {"name": "key", "data_type": "U16"},
+                {"name": "value", "data_type": "LargeUtf8"},
+            ],
+        }]))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    /// The `string_values` entries of the serialized tracer state
+    fn string_values(
+        samples: serde_json::Value,
+        options: TracingOptions,
+    ) -> PanicOnError<Vec<serde_json::Value>> {
+        fn collect(value: &serde_json::Value, target: &mut Vec<serde_json::Value>) {
+            match value {
+                serde_json::Value::Object(entries) => {
+                    for (key, value) in entries {
+                        if key == "string_values" {
+                            target.push(value.clone());
+                        } else {
+                            collect(value, target);
+                        }
+                    }
+                }
+                serde_json::Value::Array(values) => {
+                    for value in values {
+                        collect(value, target);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut tracer = SchemaTracer::new(options);
+        tracer.trace_samples(samples)?;
+
+        let mut target = Vec::new();
+        collect(&serde_json::to_value(&tracer)?, &mut target);
+        Ok(target)
+    }
+
+    #[test]
+    fn high_cardinality_values_are_dropped() -> PanicOnError<()> {
+        let data = (0..2000)
+            .map(|idx| json!({"s": idx.to_string()}))
+            .collect::<Vec<_>>();
+        let data = serde_json::Value::Array(data);
+
+        let actual = trace(0.5, data.clone())?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "s", "data_type": "LargeUtf8"},
+        ]))?;
+        assert_eq!(actual, expected);
+
+        let options = TracingOptions::default()
+            .dictionary_encoding_threshold(Some(0.5))
+            .dictionary_encoding_max_values(1000);
+        let actual = string_values(data, options)?;
+        assert_eq!(
+            actual,
+            vec![json!({"count": 1001, "distinct": [], "exceeded": true})]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ratio_is_computed_over_all_samples() -> PanicOnError<()> {
+        let data = (0..2000)
+            .map(|idx| json!({"s": idx.to_string()}))
+            .chain((0..8000).map(|_| json!({"s": "a"})))
+            .collect::<Vec<_>>();
+        let data = serde_json::Value::Array(data);
+
+        let actual = trace(0.5, data.clone())?;
+        let expected = SerdeArrowSchema::from_value(json!([{
+            "name": "s",
+            "data_type": "Dictionary",
+            "children": [
+                {"name": "key", "data_type": "U16"},
+                {"name": "value", "data_type": "LargeUtf8"},
+            ],
+        }]))?;
+        assert_eq!(actual, expected);
+
+        let options = TracingOptions::default()
+            .dictionary_encoding_threshold(Some(0.5))
+            .dictionary_encoding_max_values(1000);
+        let actual = SerdeArrowSchema::from_samples(&data, options)?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "s", "data_type": "LargeUtf8"},
+        ]))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn guessed_dates_are_not_tracked() -> PanicOnError<()> {
+        let options = TracingOptions::default()
+            .dictionary_encoding_threshold(Some(0.5))
+            .guess_dates(true);
+        let actual = string_values(
+            json!([{"s": "2024-01-01T00:00:00"}, {"s": "2024-01-01T00:00:00"}]),
+            options,
+        )?;
+        assert_eq!(actual, vec![json!(null)]);
+        Ok(())
+    }
+}
+
+/// Numbers and decimals encoded as strings are detected with `guess_numbers` / `guess_decimals`
+mod json_guess_numbers {
+    use super::*;
+
+    macro_rules! test {
+        ($name:ident, $options:expr, $expected:tt, $($data:tt)*) => {
+            #[test]
+            fn $name() -> PanicOnError<()> {
+                let mut expected = json!({"name": "value", "nullable": true});
+                for (key, value) in json!($expected).as_object().unwrap() {
+                    expected[key] = value.clone();
+                }
+                let expected = SerdeArrowSchema::from_value(json!([expected]))?;
+
+                let data = json!($($data)*);
+                let actual = SerdeArrowSchema::from_samples(&data, $options)?;
+                assert_eq!(actual, expected);
+                Ok(())
+            }
+        };
+    }
+
+    fn numbers() -> TracingOptions {
+        TracingOptions::default().guess_numbers(true)
+    }
+
+    fn decimals() -> TracingOptions {
+        TracingOptions::default().guess_decimals(true)
+    }
+
+    test!(
+        integers,
+        numbers(),
+        {"data_type": "I64", "strategy": "StrAsNumber"},
+        [{"value": "3"}, {"value": null}, {"value": "-42"}]
+    );
+    test!(
+        integers_with_leading_zeros,
+        numbers(),
+        {"data_type": "I64", "strategy": "StrAsNumber"},
+        [{"value": "0003"}, {"value": null}, {"value": "-42"}]
+    );
+    test!(
+        exact_integers_with_leading_zeros_are_strings,
+        numbers().exact_number_strings(true),
+        {"data_type": "LargeUtf8"},
+        [{"value": "0003"}, {"value": null}, {"value": "-42"}]
+    );
+    test!(
+        floats,
+        numbers(),
+        {"data_type": "F64", "strategy": "StrAsNumber"},
+        [{"value": "12.5"}, {"value": null}, {"value": "0.001"}]
+    );
+    test!(
+        floats_not_formatted_back,
+        numbers(),
+        {"data_type": "F64", "strategy": "StrAsNumber"},
+        [{"value": "1.0"}, {"value": "12.50"}, {"value": null}, {"value": "1e-3"}]
+    );
+    test!(
+        exact_floats_not_formatted_back_are_strings,
+        numbers().exact_number_strings(true),
+        {"data_type": "LargeUtf8"},
+        [{"value": "12.50"}, {"value": null}, {"value": "1e-3"}]
+    );
+    test!(
+        integers_and_floats,
+        numbers(),
+        {"data_type": "F64", "strategy": "StrAsNumber"},
+        [{"value": "12"}, {"value": null}, {"value": "0.5"}]
+    );
+    test!(
+        numbers_and_strings,
+        numbers(),
+        {"data_type": "LargeUtf8"},
+        [{"value": "12"}, {"value": null}, {"value": "foo"}]
+    );
+    test!(
+        special_floats_are_strings,
+        numbers(),
+        {"data_type": "LargeUtf8"},
+        [{"value": "inf"}, {"value": null}, {"value": "NaN"}]
+    );
+    test!(
+        decimals_widest_value,
+        decimals(),
+        {"data_type": "Decimal128(5, 2)"},
+        [{"value": "12.50"}, {"value": null}, {"value": "-100.5"}]
+    );
+    test!(
+        decimals_integers,
+        decimals(),
+        {"data_type": "Decimal128(3, 0)"},
+        [{"value": "3"}, {"value": null}, {"value": "123"}]
+    );
+    test!(
+        decimals_with_leading_zeros,
+        decimals(),
+        {"data_type": "Decimal128(3, 0)"},
+        [{"value": "0003"}, {"value": null}, {"value": "123"}]
+    );
+    test!(
+        exact_decimals_with_leading_zeros_are_strings,
+        decimals().exact_number_strings(true),
+        {"data_type": "LargeUtf8"},
+        [{"value": "0003"}, {"value": null}, {"value": "123"}]
+    );
+    test!(
+        decimals_and_strings,
+        decimals(),
+        {"data_type": "LargeUtf8"},
+        [{"value": "12.50"}, {"value": null}, {"value": "1e-3"}]
+    );
+    test!(
+        decimals_and_floats,
+        decimals().guess_numbers(true),
+        {"data_type": "F64", "strategy": "StrAsNumber"},
+        [{"value": "12.50"}, {"value": null}, {"value": "1000000000000000000000000000000000000000"}]
+    );
+    test!(
+        decimals_exceeding_precision,
+        decimals(),
+        {"data_type": "LargeUtf8"},
+        [{"value": "0.00000000000000000000000000000000001"}, {"value": null}, {"value": "1000000"}]
+    );
+    test!(
+        disabled,
+        TracingOptions::default(),
+        {"data_type": "LargeUtf8"},
+        [{"value": "12.50"}, {"value": null}]
+    );
+}
+
+mod json_guess_str_formats {
+    use super::*;
+
+    macro_rules! test {
+        ($name:ident, $options:expr, $expected:tt, $($data:tt)*) => {
+            #[test]
+            fn $name() -> PanicOnError<()> {
+                let mut expected = json!({"name": "value", "nullable": true});
+                for (key, value) in json!($expected).as_object().unwrap() {
+                    expected[key] = value.clone();
+                }
+                let expected = SerdeArrowSchema::from_value(json!([expected]))?;
+
+                let data = json!($($data)*);
+                let actual = SerdeArrowSchema::from_samples(&data, $options)?;
+                assert_eq!(actual, expected);
+                Ok(())
+            }
+        };
+    }
+
+    fn uuids() -> TracingOptions {
+        TracingOptions::default().guess_uuids(true)
+    }
+
+    fn ip_addresses() -> TracingOptions {
+        TracingOptions::default().guess_ip_addresses(true)
+    }
+
+    test!(
+        uuid_strings,
+        uuids(),
+        {
+            "data_type": "FixedSizeBinary(16)",
+            "strategy": "UuidStrAsFixedSizeBinary",
+            "metadata": {"ARROW:extension:name": "arrow.uuid"},
+        },
+        [
+            {"value": "67e55044-10b1-426f-9247-bb680e5fe0c8"},
+            {"value": null},
+            {"value": "A1A2A3A4-B1B2-C1C2-D1D2-D3D4D5D6D7D8"},
+        ]
+    );
+    test!(
+        uuids_and_strings,
+        uuids(),
+        {"data_type": "LargeUtf8"},
+        [{"value": "67e55044-10b1-426f-9247-bb680e5fe0c8"}, {"value": null}, {"value": "foo"}]
+    );
+    test!(
+        ip_address_strings,
+        ip_addresses(),
+        {"data_type": "FixedSizeBinary(16)", "strategy": "IpStrAsFixedSizeBinary"},
+        [{"value": "192.168.0.1"}, {"value": null}, {"value": "2001:db8::1"}]
+    );
+    test!(
+        ip_addresses_and_strings,
+        ip_addresses(),
+        {"data_type": "LargeUtf8"},
+        [{"value": "192.168.0.1"}, {"value": null}, {"value": "192.168.0"}]
+    );
+    test!(
+        ip_addresses_and_numbers,
+        ip_addresses().guess_numbers(true),
+        {"data_type": "LargeUtf8"},
+        [{"value": "10.0.0.1"}, {"value": null}, {"value": "10"}]
+    );
+    test!(
+        uuids_and_ip_addresses,
+        uuids().guess_ip_addresses(true),
+        {"data_type": "LargeUtf8"},
+        [{"value": "67e55044-10b1-426f-9247-bb680e5fe0c8"}, {"value": null}, {"value": "::1"}]
+    );
+    test!(
+        disabled,
+        TracingOptions::default(),
+        {"data_type": "LargeUtf8"},
+        [{"value": "67e55044-10b1-426f-9247-bb680e5fe0c8"}, {"value": null}, {"value": "::1"}]
+    );
+}
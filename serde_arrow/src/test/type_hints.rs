@@ -0,0 +1,297 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    testing::assert_error_contains,
+};
+
+#[test]
+fn chrono_path_type_hints() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Example {
+        timestamp: DateTime<Utc>,
+        date_time: Option<NaiveDateTime>,
+        date: NaiveDate,
+        time: NaiveTime,
+    }
+
+    let options = TracingOptions::default()
+        .path_type_hint(
+            "timestamp",
+            json!({"name": "timestamp", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"}),
+        )?
+        .path_type_hint(
+            "date_time",
+            json!({"name": "date_time", "data_type": "Date64", "strategy": "NaiveStrAsDate64"}),
+        )?
+        .path_type_hint("date", json!({"name": "date", "data_type": "Date32"}))?
+        .path_type_hint(
+            "time",
+            json!({"name": "time", "data_type": "Time64(Nanosecond)"}),
+        )?;
+
+    let actual = SerdeArrowSchema::from_type::<Example>(options)?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "timestamp", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+        {
+            "name": "date_time",
+            "data_type": "Date64",
+            "strategy": "NaiveStrAsDate64",
+            "nullable": true,
+        },
+        {"name": "date", "data_type": "Date32"},
+        {"name": "time", "data_type": "Time64(Nanosecond)"},
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn jiff_path_type_hints() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Example {
+        timestamp: jiff::Timestamp,
+        date_time: jiff::civil::DateTime,
+        date: jiff::civil::Date,
+        time: jiff::civil::Time,
+        span: jiff::Span,
+    }
+
+    let options = TracingOptions::default()
+        .path_type_hint(
+            "timestamp",
+            json!({"name": "timestamp", "data_type": "Timestamp(Microsecond, Some(\"UTC\"))"}),
+        )?
+        .path_type_hint(
+            "date_time",
+            json!({"name": "date_time", "data_type": "Timestamp(Microsecond, None)"}),
+        )?
+        .path_type_hint("date", json!({"name": "date", "data_type": "Date32"}))?
+        .path_type_hint(
+            "time",
+            json!({"name": "time", "data_type": "Time64(Microsecond)"}),
+        )?
+        .path_type_hint(
+            "span",
+            json!({"name": "span", "data_type": "Duration(Second)"}),
+        )?;
+
+    let actual = SerdeArrowSchema::from_type::<Example>(options)?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "timestamp", "data_type": "Timestamp(Microsecond, Some(\"UTC\"))"},
+        {"name": "date_time", "data_type": "Timestamp(Microsecond, None)"},
+        {"name": "date", "data_type": "Date32"},
+        {"name": "time", "data_type": "Time64(Microsecond)"},
+        {"name": "span", "data_type": "Duration(Second)"},
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn type_name_hints() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Example {
+        start: Date,
+        end: Option<Date>,
+        dates: Vec<Date>,
+    }
+
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Date(NaiveDate);
+
+    let options = TracingOptions::default()
+        .type_hint("Date", json!({"name": "date", "data_type": "Date32"}))?;
+
+    let actual = SerdeArrowSchema::from_type::<Example>(options)?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "start", "data_type": "Date32"},
+        {"name": "end", "data_type": "Date32", "nullable": true},
+        {
+            "name": "dates",
+            "data_type": "LargeList",
+            "children": [{"name": "element", "data_type": "Date32"}],
+        },
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn type_hints_for_structs() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Example {
+        event: Event,
+    }
+
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Event {
+        timestamp: DateTime<Utc>,
+        value: i64,
+    }
+
+    let options = TracingOptions::default().type_hint(
+        "Event",
+        json!({
+            "name": "event",
+            "data_type": "Struct",
+            "children": [
+                {"name": "timestamp", "data_type": "Timestamp(Second, Some(\"UTC\"))"},
+                {"name": "value", "data_type": "I32"},
+            ],
+        }),
+    )?;
+
+    let actual = SerdeArrowSchema::from_type::<Example>(options)?;
+    let expected = SerdeArrowSchema::from_value(json!([{
+        "name": "event",
+        "data_type": "Struct",
+        "children": [
+            {"name": "timestamp", "data_type": "Timestamp(Second, Some(\"UTC\"))"},
+            {"name": "value", "data_type": "I32"},
+        ],
+    }]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn missing_type_hints_mention_hints_in_the_error() {
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Example {
+        timestamp: DateTime<Utc>,
+    }
+
+    let res = SerdeArrowSchema::from_type::<Example>(TracingOptions::default());
+    assert_error_contains(&res, "TracingOptions::path_type_hint");
+}
+
+#[test]
+fn flatten_with_root_type_hint() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Example {
+        a: i64,
+        #[serde(flatten)]
+        inner: Inner,
+    }
+
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Inner {
+        b: String,
+        c: Option<DateTime<Utc>>,
+    }
+
+    let schema = json!([
+        {"name": "a", "data_type": "I64"},
+        {"name": "b", "data_type": "LargeUtf8"},
+        {"name": "c", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))", "nullable": true},
+    ]);
+    let options = TracingOptions::default().path_type_hint(
+        "",
+        json!({"name": "$", "data_type": "Struct", "children": schema}),
+    )?;
+
+    let actual = SerdeArrowSchema::from_type::<Example>(options)?;
+    let expected = SerdeArrowSchema::from_value(schema)?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn flatten_with_path_type_hint() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Example {
+        id: u32,
+        nested: Nested,
+    }
+
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Nested {
+        a: i64,
+        #[serde(flatten)]
+        inner: Inner,
+    }
+
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Inner {
+        b: bool,
+    }
+
+    let nested = json!({
+        "name": "nested",
+        "data_type": "Struct",
+        "children": [
+            {"name": "a", "data_type": "I64"},
+            {"name": "b", "data_type": "Bool"},
+        ],
+    });
+    let options = TracingOptions::default().path_type_hint("nested", &nested)?;
+
+    let actual = SerdeArrowSchema::from_type::<Example>(options)?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "id", "data_type": "U32"},
+        nested,
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn untagged_enums_with_type_hints() -> PanicOnError<()> {
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    struct Example {
+        value: Value,
+        values: Vec<Value>,
+    }
+
+    #[allow(unused)]
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        Number(f64),
+        Text(String),
+    }
+
+    let value = json!({
+        "name": "value",
+        "data_type": "Union",
+        "children": [
+            {"name": "Number", "data_type": "F64"},
+            {"name": "Text", "data_type": "LargeUtf8"},
+        ],
+    });
+    let options = TracingOptions::default()
+        .path_type_hint("value", &value)?
+        .path_type_hint(
+            "values.element",
+            json!({"name": "element", "data_type": "LargeUtf8"}),
+        )?;
+
+    let actual = SerdeArrowSchema::from_type::<Example>(options)?;
+    let expected = SerdeArrowSchema::from_value(json!([
+        value,
+        {
+            "name": "values",
+            "data_type": "LargeList",
+            "children": [{"name": "element", "data_type": "LargeUtf8"}],
+        },
+    ]))?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
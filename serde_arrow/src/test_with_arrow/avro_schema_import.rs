@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::_impl::arrow::datatypes::FieldRef;
+use crate::internal::error::PanicOnError;
+use crate::schema::SerdeArrowSchema;
+
+#[test]
+fn serialize_records_with_imported_schema() -> PanicOnError<()> {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        id: i64,
+        timestamp: i64,
+        date: i32,
+        suit: Suit,
+        comment: Option<String>,
+        scores: Vec<f32>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[allow(clippy::upper_case_acronyms)]
+    enum Suit {
+        SPADES,
+        HEARTS,
+    }
+
+    let schema = SerdeArrowSchema::from_avro_schema(json!({
+        "type": "record",
+        "name": "Record",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "timestamp", "type": {"type": "long", "logicalType": "timestamp-millis"}},
+            {"name": "date", "type": {"type": "int", "logicalType": "date"}},
+            {"name": "suit", "type": {"type": "enum", "name": "Suit", "symbols": ["SPADES", "HEARTS"]}},
+            {"name": "comment", "type": ["null", "string"], "default": null},
+            {"name": "scores", "type": {"type": "array", "items": "float"}},
+        ],
+    }))?;
+
+    let items = vec![
+        Record {
+            id: 1,
+            timestamp: 1_700_000_000_000,
+            date: 19_000,
+            suit: Suit::SPADES,
+            comment: None,
+            scores: vec![1.0, 2.0],
+        },
+        Record {
+            id: 2,
+            timestamp: 1_700_000_001_000,
+            date: 19_001,
+            suit: Suit::HEARTS,
+            comment: Some(String::from("hello")),
+            scores: vec![],
+        },
+    ];
+
+    let fields = Vec::<FieldRef>::try_from(schema)?;
+    let batch = crate::to_record_batch(&fields, &items)?;
+    let actual: Vec<Record> = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+    Ok(())
+}
+
+#[test]
+fn serialize_none_into_nullable_union() -> PanicOnError<()> {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        value: Option<Value>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Value {
+        #[serde(rename = "long")]
+        Long(i64),
+        #[serde(rename = "string")]
+        String(String),
+    }
+
+    let schema = SerdeArrowSchema::from_avro_schema(json!({
+        "type": "record",
+        "name": "Record",
+        "fields": [
+            {"name": "value", "type": ["null", "long", "string"]},
+        ],
+    }))?;
+
+    let items = vec![
+        Record {
+            value: Some(Value::Long(13)),
+        },
+        Record { value: None },
+        Record {
+            value: Some(Value::String(String::from("hello"))),
+        },
+        Record { value: None },
+    ];
+
+    let fields = Vec::<FieldRef>::try_from(schema)?;
+    let batch = crate::to_record_batch(&fields, &items)?;
+    let actual: Vec<Record> = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+    Ok(())
+}
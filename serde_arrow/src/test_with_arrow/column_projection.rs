@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+
+use crate::_impl::arrow::datatypes::FieldRef;
+use crate::internal::testing::assert_error_contains;
+use crate::utils::Item;
+use crate::{
+    self as serde_arrow,
+    schema::{SchemaLike, TracingOptions},
+    Deserializer,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Wide {
+    a: i32,
+    b: String,
+    c: Vec<u8>,
+    d: Option<f64>,
+}
+
+fn example_items() -> Vec<Wide> {
+    (0..4)
+        .map(|idx| Wide {
+            a: idx,
+            b: format!("b{idx}"),
+            c: vec![idx as u8; idx as usize],
+            d: (idx % 2 == 0).then_some(idx as f64),
+        })
+        .collect()
+}
+
+fn example_batch() -> crate::_impl::arrow::array::RecordBatch {
+    let fields = Vec::<FieldRef>::from_type::<Wide>(TracingOptions::default()).unwrap();
+    serde_arrow::to_record_batch(&fields, &example_items()).unwrap()
+}
+
+#[test]
+fn narrow_struct() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Narrow {
+        d: Option<f64>,
+        a: i32,
+    }
+
+    let batch = example_batch();
+
+    let res = Vec::<Narrow>::deserialize(Deserializer::from_record_batch(&batch).unwrap());
+    assert_error_contains(&res, "unknown field");
+
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .project::<Narrow>(TracingOptions::default())
+        .unwrap();
+    assert_eq!(deserializer.fields.len(), 2);
+
+    let actual = Vec::<Narrow>::deserialize(deserializer).unwrap();
+    let expected = example_items()
+        .into_iter()
+        .map(|item| Narrow {
+            d: item.d,
+            a: item.a,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn missing_columns_are_ignored() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Record {
+        b: String,
+        missing: Option<u8>,
+        #[serde(default)]
+        also_missing: Vec<String>,
+    }
+
+    let batch = example_batch();
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .project::<Record>(TracingOptions::default())
+        .unwrap();
+
+    let actual: Record = deserializer.get(2).unwrap();
+    assert_eq!(
+        actual,
+        Record {
+            b: String::from("b2"),
+            missing: None,
+            also_missing: vec![],
+        }
+    );
+
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+    assert_eq!(actual.len(), 4);
+}
+
+#[test]
+fn projection_of_multiple_batches() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Record {
+        c: Vec<u8>,
+    }
+
+    let batch = example_batch();
+    let batches = [batch.clone(), batch.slice(1, 2)];
+
+    let deserializer = Deserializer::from_record_batches(&batches)
+        .unwrap()
+        .project::<Record>(TracingOptions::default())
+        .unwrap();
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+    let expected = [0, 1, 2, 3, 1, 2]
+        .into_iter()
+        .map(|idx| Record {
+            c: vec![idx as u8; idx],
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn projection_with_item_wrapper() {
+    let fields = Vec::<FieldRef>::from_type::<Item<u32>>(TracingOptions::default()).unwrap();
+    let arrays = serde_arrow::to_arrow(&fields, [Item(1_u32), Item(2)]).unwrap();
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .project::<Item<u32>>(TracingOptions::default())
+        .unwrap();
+    let actual = Vec::<Item<u32>>::deserialize(deserializer).unwrap();
+    assert_eq!(actual, vec![Item(1), Item(2)]);
+}
+
+#[test]
+fn projection_requires_struct_records() {
+    let batch = example_batch();
+    let res = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .project::<u32>(TracingOptions::default());
+    assert_error_contains(&res, "Cannot project columns for non-struct records");
+}
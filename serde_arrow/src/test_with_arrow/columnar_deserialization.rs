@@ -0,0 +1,222 @@
+use serde::{Deserialize, Serialize};
+
+use crate::_impl::arrow::{
+    array::{PrimitiveArray, RecordBatch},
+    datatypes::{FieldRef, Float32Type},
+};
+use crate::internal::testing::assert_error_contains;
+use crate::utils::Slice;
+use crate::{
+    self as serde_arrow,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    Deserializer,
+};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+struct Nested {
+    x: u8,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+struct Record {
+    a: f32,
+    b: i32,
+    c: Option<String>,
+    d: Vec<u16>,
+    e: Nested,
+    f: Option<i64>,
+}
+
+fn example_items() -> Vec<Record> {
+    (0..5)
+        .map(|idx| Record {
+            a: idx as f32 * 0.5,
+            b: -idx,
+            c: (idx % 2 == 0).then(|| idx.to_string()),
+            d: vec![idx as u16; idx as usize],
+            e: Nested { x: idx as u8 },
+            f: (idx != 3).then_some(idx as i64),
+        })
+        .collect()
+}
+
+fn example_batch() -> RecordBatch {
+    let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default()).unwrap();
+    serde_arrow::to_record_batch(&fields, &example_items()).unwrap()
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Columns {
+    a: Vec<f32>,
+    b: Vec<i32>,
+    c: Vec<Option<String>>,
+    d: Vec<Vec<u16>>,
+    e: Vec<Nested>,
+    f: Vec<Option<i64>>,
+}
+
+fn expected_columns(items: &[Record]) -> Columns {
+    Columns {
+        a: items.iter().map(|item| item.a).collect(),
+        b: items.iter().map(|item| item.b).collect(),
+        c: items.iter().map(|item| item.c.clone()).collect(),
+        d: items.iter().map(|item| item.d.clone()).collect(),
+        e: items.iter().map(|item| item.e.clone()).collect(),
+        f: items.iter().map(|item| item.f).collect(),
+    }
+}
+
+#[test]
+fn deserialize_columns() {
+    let batch = example_batch();
+    let actual: Columns = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .deserialize_columns()
+        .unwrap();
+    assert_eq!(actual, expected_columns(&example_items()));
+}
+
+#[test]
+fn deserialize_columns_across_batches() {
+    let batch = example_batch();
+    let batches = [batch.slice(1, 3), batch.slice(0, 0), batch.clone()];
+
+    let actual: Columns = Deserializer::from_record_batches(&batches)
+        .unwrap()
+        .deserialize_columns()
+        .unwrap();
+
+    let items = example_items();
+    let expected_items = items[1..4]
+        .iter()
+        .chain(items.iter())
+        .cloned()
+        .collect::<Vec<_>>();
+    assert_eq!(actual, expected_columns(&expected_items));
+}
+
+#[test]
+fn skipped_and_missing_columns() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Columns {
+        b: Vec<i32>,
+        #[serde(default)]
+        missing: Vec<u8>,
+    }
+
+    let batch = example_batch();
+    let actual: Columns = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .deserialize_columns()
+        .unwrap();
+    assert_eq!(
+        actual,
+        Columns {
+            b: vec![0, -1, -2, -3, -4],
+            missing: vec![],
+        }
+    );
+}
+
+#[test]
+fn missing_columns_filled_from_schema() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Columns {
+        b: Vec<i32>,
+        filled: Vec<u32>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[allow(dead_code)]
+    struct NewRecord {
+        b: i32,
+        filled: u32,
+    }
+
+    let schema = SerdeArrowSchema::from_type::<NewRecord>(TracingOptions::default()).unwrap();
+    let batch = example_batch();
+    let actual: Columns = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_schema(&schema)
+        .unwrap()
+        .deserialize_columns()
+        .unwrap();
+    assert_eq!(actual.filled, vec![0; 5]);
+}
+
+#[test]
+fn zero_copy_slices() {
+    #[derive(Debug, Deserialize)]
+    struct Columns<'a> {
+        #[serde(borrow)]
+        a: Slice<'a, f32>,
+        #[serde(borrow)]
+        b: Slice<'a, i32>,
+        #[serde(borrow)]
+        f: Option<Slice<'a, i64>>,
+    }
+
+    let batch = example_batch().slice(0, 3);
+    let actual: Columns = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .deserialize_columns()
+        .unwrap();
+
+    assert_eq!(&*actual.a, &[0.0, 0.5, 1.0]);
+    assert_eq!(&*actual.b, &[0, -1, -2]);
+    assert_eq!(actual.f.as_deref(), Some([0, 1, 2].as_slice()));
+
+    let array = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<PrimitiveArray<Float32Type>>()
+        .unwrap();
+    assert_eq!(actual.a.as_ptr(), array.values().as_ptr());
+}
+
+#[test]
+fn zero_copy_slice_errors() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct WrongType<'a> {
+        #[serde(borrow)]
+        b: Slice<'a, i64>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct WithNulls<'a> {
+        #[serde(borrow)]
+        f: Slice<'a, i64>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct NonPrimitive<'a> {
+        #[serde(borrow)]
+        c: Slice<'a, u8>,
+    }
+
+    let batch = example_batch();
+
+    let res = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .deserialize_columns::<WrongType>();
+    assert_error_contains(&res, "the column is of type serde_arrow::Slice<i32>");
+
+    let res = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .deserialize_columns::<WithNulls>();
+    assert_error_contains(&res, "the column contains nulls");
+
+    let res = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .deserialize_columns::<NonPrimitive>();
+    assert_error_contains(&res, "zero-copy slices require a primitive column");
+
+    let batches = [batch.clone(), batch];
+    let res = Deserializer::from_record_batches(&batches)
+        .unwrap()
+        .deserialize_columns::<WrongType>();
+    assert_error_contains(&res, "zero-copy slices require a single chunk");
+}
@@ -0,0 +1,57 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::_impl::arrow::datatypes::FieldRef;
+use crate::internal::error::PanicOnError;
+use crate::schema::ArrowSchema;
+
+#[test]
+fn roundtrip_with_derived_schema() -> PanicOnError<()> {
+    #[derive(Debug, PartialEq, Serialize, Deserialize, ArrowSchema)]
+    #[serde(rename_all = "camelCase")]
+    struct Record {
+        record_id: u64,
+        #[arrow(data_type = "Timestamp(Millisecond, Some(\"UTC\"))")]
+        #[serde(with = "chrono::serde::ts_milliseconds")]
+        created_at: DateTime<Utc>,
+        #[arrow(data_type = "Date64", strategy = "NaiveStrAsDate64")]
+        updated_at: Option<NaiveDateTime>,
+        tags: Vec<String>,
+        status: Status,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, ArrowSchema)]
+    enum Status {
+        Active(u8),
+        Archived { reason: String },
+    }
+
+    let items = vec![
+        Record {
+            record_id: 1,
+            created_at: DateTime::from_timestamp_millis(1_700_000_000_000).unwrap(),
+            updated_at: None,
+            tags: vec![String::from("a")],
+            status: Status::Active(3),
+        },
+        Record {
+            record_id: 2,
+            created_at: DateTime::from_timestamp_millis(1_700_000_001_000).unwrap(),
+            updated_at: Some(
+                DateTime::from_timestamp_millis(1_700_000_002_000)
+                    .unwrap()
+                    .naive_utc(),
+            ),
+            tags: vec![],
+            status: Status::Archived {
+                reason: String::from("done"),
+            },
+        },
+    ];
+
+    let fields = Vec::<FieldRef>::try_from(Record::arrow_schema()?)?;
+    let batch = crate::to_record_batch(&fields, &items)?;
+    let actual: Vec<Record> = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+    Ok(())
+}
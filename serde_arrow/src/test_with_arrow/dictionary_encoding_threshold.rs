@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::_impl::arrow::datatypes::{DataType, FieldRef};
+use crate::internal::error::PanicOnError;
+use crate::schema::{SchemaLike, TracingOptions};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    category: String,
+    name: String,
+}
+
+#[test]
+fn low_cardinality_fields_are_dictionary_encoded() -> PanicOnError<()> {
+    let items = (0..100)
+        .map(|idx| Record {
+            category: format!("category {}", idx % 3),
+            name: format!("name {idx}"),
+        })
+        .collect::<Vec<_>>();
+
+    let options = TracingOptions::default().dictionary_encoding_threshold(Some(0.1));
+    let fields = Vec::<FieldRef>::from_samples(&items, options)?;
+    assert_eq!(
+        fields[0].data_type(),
+        &DataType::Dictionary(Box::new(DataType::UInt8), Box::new(DataType::LargeUtf8)),
+    );
+    assert_eq!(fields[1].data_type(), &DataType::LargeUtf8);
+
+    let batch = crate::to_record_batch(&fields, &items)?;
+    let actual: Vec<Record> = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::_impl::arrow::datatypes::FieldRef;
+use crate::internal::error::PanicOnError;
+use crate::schema::{FieldNames, SchemaLike, TracingOptions};
+use crate::{ArrayBuilder, Deserializer};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    user_id: u64,
+    home_address: Option<Address>,
+    field_1: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Address {
+    street_name: String,
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            user_id: 1,
+            home_address: Some(Address {
+                street_name: String::from("Main Street"),
+            }),
+            field_1: true,
+        },
+        Record {
+            user_id: 2,
+            home_address: None,
+            field_1: false,
+        },
+    ]
+}
+
+#[test]
+fn struct_roundtrip() -> PanicOnError<()> {
+    for field_names in [
+        FieldNames::Unchanged,
+        FieldNames::CamelCase,
+        FieldNames::PascalCase,
+        FieldNames::ScreamingSnakeCase,
+        FieldNames::KebabCase,
+    ] {
+        let items = items();
+        let options = TracingOptions::default().field_names(field_names);
+        let fields = Vec::<FieldRef>::from_type::<Record>(options)?;
+
+        let mut builder = ArrayBuilder::from_arrow(&fields)?.with_field_names(field_names)?;
+        builder.extend(&items)?;
+        let batch = builder.to_record_batch()?;
+
+        let deserializer = Deserializer::from_record_batch(&batch)?.with_field_names(field_names);
+        let actual = Vec::<Record>::deserialize(deserializer)?;
+        assert_eq!(actual, items, "{field_names:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn camel_case_column_names() -> PanicOnError<()> {
+    let items = items();
+    let options = TracingOptions::default().field_names(FieldNames::CamelCase);
+    let fields = Vec::<FieldRef>::from_samples(&items, options)?;
+
+    let mut builder = ArrayBuilder::from_arrow(&fields)?.with_field_names(FieldNames::CamelCase)?;
+    builder.extend(&items)?;
+    let batch = builder.to_record_batch()?;
+
+    let schema = batch.schema();
+    let names = schema
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["userId", "homeAddress", "field1"]);
+    Ok(())
+}
+
+#[test]
+fn map_as_struct_roundtrip() -> PanicOnError<()> {
+    let items = vec![
+        BTreeMap::from([
+            (String::from("first_key"), 1_u8),
+            (String::from("second_key"), 2),
+        ]),
+        BTreeMap::from([
+            (String::from("first_key"), 3_u8),
+            (String::from("second_key"), 4),
+        ]),
+    ];
+    let options = TracingOptions::default().field_names(FieldNames::ScreamingKebabCase);
+    let fields = Vec::<FieldRef>::from_samples(&items, options)?;
+
+    let mut builder =
+        ArrayBuilder::from_arrow(&fields)?.with_field_names(FieldNames::ScreamingKebabCase)?;
+    builder.extend(&items)?;
+    let batch = builder.to_record_batch()?;
+    assert_eq!(batch.schema().field(0).name(), "FIRST-KEY");
+
+    let deserializer =
+        Deserializer::from_record_batch(&batch)?.with_field_names(FieldNames::ScreamingKebabCase);
+    let actual = Vec::<BTreeMap<String, u8>>::deserialize(deserializer)?;
+    assert_eq!(actual, items);
+    Ok(())
+}
+
+#[test]
+fn projection_uses_field_names() -> PanicOnError<()> {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Projected {
+        user_id: u64,
+    }
+
+    let items = items();
+    let options = TracingOptions::default().field_names(FieldNames::CamelCase);
+    let fields = Vec::<FieldRef>::from_type::<Record>(options.clone())?;
+    let mut builder = ArrayBuilder::from_arrow(&fields)?.with_field_names(FieldNames::CamelCase)?;
+    builder.extend(&items)?;
+    let batch = builder.to_record_batch()?;
+
+    let deserializer = Deserializer::from_record_batch(&batch)?
+        .with_field_names(FieldNames::CamelCase)
+        .project::<Projected>(options)?;
+    assert_eq!(deserializer.fields.len(), 1);
+
+    let actual = Vec::<Projected>::deserialize(deserializer)?;
+    assert_eq!(actual, [Projected { user_id: 1 }, Projected { user_id: 2 }]);
+    Ok(())
+}
+
+#[test]
+fn policy_must_be_set_before_pushing() -> PanicOnError<()> {
+    let items = items();
+    let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+    let mut builder = ArrayBuilder::from_arrow(&fields)?;
+    builder.extend(&items)?;
+    assert!(builder.with_field_names(FieldNames::CamelCase).is_err());
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use serde_json::json;
+
+use crate::_impl::arrow::datatypes::FieldRef;
+use crate::internal::error::PanicOnError;
+use crate::schema::{SchemaLike, SerdeArrowSchema};
+
+fn schema() -> PanicOnError<SerdeArrowSchema> {
+    Ok(SerdeArrowSchema::from_value(json!([
+        {"name": "id", "data_type": "U64"},
+        {"name": "createdAt", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+        {"name": "date", "data_type": "Date32", "nullable": true},
+        {"name": "time", "data_type": "Date64", "strategy": "NaiveStrAsDate64"},
+        {"name": "tags", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "LargeUtf8"},
+        ]},
+        {"name": "pair", "data_type": "Struct", "strategy": "TupleAsStruct", "children": [
+            {"name": "0", "data_type": "U8"},
+            {"name": "1", "data_type": "Bool"},
+        ]},
+        {"name": "status", "data_type": "Union", "children": [
+            {"name": "Active", "data_type": "Null"},
+            {"name": "archived", "data_type": "Struct", "children": [
+                {"name": "reason", "data_type": "Utf8"},
+            ]},
+        ]},
+    ]))?)
+}
+
+/// The output of `generate_rust_types("record")` for the schema above
+mod generated {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct Record {
+        pub id: u64,
+        #[serde(rename = "createdAt")]
+        pub created_at: chrono::DateTime<chrono::Utc>,
+        pub date: Option<chrono::NaiveDate>,
+        pub time: chrono::NaiveDateTime,
+        pub tags: Vec<String>,
+        pub pair: (u8, bool),
+        pub status: RecordStatus,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub enum RecordStatus {
+        Active,
+        #[serde(rename = "archived")]
+        Archived(RecordStatusArchived),
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct RecordStatusArchived {
+        pub reason: String,
+    }
+}
+
+#[test]
+fn generated_source_is_up_to_date() -> PanicOnError<()> {
+    let actual = schema()?.generate_rust_types("record")?;
+    let source = include_str!("generated_types.rs");
+    let start = source.find("    use serde::{").unwrap();
+    let end = source.find("\n}\n\n#[test]").unwrap();
+
+    let expected = source[start..end]
+        .lines()
+        .map(|line| line.strip_prefix("    ").unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert_eq!(actual.trim_end(), expected);
+    Ok(())
+}
+
+#[test]
+fn generated_types_roundtrip() -> PanicOnError<()> {
+    use generated::{Record, RecordStatus, RecordStatusArchived};
+
+    let items = vec![
+        Record {
+            id: 1,
+            created_at: chrono::DateTime::from_timestamp_millis(1_700_000_000_000).unwrap(),
+            date: None,
+            time: chrono::DateTime::from_timestamp_millis(1_700_000_002_000)
+                .unwrap()
+                .naive_utc(),
+            tags: vec![String::from("a")],
+            pair: (1, true),
+            status: RecordStatus::Active,
+        },
+        Record {
+            id: 2,
+            created_at: chrono::DateTime::from_timestamp_millis(1_700_000_001_000).unwrap(),
+            date: chrono::NaiveDate::from_ymd_opt(2024, 1, 2),
+            time: chrono::DateTime::from_timestamp_millis(1_700_000_003_000)
+                .unwrap()
+                .naive_utc(),
+            tags: vec![],
+            pair: (2, false),
+            status: RecordStatus::Archived(RecordStatusArchived {
+                reason: String::from("done"),
+            }),
+        },
+    ];
+
+    let fields = Vec::<FieldRef>::try_from(schema()?)?;
+    let batch = crate::to_record_batch(&fields, &items)?;
+    let actual: Vec<Record> = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+
+use crate::_impl::arrow::datatypes::{DataType, FieldRef};
+use crate::internal::{error::PanicOnError, testing::assert_error_contains};
+use crate::schema::{SchemaLike, TracingOptions};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    count: String,
+    ratio: Option<String>,
+    price: String,
+}
+
+#[test]
+fn numeric_strings_roundtrip() -> PanicOnError<()> {
+    let items = vec![
+        Record {
+            count: String::from("13"),
+            ratio: Some(String::from("0.5")),
+            price: String::from("12.50"),
+        },
+        Record {
+            count: String::from("-2"),
+            ratio: None,
+            price: String::from("100.00"),
+        },
+    ];
+
+    let options = TracingOptions::default()
+        .guess_numbers(true)
+        .guess_decimals(true);
+    let fields = Vec::<FieldRef>::from_samples(&items, options)?;
+    let data_types = fields
+        .iter()
+        .map(|field| field.data_type().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        data_types,
+        vec![
+            DataType::Decimal128(2, 0),
+            DataType::Decimal128(1, 1),
+            DataType::Decimal128(5, 2),
+        ]
+    );
+
+    let batch = crate::to_record_batch(&fields, &items)?;
+    let actual: Vec<Record> = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+    Ok(())
+}
+
+#[test]
+fn str_as_number_roundtrip() -> PanicOnError<()> {
+    let items = vec![
+        Record {
+            count: String::from("13"),
+            ratio: Some(String::from("0.5")),
+            price: String::from("1e3"),
+        },
+        Record {
+            count: String::from("-2"),
+            ratio: None,
+            price: String::from("2.5"),
+        },
+    ];
+
+    let options = TracingOptions::default()
+        .guess_numbers(true)
+        .exact_number_strings(true);
+    let fields = Vec::<FieldRef>::from_samples(&items, options)?;
+    let data_types = fields
+        .iter()
+        .map(|field| field.data_type().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        data_types,
+        vec![DataType::Int64, DataType::Float64, DataType::LargeUtf8]
+    );
+
+    let batch = crate::to_record_batch(&fields, &items)?;
+    let actual: Vec<Record> = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+
+    let actual: serde_json::Value = crate::from_record_batch(&batch)?;
+    assert_eq!(
+        actual,
+        serde_json::json!([
+            {"count": "13", "ratio": "0.5", "price": "1e3"},
+            {"count": "-2", "ratio": null, "price": "2.5"},
+        ])
+    );
+    Ok(())
+}
+
+#[test]
+fn guessed_numbers_lose_their_formatting() -> PanicOnError<()> {
+    let items = vec![
+        Record {
+            count: String::from("0003"),
+            ratio: Some(String::from("12.50")),
+            price: String::from("+1"),
+        },
+        Record {
+            count: String::from("4"),
+            ratio: None,
+            price: String::from("1.0"),
+        },
+    ];
+
+    let fields =
+        Vec::<FieldRef>::from_samples(&items, TracingOptions::default().guess_numbers(true))?;
+    let data_types = fields
+        .iter()
+        .map(|field| field.data_type().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        data_types,
+        vec![DataType::Int64, DataType::Float64, DataType::Float64]
+    );
+
+    let batch = crate::to_record_batch(&fields, &items)?;
+    let actual: serde_json::Value = crate::from_record_batch(&batch)?;
+    assert_eq!(
+        actual,
+        serde_json::json!([
+            {"count": "3", "ratio": "12.5", "price": "1"},
+            {"count": "4", "ratio": null, "price": "1"},
+        ])
+    );
+    Ok(())
+}
+
+#[test]
+fn exact_numbers_that_do_not_roundtrip_stay_strings() -> PanicOnError<()> {
+    let items = vec![
+        Record {
+            count: String::from("0003"),
+            ratio: Some(String::from("12.50")),
+            price: String::from("+1"),
+        },
+        Record {
+            count: String::from("4"),
+            ratio: None,
+            price: String::from("2"),
+        },
+    ];
+
+    let fields = Vec::<FieldRef>::from_samples(
+        &items,
+        TracingOptions::default()
+            .guess_numbers(true)
+            .exact_number_strings(true),
+    )?;
+    let data_types = fields
+        .iter()
+        .map(|field| field.data_type().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        data_types,
+        vec![
+            DataType::LargeUtf8,
+            DataType::LargeUtf8,
+            DataType::LargeUtf8
+        ]
+    );
+
+    let fields = Vec::<FieldRef>::from_samples(
+        &items,
+        TracingOptions::default()
+            .guess_numbers(true)
+            .guess_decimals(true)
+            .exact_number_strings(true),
+    )?;
+    let data_types = fields
+        .iter()
+        .map(|field| field.data_type().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        data_types,
+        vec![
+            DataType::LargeUtf8,
+            DataType::Decimal128(4, 2),
+            DataType::LargeUtf8
+        ]
+    );
+
+    let batch = crate::to_record_batch(&fields, &items)?;
+    let actual: Vec<Record> = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+    Ok(())
+}
+
+#[test]
+fn strings_into_numbers_require_str_as_number() -> PanicOnError<()> {
+    let fields = Vec::<FieldRef>::from_value(serde_json::json!([
+        {"name": "count", "data_type": "I64"},
+        {"name": "ratio", "data_type": "F64", "nullable": true},
+        {"name": "price", "data_type": "LargeUtf8"},
+    ]))?;
+    let items = vec![Record {
+        count: String::from("13"),
+        ratio: Some(String::from("0.5")),
+        price: String::from("2"),
+    }];
+
+    let res = crate::to_record_batch(&fields, &items);
+    assert_error_contains(&res, "StrAsNumber");
+    assert_error_contains(&res, "field: \"$.count\"");
+    Ok(())
+}
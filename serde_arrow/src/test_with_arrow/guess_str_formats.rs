@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::_impl::arrow::datatypes::{DataType, FieldRef};
+use crate::internal::{error::PanicOnError, testing::assert_error_contains};
+use crate::schema::{SchemaLike, TracingOptions};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: String,
+    address: Option<String>,
+}
+
+#[test]
+fn uuid_and_ip_address_roundtrip() -> PanicOnError<()> {
+    let items = vec![
+        Record {
+            id: String::from("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            address: Some(String::from("192.168.0.1")),
+        },
+        Record {
+            id: String::from("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8"),
+            address: None,
+        },
+        Record {
+            id: String::from("00000000-0000-0000-0000-000000000000"),
+            address: Some(String::from("2001:db8::1")),
+        },
+    ];
+
+    let options = TracingOptions::default()
+        .guess_uuids(true)
+        .guess_ip_addresses(true);
+    let fields = Vec::<FieldRef>::from_samples(&items, options)?;
+    assert_eq!(fields[0].data_type(), &DataType::FixedSizeBinary(16));
+    assert_eq!(
+        fields[0]
+            .metadata()
+            .get("ARROW:extension:name")
+            .map(String::as_str),
+        Some("arrow.uuid")
+    );
+    assert_eq!(fields[1].data_type(), &DataType::FixedSizeBinary(16));
+
+    let batch = crate::to_record_batch(&fields, &items)?;
+    let actual: Vec<Record> = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+
+    let actual: serde_json::Value = crate::from_record_batch(&batch)?;
+    assert_eq!(
+        actual,
+        json!([
+            {"id": "67e55044-10b1-426f-9247-bb680e5fe0c8", "address": "192.168.0.1"},
+            {"id": "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8", "address": null},
+            {"id": "00000000-0000-0000-0000-000000000000", "address": "2001:db8::1"},
+        ])
+    );
+    Ok(())
+}
+
+#[test]
+fn invalid_uuid() {
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "id",
+        "data_type": "FixedSizeBinary(16)",
+        "strategy": "UuidStrAsFixedSizeBinary",
+    }]))
+    .unwrap();
+
+    let res = crate::to_record_batch(&fields, &json!([{"id": "not-a-uuid"}]));
+    assert_error_contains(&res, "Invalid UUID");
+}
+
+#[test]
+fn strategy_requires_16_bytes() {
+    let res = Vec::<FieldRef>::from_value(json!([{
+        "name": "id",
+        "data_type": "FixedSizeBinary(8)",
+        "strategy": "IpStrAsFixedSizeBinary",
+    }]));
+    assert_error_contains(&res, "invalid strategy for FixedSizeBinary(8) field");
+}
@@ -330,14 +330,14 @@ fn time64_type_invalid_units() {
     // microseconds or nanoseconds."
 
     assert_error_contains(
-        &SerdeArrowSchema::from_value(&json!([{
+        &SerdeArrowSchema::from_value(json!([{
             "name": "item",
             "data_type": "Time64(Millisecond)",
         }])),
         "Error: Time64 field must have Microsecond or Nanosecond unit",
     );
     assert_error_contains(
-        &SerdeArrowSchema::from_value(&json!([{
+        &SerdeArrowSchema::from_value(json!([{
             "name": "item",
             "data_type": "Time64(Second)",
         }])),
@@ -345,14 +345,14 @@ fn time64_type_invalid_units() {
     );
 
     assert_error_contains(
-        &SerdeArrowSchema::from_value(&json!([{
+        &SerdeArrowSchema::from_value(json!([{
             "name": "item",
             "data_type": "Time32(Microsecond)",
         }])),
         "Error: Time32 field must have Second or Millisecond unit",
     );
     assert_error_contains(
-        &SerdeArrowSchema::from_value(&json!([{
+        &SerdeArrowSchema::from_value(json!([{
             "name": "item",
             "data_type": "Time32(Nanosecond)",
         }])),
@@ -682,7 +682,7 @@ mod naive_time {
             .with_schema(json!([{"name": "item", "data_type": "LargeUtf8"}]))
             .trace_schema_from_samples(&items, TracingOptions::default())
             .serialize(&items)
-            .deserialize(&items);
+            .deserialize(items);
     }
 
     #[test]
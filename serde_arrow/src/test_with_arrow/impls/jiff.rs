@@ -342,7 +342,7 @@ mod span {
 
     impl std::cmp::PartialEq for EquivalentSpan {
         fn eq(&self, other: &Self) -> bool {
-            match self.0.compare(&other.0) {
+            match self.0.compare(other.0) {
                 Ok(ordering) => ordering == std::cmp::Ordering::Equal,
                 Err(_) => false,
             }
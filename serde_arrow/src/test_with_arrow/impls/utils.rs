@@ -64,7 +64,7 @@ impl Test {
 }
 
 impl Test {
-    pub fn get_arrow_fields(&self) -> Cow<'_, Vec<arrow::datatypes::FieldRef>> {
+    pub fn get_arrow_fields(&self) -> Cow<'_, [arrow::datatypes::FieldRef]> {
         match self.schema.as_ref() {
             Some(schema) => Cow::Owned(
                 Vec::<arrow::datatypes::FieldRef>::try_from(schema)
@@ -79,7 +79,7 @@ impl Test {
         }
     }
 
-    pub fn get_arrow2_fields(&self) -> Cow<'_, Vec<arrow2::datatypes::Field>> {
+    pub fn get_arrow2_fields(&self) -> Cow<'_, [arrow2::datatypes::Field]> {
         match self.schema.as_ref() {
             Some(schema) => Cow::Owned(
                 Vec::<arrow2::datatypes::Field>::try_from(schema)
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::_impl::arrow::datatypes::FieldRef;
+use crate::internal::error::PanicOnError;
+use crate::schema::SerdeArrowSchema;
+
+#[test]
+fn serialize_events_with_imported_schema() -> PanicOnError<()> {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        id: String,
+        timestamp: String,
+        tags: Vec<String>,
+        status: Status,
+        comment: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Status {
+        Active,
+        Archived { reason: String },
+    }
+
+    let schema = SerdeArrowSchema::from_json_schema(json!({
+        "type": "object",
+        "properties": {
+            "id": {"type": "string", "format": "uuid"},
+            "timestamp": {"type": "string", "format": "date-time"},
+            "tags": {"type": "array", "items": {"type": "string"}},
+            "status": {
+                "oneOf": [
+                    {"type": "string", "enum": ["Active"]},
+                    {
+                        "type": "object",
+                        "properties": {
+                            "Archived": {
+                                "type": "object",
+                                "properties": {"reason": {"type": "string"}},
+                                "required": ["reason"],
+                            },
+                        },
+                        "required": ["Archived"],
+                    },
+                ],
+            },
+            "comment": {"type": "string"},
+        },
+        "required": ["id", "timestamp", "tags", "status"],
+    }))?;
+
+    let items = vec![
+        Event {
+            id: String::from("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            timestamp: String::from("2024-01-02T03:04:05Z"),
+            tags: vec![String::from("a")],
+            status: Status::Active,
+            comment: None,
+        },
+        Event {
+            id: String::from("a2d3bd4c-1b4b-4a7c-8a6b-6c7b2f2b0f4e"),
+            timestamp: String::from("2024-02-03T04:05:06Z"),
+            tags: vec![],
+            status: Status::Archived {
+                reason: String::from("done"),
+            },
+            comment: Some(String::from("hello")),
+        },
+    ];
+
+    let fields = Vec::<FieldRef>::try_from(schema)?;
+    let batch = crate::to_record_batch(&fields, &items)?;
+    let actual: Vec<Event> = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+    Ok(())
+}
+
+#[test]
+fn serialize_none_into_nullable_union() -> PanicOnError<()> {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        value: Option<Value>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Value {
+        Int(i64),
+        Str(String),
+    }
+
+    let schema = SerdeArrowSchema::from_json_schema(json!({
+        "type": "object",
+        "required": ["value"],
+        "properties": {
+            "value": {
+                "oneOf": [
+                    {"type": "null"},
+                    {"type": "object", "required": ["Int"], "properties": {"Int": {"type": "integer"}}},
+                    {"type": "object", "required": ["Str"], "properties": {"Str": {"type": "string"}}},
+                ],
+            },
+        },
+    }))?;
+
+    let items = vec![
+        Record {
+            value: Some(Value::Int(13)),
+        },
+        Record { value: None },
+        Record {
+            value: Some(Value::Str(String::from("hello"))),
+        },
+    ];
+
+    let fields = Vec::<FieldRef>::try_from(schema)?;
+    let batch = crate::to_record_batch(&fields, &items)?;
+    let actual: Vec<Record> = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+    Ok(())
+}
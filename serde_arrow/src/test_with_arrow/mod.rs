@@ -1,10 +1,28 @@
 //! Test with an arrow implementation
 //!
+mod avro_schema_import;
+mod column_projection;
+mod columnar_deserialization;
+#[cfg(feature = "derive")]
+mod derive_arrow_schema;
+mod dictionary_encoding_threshold;
+mod field_names;
+mod generated_types;
+mod guess_numbers;
+mod guess_str_formats;
 mod impls;
 mod issue_137_schema_like_from_arrow_schema;
 mod issue_248_slices_deserialization;
 mod issue_35_preserve_metadata;
 mod issue_90_top_level_nulls_in_structs;
 mod items_wrapper;
+mod json_schema_import;
+mod multiple_record_batches;
+mod narrow_integers;
+mod recursive_types;
+mod row_range_deserialization;
+mod schema_evolution;
+mod schema_fingerprint;
+mod schema_metadata;
 mod schema_overwrites;
 mod serializer_deserializer;
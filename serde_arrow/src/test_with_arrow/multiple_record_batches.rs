@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::_impl::arrow::{
+    array::RecordBatch,
+    datatypes::{FieldRef, Schema},
+    error::ArrowError,
+};
+use crate::internal::testing::assert_error_contains;
+use crate::{
+    self as serde_arrow,
+    schema::{SchemaLike, TracingOptions},
+    Deserializer,
+};
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+struct Record {
+    int: Option<i32>,
+    string: String,
+    list: Vec<u16>,
+}
+
+fn example_records(len: usize) -> Vec<Record> {
+    (0..len)
+        .map(|idx| Record {
+            int: (idx % 2 == 0).then_some(idx as i32),
+            string: idx.to_string(),
+            list: vec![idx as u16; idx % 3],
+        })
+        .collect()
+}
+
+fn example_batches(sizes: &[usize]) -> (Vec<Record>, Vec<RecordBatch>) {
+    let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default()).unwrap();
+    let items = example_records(sizes.iter().sum());
+
+    let mut batches = Vec::new();
+    let mut offset = 0;
+    for &size in sizes {
+        batches
+            .push(serde_arrow::to_record_batch(&fields, &&items[offset..offset + size]).unwrap());
+        offset += size;
+    }
+    (items, batches)
+}
+
+#[test]
+fn deserialize_multiple_batches() {
+    let (items, batches) = example_batches(&[3, 0, 5, 1]);
+
+    let actual: Vec<Record> = serde_arrow::from_record_batches(&batches).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn deserialize_no_batches() {
+    let actual: Vec<Record> = serde_arrow::from_record_batches(&[]).unwrap();
+    assert_eq!(actual, vec![]);
+}
+
+#[test]
+fn get_rows_across_batches() {
+    let (items, batches) = example_batches(&[2, 0, 3, 4]);
+
+    let deserializer = Deserializer::from_record_batches(&batches).unwrap();
+    for (idx, expected) in items.iter().enumerate() {
+        let actual: Record = deserializer.get(idx).unwrap();
+        assert_eq!(&actual, expected, "row {idx}");
+    }
+    assert_error_contains(&deserializer.get::<Record>(9), "Invalid index");
+}
+
+#[test]
+fn lazy_iteration() {
+    let (items, batches) = example_batches(&[4, 0, 2, 3]);
+
+    let mut pulled = 0;
+    let mut iter = serde_arrow::iter_from_record_batches::<Record, _, ArrowError>(
+        batches.iter().cloned().map(Ok).inspect(|_| pulled += 1),
+    );
+    assert_eq!(iter.next().unwrap().unwrap(), items[0]);
+    drop(iter);
+    assert_eq!(pulled, 1);
+
+    let actual =
+        serde_arrow::iter_from_record_batches::<Record, _, ArrowError>(batches.into_iter().map(Ok))
+            .collect::<serde_arrow::Result<Vec<_>>>()
+            .unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn lazy_iteration_is_row_by_row() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Narrow {
+        int: Option<u8>,
+    }
+
+    let (_, batches) = example_batches(&[3]);
+    let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default()).unwrap();
+    let items = [Some(1), None, Some(1000)].map(|int| Record {
+        int,
+        string: String::new(),
+        list: Vec::new(),
+    });
+    let batch = serde_arrow::to_record_batch(&fields, &items).unwrap();
+
+    // the rows before the invalid row are returned before the error
+    let mut iter = serde_arrow::iter_from_record_batches::<Narrow, _, ArrowError>(vec![
+        Ok(batch),
+        Ok(batches[0].clone()),
+    ]);
+    assert_eq!(iter.next().unwrap().unwrap(), Narrow { int: Some(1) });
+    assert_eq!(iter.next().unwrap().unwrap(), Narrow { int: None });
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn lazy_iteration_forwards_errors() {
+    let (items, batches) = example_batches(&[2]);
+
+    let mut iter = serde_arrow::iter_from_record_batches::<Record, _, ArrowError>(vec![
+        Ok(batches[0].clone()),
+        Err(ArrowError::ComputeError(String::from("reader failed"))),
+        Ok(batches[0].clone()),
+    ]);
+    assert_eq!(iter.next().unwrap().unwrap(), items[0]);
+    assert_eq!(iter.next().unwrap().unwrap(), items[1]);
+    assert_error_contains(&iter.next().unwrap(), "reader failed");
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn mismatched_schemas() {
+    let (_, batches) = example_batches(&[2, 3]);
+
+    let schema = batches[1].schema();
+    let renamed = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            Arc::new(
+                field
+                    .as_ref()
+                    .clone()
+                    .with_name(format!("{}_", field.name())),
+            )
+        })
+        .collect::<Vec<_>>();
+    let other = RecordBatch::try_new(
+        Arc::new(Schema::new(renamed)),
+        batches[1].columns().to_vec(),
+    )
+    .unwrap();
+
+    let res = Deserializer::from_record_batches([&batches[0], &other]);
+    assert_error_contains(&res, "different schemas");
+    assert_error_contains(&res, "batch 1");
+
+    let res = serde_arrow::iter_from_record_batches::<Record, _, ArrowError>(vec![
+        Ok(batches[0].clone()),
+        Ok(other),
+    ])
+    .collect::<serde_arrow::Result<Vec<_>>>();
+    assert_error_contains(&res, "different schemas");
+}
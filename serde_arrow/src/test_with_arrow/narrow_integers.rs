@@ -0,0 +1,31 @@
+use serde_json::json;
+
+use crate::_impl::arrow::datatypes::{DataType, FieldRef};
+use crate::internal::error::PanicOnError;
+use crate::schema::{SchemaLike, TracingOptions};
+
+#[test]
+fn json_values_roundtrip_with_narrowed_types() -> PanicOnError<()> {
+    let items = json!([
+        {"small": 13, "negative": -2, "large": 70000},
+        {"small": 255, "negative": 100, "large": 1},
+    ]);
+    let options = TracingOptions::default()
+        .narrow_integers(true)
+        .coerce_numbers(true);
+    let fields = Vec::<FieldRef>::from_samples(&items, options)?;
+
+    let data_types = fields
+        .iter()
+        .map(|field| field.data_type().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        data_types,
+        vec![DataType::UInt32, DataType::Int8, DataType::UInt8]
+    );
+
+    let batch = crate::to_record_batch(&fields, &items)?;
+    let actual: serde_json::Value = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+    Ok(())
+}
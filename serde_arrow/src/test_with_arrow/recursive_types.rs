@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use crate::_impl::arrow::datatypes::FieldRef;
+use crate::internal::{error::PanicOnError, utils::Item};
+use crate::schema::{SchemaLike, TracingOptions};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Comment {
+    text: String,
+    replies: Vec<Comment>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Expr {
+    Literal(i64),
+    Neg(Box<Expr>),
+    Add { lhs: Box<Expr>, rhs: Box<Expr> },
+    Call(String, Vec<Expr>),
+}
+
+fn comment(text: &str, replies: Vec<Comment>) -> Comment {
+    Comment {
+        text: text.to_string(),
+        replies,
+    }
+}
+
+fn comments() -> Vec<Comment> {
+    vec![
+        comment(
+            "root",
+            vec![
+                comment("a", vec![comment("b", vec![comment("c", vec![])])]),
+                comment("d", vec![]),
+            ],
+        ),
+        comment("single", vec![]),
+    ]
+}
+
+#[test]
+fn struct_tree_roundtrip() -> PanicOnError<()> {
+    for options in [
+        TracingOptions::default().max_depth(Some(1)),
+        TracingOptions::default().max_depth(Some(3)),
+        TracingOptions::default()
+            .max_depth(Some(3))
+            .max_depth_as_binary(true),
+    ] {
+        let items = comments();
+        let fields = Vec::<FieldRef>::from_type::<Comment>(options.clone())?;
+        let batch = crate::to_record_batch(&fields, &items)?;
+        let actual: Vec<Comment> = crate::from_record_batch(&batch)?;
+        assert_eq!(actual, items);
+
+        let fields = Vec::<FieldRef>::from_samples(&items, options)?;
+        let batch = crate::to_record_batch(&fields, &items)?;
+        let actual: Vec<Comment> = crate::from_record_batch(&batch)?;
+        assert_eq!(actual, items);
+    }
+    Ok(())
+}
+
+#[test]
+fn enum_tree_roundtrip() -> PanicOnError<()> {
+    let items = vec![
+        Item(Expr::Literal(1)),
+        Item(Expr::Neg(Box::new(Expr::Literal(2)))),
+        Item(Expr::Neg(Box::new(Expr::Add {
+            lhs: Box::new(Expr::Literal(3)),
+            rhs: Box::new(Expr::Neg(Box::new(Expr::Literal(4)))),
+        }))),
+        Item(Expr::Add {
+            lhs: Box::new(Expr::Call(String::from("f"), vec![Expr::Literal(5)])),
+            rhs: Box::new(Expr::Literal(6)),
+        }),
+        Item(Expr::Call(
+            String::from("g"),
+            vec![Expr::Literal(7), Expr::Neg(Box::new(Expr::Literal(8)))],
+        )),
+    ];
+
+    for max_depth in [1, 2, 3] {
+        let options = TracingOptions::default().max_depth(Some(max_depth));
+        let fields = Vec::<FieldRef>::from_type::<Item<Expr>>(options)?;
+        let batch = crate::to_record_batch(&fields, &items)?;
+        let actual: Vec<Item<Expr>> = crate::from_record_batch(&batch)?;
+        assert_eq!(actual, items);
+    }
+    Ok(())
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::_impl::{arrow, arrow2};
+use crate::internal::testing::{assert_error_contains, hash_map};
+use crate::utils::Item;
+use crate::{
+    self as serde_arrow,
+    schema::{SchemaLike, TracingOptions},
+    Deserializer,
+};
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+enum Value {
+    I64(i64),
+    Str(String),
+    Pair { a: u8, b: Option<String> },
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+struct Record {
+    int: Option<i32>,
+    string: String,
+    list: Vec<Vec<u16>>,
+    map: HashMap<String, Vec<i64>>,
+    nested: Option<Nested>,
+    value: Value,
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+struct Nested {
+    flag: bool,
+    items: Vec<String>,
+}
+
+fn example_records() -> Vec<Record> {
+    (0..10)
+        .map(|idx| Record {
+            int: (idx % 3 != 0).then_some(idx),
+            string: idx.to_string(),
+            list: (0..idx).map(|n| vec![n as u16; n as usize]).collect(),
+            map: hash_map!(idx.to_string() => vec![idx as i64; idx as usize]),
+            nested: (idx % 2 == 0).then(|| Nested {
+                flag: idx % 4 == 0,
+                items: vec![format!("item {idx}")],
+            }),
+            value: match idx % 3 {
+                0 => Value::I64(idx as i64),
+                1 => Value::Str(format!("str {idx}")),
+                _ => Value::Pair {
+                    a: idx as u8,
+                    b: (idx > 5).then(|| idx.to_string()),
+                },
+            },
+        })
+        .collect()
+}
+
+fn example_fields() -> Vec<arrow::datatypes::FieldRef> {
+    Vec::<arrow::datatypes::FieldRef>::from_type::<Record>(
+        TracingOptions::default()
+            .map_as_struct(false)
+            .sequence_as_large_list(false),
+    )
+    .unwrap()
+}
+
+#[test]
+fn record_batch_ranges() {
+    let items = example_records();
+    let batch = serde_arrow::to_record_batch(&example_fields(), &items).unwrap();
+
+    for start in 0..items.len() {
+        for end in start..=items.len() {
+            let deserializer = Deserializer::from_record_batch_range(&batch, start..end).unwrap();
+            let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+            assert_eq!(actual, items[start..end], "range {start}..{end}");
+        }
+    }
+}
+
+#[test]
+fn ranges_of_sliced_record_batches() {
+    let items = example_records();
+    let batch = serde_arrow::to_record_batch(&example_fields(), &items).unwrap();
+    let batch = batch.slice(3, 6);
+
+    let deserializer = Deserializer::from_record_batch_range(&batch, 2..5).unwrap();
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+    assert_eq!(actual, items[5..8]);
+}
+
+#[test]
+fn get_single_rows() {
+    let items = example_records();
+    let batch = serde_arrow::to_record_batch(&example_fields(), &items).unwrap();
+
+    let deserializer = Deserializer::from_record_batch(&batch).unwrap();
+    for (idx, expected) in items.iter().enumerate().rev() {
+        let actual: Record = deserializer.get(idx).unwrap();
+        assert_eq!(&actual, expected, "row {idx}");
+    }
+
+    let deserializer = Deserializer::from_record_batch_range(&batch, 4..8).unwrap();
+    let actual: Record = deserializer.get(1).unwrap();
+    assert_eq!(actual, items[5]);
+
+    // the deserializer can still be used afterwards
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+    assert_eq!(actual, items[4..8]);
+}
+
+#[test]
+fn get_single_values_via_item() {
+    let items = [Item(1_u32), Item(2), Item(3)];
+    let fields =
+        Vec::<arrow::datatypes::FieldRef>::from_type::<Item<u32>>(TracingOptions::default())
+            .unwrap();
+    let arrays = serde_arrow::to_arrow(&fields, items).unwrap();
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays).unwrap();
+    let Item(actual): Item<u32> = deserializer.get(2).unwrap();
+    assert_eq!(actual, 3);
+}
+
+#[test]
+fn dictionary_and_fixed_size_ranges() {
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Record {
+        dict: String,
+        fixed: Vec<u8>,
+    }
+
+    let items = (0..6)
+        .map(|idx| Record {
+            dict: format!("value {}", idx % 2),
+            fixed: vec![idx; 3],
+        })
+        .collect::<Vec<_>>();
+
+    let fields = Vec::<arrow::datatypes::FieldRef>::from_value(json!([
+        {
+            "name": "dict",
+            "data_type": "Dictionary",
+            "children": [
+                {"name": "key", "data_type": "U8"},
+                {"name": "value", "data_type": "Utf8"},
+            ],
+        },
+        {
+            "name": "fixed",
+            "data_type": "FixedSizeList(3)",
+            "children": [{"name": "element", "data_type": "U8"}],
+        },
+    ]))
+    .unwrap();
+    let arrays = serde_arrow::to_arrow(&fields, &items).unwrap();
+
+    let deserializer = Deserializer::from_arrow_range(&fields, &arrays, 3..5).unwrap();
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+    assert_eq!(actual, items[3..5]);
+}
+
+#[test]
+fn arrow2_ranges() {
+    let items = example_records();
+    let fields = Vec::<arrow2::datatypes::Field>::from_type::<Record>(
+        TracingOptions::default()
+            .map_as_struct(false)
+            .sequence_as_large_list(false),
+    )
+    .unwrap();
+    let arrays = serde_arrow::to_arrow2(&fields, &items).unwrap();
+
+    let deserializer = Deserializer::from_arrow2_range(&fields, &arrays, 7..10).unwrap();
+    let actual: Record = deserializer.get(0).unwrap();
+    assert_eq!(actual, items[7]);
+
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+    assert_eq!(actual, items[7..10]);
+}
+
+#[test]
+fn invalid_ranges() {
+    let items = example_records();
+    let batch = serde_arrow::to_record_batch(&example_fields(), &items).unwrap();
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let res = Deserializer::from_record_batch_range(&batch, 5..4);
+    assert_error_contains(&res, "Invalid range");
+
+    let res = Deserializer::from_record_batch_range(&batch, 5..11);
+    assert_error_contains(&res, "Invalid range");
+
+    let deserializer = Deserializer::from_record_batch(&batch).unwrap();
+    assert_error_contains(&deserializer.get::<Record>(10), "Invalid index");
+}
@@ -0,0 +1,296 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::_impl::arrow::{array::RecordBatch, datatypes::FieldRef};
+use crate::internal::testing::assert_error_contains;
+use crate::{
+    self as serde_arrow,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions, DEFAULT_VALUE_KEY},
+    Deserializer,
+};
+
+#[derive(Debug, Serialize)]
+struct OldNested {
+    x: i32,
+}
+
+#[derive(Debug, Serialize)]
+struct OldRecord {
+    a: i32,
+    nested: OldNested,
+    removed: String,
+}
+
+fn old_batch() -> RecordBatch {
+    let items = (0..3)
+        .map(|idx| OldRecord {
+            a: idx,
+            nested: OldNested { x: 10 * idx },
+            removed: idx.to_string(),
+        })
+        .collect::<Vec<_>>();
+    let fields = Vec::<FieldRef>::from_samples(&items, TracingOptions::default()).unwrap();
+    serde_arrow::to_record_batch(&fields, &items).unwrap()
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct NewNested {
+    x: i32,
+    y: Option<bool>,
+    z: i64,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Added {
+    flag: bool,
+    values: Vec<u8>,
+    name: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct NewRecord {
+    a: i32,
+    nested: NewNested,
+    b: Option<String>,
+    c: u32,
+    d: String,
+    e: Vec<f64>,
+    added: Added,
+}
+
+fn expected_new_record(idx: i32) -> NewRecord {
+    NewRecord {
+        a: idx,
+        nested: NewNested {
+            x: 10 * idx,
+            y: None,
+            z: 0,
+        },
+        b: None,
+        c: 0,
+        d: String::new(),
+        e: vec![],
+        added: Added {
+            flag: false,
+            values: vec![],
+            name: None,
+        },
+    }
+}
+
+#[test]
+fn missing_fields_without_schema_fail() {
+    let batch = old_batch();
+    let res = serde_arrow::from_record_batch::<Vec<NewRecord>>(&batch);
+    assert_error_contains(&res, "missing field");
+}
+
+#[test]
+fn fill_missing_fields() {
+    let batch = old_batch();
+    let schema = SerdeArrowSchema::from_type::<NewRecord>(TracingOptions::default()).unwrap();
+
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_schema(&schema)
+        .unwrap();
+
+    let actual: NewRecord = deserializer.get(2).unwrap();
+    assert_eq!(actual, expected_new_record(2));
+
+    let actual = Vec::<NewRecord>::deserialize(deserializer).unwrap();
+    let expected = (0..3).map(expected_new_record).collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn fill_missing_fields_across_batches_and_projection() {
+    let batch = old_batch();
+    let batches = [batch.clone(), batch.slice(1, 2)];
+    let schema = SerdeArrowSchema::from_type::<NewRecord>(TracingOptions::default()).unwrap();
+
+    let deserializer = Deserializer::from_record_batches(&batches)
+        .unwrap()
+        .with_schema(&schema)
+        .unwrap()
+        .project::<NewRecord>(TracingOptions::default())
+        .unwrap();
+    assert_eq!(deserializer.fields.len(), 2);
+
+    let actual = Vec::<NewRecord>::deserialize(deserializer).unwrap();
+    let expected = [0, 1, 2, 1, 2]
+        .into_iter()
+        .map(expected_new_record)
+        .collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn default_values_from_metadata() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Record {
+        a: i32,
+        b: u32,
+        c: String,
+        d: Option<f32>,
+        date: chrono::NaiveDate,
+        nested: Nested,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Nested {
+        x: i32,
+        flag: bool,
+    }
+
+    let schema = SerdeArrowSchema::from_value(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "U32", "metadata": {DEFAULT_VALUE_KEY: "42"}},
+        {"name": "c", "data_type": "Utf8", "metadata": {DEFAULT_VALUE_KEY: "hello"}},
+        {
+            "name": "d",
+            "data_type": "F32",
+            "nullable": true,
+            "metadata": {DEFAULT_VALUE_KEY: "1.5"},
+        },
+        {"name": "date", "data_type": "Date32", "metadata": {DEFAULT_VALUE_KEY: "2024-01-01"}},
+        {
+            "name": "nested",
+            "data_type": "Struct",
+            "children": [
+                {"name": "x", "data_type": "I32"},
+                {"name": "flag", "data_type": "Bool", "metadata": {DEFAULT_VALUE_KEY: "true"}},
+            ],
+        },
+    ]))
+    .unwrap();
+
+    let batch = old_batch();
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_schema(&schema)
+        .unwrap();
+    let actual: Record = deserializer.get(1).unwrap();
+
+    assert_eq!(
+        actual,
+        Record {
+            a: 1,
+            b: 42,
+            c: String::from("hello"),
+            d: Some(1.5),
+            date: chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            nested: Nested { x: 10, flag: true },
+        }
+    );
+}
+
+#[test]
+fn temporal_and_decimal_default_values() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Physical {
+        date: i32,
+        ts: i64,
+        time: i64,
+        duration: i64,
+        decimal: String,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Formatted {
+        date: chrono::NaiveDate,
+        ts: chrono::DateTime<chrono::Utc>,
+        time: chrono::NaiveTime,
+        duration: String,
+        decimal: String,
+    }
+
+    let schema = SerdeArrowSchema::from_value(json!([
+        {"name": "date", "data_type": "Date32", "metadata": {DEFAULT_VALUE_KEY: "1970-01-02"}},
+        {
+            "name": "ts",
+            "data_type": "Timestamp(Millisecond, Some(\"UTC\"))",
+            "metadata": {DEFAULT_VALUE_KEY: "1970-01-01T00:00:01Z"},
+        },
+        {"name": "time", "data_type": "Time64(Microsecond)", "metadata": {DEFAULT_VALUE_KEY: "00:00:02"}},
+        {"name": "duration", "data_type": "Duration(Second)", "metadata": {DEFAULT_VALUE_KEY: "PT3S"}},
+        {"name": "decimal", "data_type": "Decimal128(5, 2)", "metadata": {DEFAULT_VALUE_KEY: "1.5"}},
+    ]))
+    .unwrap();
+
+    let batch = old_batch();
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_schema(&schema)
+        .unwrap();
+
+    let actual: Physical = deserializer.get(0).unwrap();
+    assert_eq!(
+        actual,
+        Physical {
+            date: 1,
+            ts: 1_000,
+            time: 2_000_000,
+            duration: 3,
+            decimal: String::from("1.50"),
+        }
+    );
+
+    let actual: Formatted = deserializer.get(0).unwrap();
+    assert_eq!(
+        actual,
+        Formatted {
+            date: chrono::NaiveDate::from_ymd_opt(1970, 1, 2).unwrap(),
+            ts: chrono::DateTime::from_timestamp(1, 0).unwrap(),
+            time: chrono::NaiveTime::from_hms_opt(0, 0, 2).unwrap(),
+            duration: String::from("PT3s"),
+            decimal: String::from("1.50"),
+        }
+    );
+}
+
+#[test]
+fn missing_fields_without_default() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        a: i32,
+        ts: i64,
+    }
+
+    let schema = SerdeArrowSchema::from_value(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "ts", "data_type": "Timestamp(Millisecond, None)"},
+    ]))
+    .unwrap();
+
+    let batch = old_batch();
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_schema(&schema)
+        .unwrap();
+    let res = Vec::<Record>::deserialize(deserializer);
+    assert_error_contains(&res, "No default value for data type Timestamp");
+}
+
+#[test]
+fn invalid_default_values() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        b: u8,
+    }
+
+    let schema = SerdeArrowSchema::from_value(json!([
+        {"name": "b", "data_type": "U8", "metadata": {DEFAULT_VALUE_KEY: "300"}},
+    ]))
+    .unwrap();
+
+    let batch = old_batch();
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_schema(&schema)
+        .unwrap();
+    let res = Vec::<Record>::deserialize(deserializer);
+    assert_error_contains(&res, "ParseIntError");
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::_impl::arrow::datatypes::{DataType, Field, FieldRef};
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+};
+
+#[test]
+fn arrow_list_item_names() -> PanicOnError<()> {
+    #[derive(Serialize)]
+    struct Record {
+        values: Vec<i32>,
+    }
+
+    let traced = SerdeArrowSchema::from_samples(
+        &[Record { values: vec![1] }],
+        TracingOptions::default().sequence_as_large_list(false),
+    )?;
+
+    let fields: Vec<FieldRef> = vec![Arc::new(Field::new(
+        "values",
+        DataType::List(Arc::new(Field::new("item", DataType::Int32, false))),
+        false,
+    ))];
+    let from_arrow = SerdeArrowSchema::try_from(fields.as_slice())?;
+
+    assert_ne!(traced, from_arrow);
+    assert!(traced.is_equivalent(&from_arrow));
+    assert_eq!(traced.fingerprint(), from_arrow.fingerprint());
+    Ok(())
+}
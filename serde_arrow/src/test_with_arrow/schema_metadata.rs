@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+use crate::_impl::arrow::datatypes::Schema;
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    testing::hash_map,
+};
+use crate::ArrayBuilder;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u64,
+    distance: f64,
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            id: 1,
+            distance: 2.5,
+        },
+        Record {
+            id: 2,
+            distance: 0.5,
+        },
+    ]
+}
+
+#[test]
+fn record_batch_metadata() -> PanicOnError<()> {
+    let items = items();
+    let options = TracingOptions::default()
+        .field_metadata("distance", "unit", "km")
+        .schema_metadata("owner", "core");
+    let schema = SerdeArrowSchema::from_type::<Record>(options)?;
+
+    let mut builder = ArrayBuilder::new(schema)?;
+    builder.extend(&items)?;
+    let batch = builder.to_record_batch()?;
+
+    let batch_schema = batch.schema();
+    assert_eq!(batch_schema.metadata(), &hash_map!("owner" => "core"));
+    assert_eq!(batch_schema.field(1).metadata(), &hash_map!("unit" => "km"));
+
+    let actual: Vec<Record> = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+    Ok(())
+}
+
+#[test]
+fn arrow_schema_roundtrip() -> PanicOnError<()> {
+    let schema = SerdeArrowSchema::from_type::<Record>(
+        TracingOptions::default().schema_metadata("version", "1"),
+    )?;
+
+    let arrow_schema = Schema::try_from(&schema)?;
+    assert_eq!(arrow_schema.metadata(), &hash_map!("version" => "1"));
+    assert_eq!(arrow_schema.fields().len(), 2);
+
+    let roundtripped = SerdeArrowSchema::try_from(&arrow_schema)?;
+    assert_eq!(roundtripped, schema);
+    Ok(())
+}
+
+#[test]
+fn to_record_batch_with_schema_keeps_the_metadata() -> PanicOnError<()> {
+    let items = items();
+    let schema = SerdeArrowSchema::from_type::<Record>(
+        TracingOptions::default()
+            .field_metadata("distance", "unit", "km")
+            .schema_metadata("owner", "core"),
+    )?;
+    let schema = Schema::try_from(&schema)?;
+
+    let batch = crate::to_record_batch_with_schema(&schema, &items)?;
+    let batch_schema = batch.schema();
+    assert_eq!(batch_schema.metadata(), &hash_map!("owner" => "core"));
+    assert_eq!(batch_schema.field(1).metadata(), &hash_map!("unit" => "km"));
+
+    let mut builder = ArrayBuilder::from_arrow_schema(&schema)?;
+    builder.extend(&items)?;
+    let batch = builder.to_record_batch()?;
+    assert_eq!(batch.schema().metadata(), &hash_map!("owner" => "core"));
+
+    let actual: Vec<Record> = crate::from_record_batch(&batch)?;
+    assert_eq!(actual, items);
+    Ok(())
+}
@@ -5,6 +5,7 @@ use serde_json::json;
 use crate::internal::{
     error::PanicOnError,
     schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    testing::assert_error_contains,
 };
 
 /// https://github.com/chmp/serde_arrow/issues/187
@@ -16,7 +17,7 @@ fn example_issue_187() -> PanicOnError<()> {
         pub expiry: DateTime<Utc>,
     }
 
-    let expected = SerdeArrowSchema::from_value(&json!([
+    let expected = SerdeArrowSchema::from_value(json!([
         {"name": "expiry", "data_type": "Timestamp(Microsecond, None)"}
     ]))?;
 
@@ -47,7 +48,7 @@ fn example_nested_overwrites_vec() -> PanicOnError<()> {
     )?;
     let actual = SerdeArrowSchema::from_type::<Example>(options)?;
 
-    let expected = SerdeArrowSchema::from_value(&json!([
+    let expected = SerdeArrowSchema::from_value(json!([
         {
             "name": "date_times",
             "data_type": "LargeList",
@@ -77,7 +78,7 @@ fn example_nested_overwrites_structs() -> PanicOnError<()> {
         .overwrite("inner.value", json!({"name": "value", "data_type": "I32"}))?;
     let actual = SerdeArrowSchema::from_type::<Example>(options)?;
 
-    let expected = SerdeArrowSchema::from_value(&json!([
+    let expected = SerdeArrowSchema::from_value(json!([
         {
             "name": "inner",
             "data_type": "Struct",
@@ -108,7 +109,7 @@ fn example_nested_struct_overwrite_outer() -> PanicOnError<()> {
         .overwrite("field", json!({"name": "field", "data_type": "I32"}))?;
     let actual = SerdeArrowSchema::from_type::<Example>(options)?;
 
-    let expected = SerdeArrowSchema::from_value(&json!([
+    let expected = SerdeArrowSchema::from_value(json!([
         {
             "name": "inner",
             "data_type": "Struct",
@@ -138,10 +139,168 @@ fn example_with_arrow_field() {
             .unwrap(),
     )
     .unwrap();
-    let expected = SerdeArrowSchema::from_value(&json!([
+    let expected = SerdeArrowSchema::from_value(json!([
         {"name": "value", "data_type": "U64"}
     ]))
     .unwrap();
 
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn pattern_overwrites_descendants() -> PanicOnError<()> {
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Example {
+        pub timestamp: i64,
+        pub events: Vec<Event>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Event {
+        pub timestamp: i64,
+        pub value: i64,
+    }
+
+    let options = TracingOptions::default().overwrite_pattern(
+        "$..timestamp",
+        json!({"name": "ts", "data_type": "Timestamp(Millisecond, None)"}),
+    )?;
+    let actual = SerdeArrowSchema::from_type::<Example>(options)?;
+
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "timestamp", "data_type": "Timestamp(Millisecond, None)"},
+        {
+            "name": "events",
+            "data_type": "LargeList",
+            "children": [{
+                "name": "element",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "timestamp", "data_type": "Timestamp(Millisecond, None)"},
+                    {"name": "value", "data_type": "I64"},
+                ],
+            }],
+        }
+    ]))?;
+
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn pattern_overwrites_wildcard() -> PanicOnError<()> {
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Example {
+        pub events: Vec<Event>,
+        pub c: i64,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Event {
+        pub a: i64,
+        pub b: i64,
+    }
+
+    let options = TracingOptions::default().overwrite_pattern(
+        "$.events.element.*",
+        json!({"name": "_", "data_type": "I32"}),
+    )?;
+    let actual = SerdeArrowSchema::from_type::<Example>(options)?;
+
+    let expected = SerdeArrowSchema::from_value(json!([
+        {
+            "name": "events",
+            "data_type": "LargeList",
+            "children": [{
+                "name": "element",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "a", "data_type": "I32"},
+                    {"name": "b", "data_type": "I32"},
+                ],
+            }],
+        },
+        {"name": "c", "data_type": "I64"},
+    ]))?;
+
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn pattern_overwrites_exact_overwrites_take_precedence() -> PanicOnError<()> {
+    let options = TracingOptions::default()
+        .overwrite_pattern("$.*", json!({"name": "_", "data_type": "I32"}))?
+        .overwrite("b", json!({"name": "b", "data_type": "U8"}))?;
+    let actual = SerdeArrowSchema::from_samples(json!([{"a": 1, "b": 2}]), options)?;
+
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "U8"},
+    ]))?;
+
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn pattern_overwrites_without_matches() -> PanicOnError<()> {
+    let options = TracingOptions::default()
+        .overwrite_pattern("$..missing", json!({"name": "_", "data_type": "I32"}))?;
+    let actual = SerdeArrowSchema::from_samples(json!([{"a": 1}]), options)?;
+    let expected = SerdeArrowSchema::from_value(json!([{"name": "a", "data_type": "U64"}]))?;
+
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn data_type_substitutions() -> PanicOnError<()> {
+    let options = TracingOptions::default()
+        .string_dictionary_encoding(true)
+        .substitute_data_type("LargeUtf8", "Utf8")?
+        .substitute_data_type("U64", "I32")?;
+    let actual =
+        SerdeArrowSchema::from_samples(json!([{"a": "foo", "b": [1, 2], "c": 3.0}]), options)?;
+
+    let expected = SerdeArrowSchema::from_value(json!([
+        {
+            "name": "a",
+            "data_type": "Dictionary",
+            "children": [
+                {"name": "key", "data_type": "U32"},
+                {"name": "value", "data_type": "Utf8"},
+            ],
+        },
+        {
+            "name": "b",
+            "data_type": "LargeList",
+            "children": [{"name": "element", "data_type": "I32"}],
+        },
+        {"name": "c", "data_type": "F64"},
+    ]))?;
+
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn substitutions_with_incompatible_strategies() -> PanicOnError<()> {
+    let options = TracingOptions::default()
+        .guess_dates(true)
+        .substitute_data_type("Date64", "I64")?;
+    let res = SerdeArrowSchema::from_samples(json!([{"a": {"b": "2024-01-01T00:00:00"}}]), options);
+    assert_error_contains(&res, "invalid strategy");
+    assert_error_contains(&res, "path: \"$.a.b\"");
+    Ok(())
+}
+
+#[test]
+fn invalid_patterns_and_substitutions() {
+    let res = TracingOptions::default()
+        .overwrite_pattern("$.a..", json!({"name": "a", "data_type": "I32"}));
+    assert!(res.is_err());
+
+    let res = TracingOptions::default().substitute_data_type("LargeUtf8", "NotAType");
+    assert!(res.is_err());
+}
@@ -0,0 +1,66 @@
+//! Generate Rust types from an Arrow schema
+//!
+//! Usage: `serde_arrow_codegen [--name NAME] PATH`
+//!
+//! `PATH` is either an Arrow IPC file (file or stream format) or a JSON file
+//! with a schema in the format accepted by `SchemaLike::from_value`. The
+//! generated source is written to stdout.
+use std::{fs::File, io::BufReader, path::Path};
+
+use arrow::{
+    datatypes::SchemaRef,
+    ipc::reader::{FileReader, StreamReader},
+};
+use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+
+type Result<T, E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
+
+const USAGE: &str = "Usage: serde_arrow_codegen [--name NAME] PATH";
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let mut name = String::from("Record");
+    let mut path = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--name" => name = args.next().ok_or(USAGE)?,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err(USAGE.into()),
+        }
+    }
+    let path = path.ok_or(USAGE)?;
+
+    let schema = read_schema(Path::new(&path))?;
+    print!("{}", schema.generate_rust_types(&name)?);
+    Ok(())
+}
+
+fn read_schema(path: &Path) -> Result<SerdeArrowSchema> {
+    if path.extension().is_some_and(|ext| ext == "json") {
+        let value: serde_json::Value = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+        return Ok(SerdeArrowSchema::from_value(value)?);
+    }
+
+    let schema = read_ipc_schema(path)?;
+    Ok(SerdeArrowSchema::try_from(schema.fields().as_ref())?)
+}
+
+fn read_ipc_schema(path: &Path) -> Result<SchemaRef> {
+    if let Ok(reader) = FileReader::try_new(BufReader::new(File::open(path)?), None) {
+        return Ok(reader.schema());
+    }
+    let reader = StreamReader::try_new(BufReader::new(File::open(path)?), None)?;
+    Ok(reader.schema())
+}
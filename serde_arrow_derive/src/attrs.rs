@@ -0,0 +1,300 @@
+//! Parsing of the `#[serde(...)]` and `#[arrow(...)]` attributes
+use syn::{meta::ParseNestedMeta, Attribute, Expr, Lit, LitStr, Result, Token, UnOp};
+
+use crate::case::RenameRule;
+
+#[derive(Default)]
+pub struct ContainerAttrs {
+    pub rename_all: Option<RenameRule>,
+    pub transparent: bool,
+}
+
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub rename: Option<String>,
+    pub skip: bool,
+    pub flatten: bool,
+    pub skip_serializing_if: bool,
+    pub data_type: Option<LitStr>,
+    pub strategy: Option<LitStr>,
+}
+
+#[derive(Default)]
+pub struct VariantAttrs {
+    pub rename: Option<String>,
+    pub rename_all: Option<RenameRule>,
+    pub skip: bool,
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut res = Self::default();
+        for attr in attrs {
+            if attr.path().is_ident("serde") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename_all") {
+                        if let Some(rule) = parse_serialize_name(&meta)? {
+                            res.rename_all = Some(RenameRule::from_lit(&rule)?);
+                        }
+                    } else if meta.path.is_ident("transparent") {
+                        res.transparent = true;
+                    } else if meta.path.is_ident("tag")
+                        || meta.path.is_ident("content")
+                        || meta.path.is_ident("untagged")
+                    {
+                        return Err(meta.error(
+                            "ArrowSchema only supports externally tagged enums and structs without tags",
+                        ));
+                    } else {
+                        skip_meta(&meta)?;
+                    }
+                    Ok(())
+                })?;
+            } else if attr.path().is_ident("arrow") {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "#[arrow(...)] is only supported on fields",
+                ));
+            }
+        }
+        Ok(res)
+    }
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut res = Self::default();
+        for attr in attrs {
+            if attr.path().is_ident("serde") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        if let Some(name) = parse_serialize_name(&meta)? {
+                            res.rename = Some(name.value());
+                        }
+                    } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                        res.skip = true;
+                    } else if meta.path.is_ident("flatten") {
+                        res.flatten = true;
+                    } else if meta.path.is_ident("skip_serializing_if") {
+                        res.skip_serializing_if = true;
+                        skip_meta(&meta)?;
+                    } else {
+                        skip_meta(&meta)?;
+                    }
+                    Ok(())
+                })?;
+            } else if attr.path().is_ident("arrow") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("data_type") {
+                        let data_type = meta.value()?.parse()?;
+                        validate_data_type(&data_type)?;
+                        res.data_type = Some(data_type);
+                    } else if meta.path.is_ident("strategy") {
+                        let strategy = meta.value()?.parse()?;
+                        validate_strategy(&strategy)?;
+                        res.strategy = Some(strategy);
+                    } else {
+                        return Err(meta
+                            .error("unknown arrow attribute, expected `data_type` or `strategy`"));
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+        Ok(res)
+    }
+}
+
+impl VariantAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut res = Self::default();
+        for attr in attrs {
+            if attr.path().is_ident("serde") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        if let Some(name) = parse_serialize_name(&meta)? {
+                            res.rename = Some(name.value());
+                        }
+                    } else if meta.path.is_ident("rename_all") {
+                        if let Some(rule) = parse_serialize_name(&meta)? {
+                            res.rename_all = Some(RenameRule::from_lit(&rule)?);
+                        }
+                    } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                        res.skip = true;
+                    } else {
+                        skip_meta(&meta)?;
+                    }
+                    Ok(())
+                })?;
+            } else if attr.path().is_ident("arrow") {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "#[arrow(...)] is only supported on fields",
+                ));
+            }
+        }
+        Ok(res)
+    }
+}
+
+/// Parse `key = "value"` or `key(serialize = "value", deserialize = "...")`
+///
+/// The serialized name is used, as it determines the layout of the arrays.
+fn parse_serialize_name(meta: &ParseNestedMeta) -> Result<Option<LitStr>> {
+    if meta.input.peek(Token![=]) {
+        return Ok(Some(meta.value()?.parse()?));
+    }
+
+    let mut res = None;
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("serialize") {
+            res = Some(meta.value()?.parse()?);
+        } else {
+            skip_meta(&meta)?;
+        }
+        Ok(())
+    })?;
+    Ok(res)
+}
+
+/// Skip attributes not relevant for the schema
+fn skip_meta(meta: &ParseNestedMeta) -> Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|meta| skip_meta(&meta))?;
+    }
+    Ok(())
+}
+
+const STRATEGIES: &[&str] = &[
+    "InconsistentTypes",
+    "UtcStrAsDate64",
+    "NaiveStrAsDate64",
+    "TupleAsStruct",
+    "MapAsStruct",
+    "StrAsNumber",
+    "UuidStrAsFixedSizeBinary",
+    "IpStrAsFixedSizeBinary",
+    "UnknownVariant",
+    "ValueAsJson",
+];
+
+fn validate_strategy(strategy: &LitStr) -> Result<()> {
+    if STRATEGIES.contains(&strategy.value().as_str()) {
+        Ok(())
+    } else {
+        Err(syn::Error::new_spanned(
+            strategy,
+            format!("unknown strategy {:?}", strategy.value()),
+        ))
+    }
+}
+
+/// Check that the data type given in `#[arrow(data_type = "...")]` can be
+/// parsed by `serde_arrow`
+///
+/// Mirrors the data type names accepted by `SchemaLike::from_value`. Nested
+/// data types are rejected, as their children cannot be given in the string.
+fn validate_data_type(data_type: &LitStr) -> Result<()> {
+    let error = |msg: String| syn::Error::new_spanned(data_type, msg);
+
+    let expr: Expr = syn::parse_str(&data_type.value())
+        .map_err(|_| error(format!("invalid data type {:?}", data_type.value())))?;
+    let (name, args) = match &expr {
+        Expr::Path(path) => (path_ident(&expr, &path.path)?, Vec::new()),
+        Expr::Call(call) => match call.func.as_ref() {
+            Expr::Path(path) => (
+                path_ident(&expr, &path.path)?,
+                call.args.iter().collect::<Vec<_>>(),
+            ),
+            _ => return Err(error(format!("invalid data type {:?}", data_type.value()))),
+        },
+        _ => return Err(error(format!("invalid data type {:?}", data_type.value()))),
+    };
+
+    let valid = match (name.as_str(), args.as_slice()) {
+        (
+            "Null" | "Bool" | "Boolean" | "Utf8" | "LargeUtf8" | "U8" | "UInt8" | "U16" | "UInt16"
+            | "U32" | "UInt32" | "U64" | "UInt64" | "I8" | "Int8" | "I16" | "Int16" | "I32"
+            | "Int32" | "I64" | "Int64" | "F16" | "Float16" | "F32" | "Float32" | "F64" | "Float64"
+            | "Date32" | "Date64" | "Binary" | "LargeBinary",
+            [],
+        ) => true,
+        ("FixedSizeBinary", [n]) => parse_int::<i32>(n).is_some(),
+        ("Timestamp", [unit, timezone]) => is_time_unit(unit) && is_optional_str(timezone),
+        ("Time32" | "Time64" | "Duration", [unit]) => is_time_unit(unit),
+        ("Decimal128", [precision, scale]) => {
+            parse_int::<u8>(precision).is_some() && parse_int::<i8>(scale).is_some()
+        }
+        ("Struct" | "List" | "LargeList" | "FixedSizeList" | "Dictionary" | "Map" | "Union", _) => {
+            return Err(error(format!(
+                "nested data type {name} is not supported in #[arrow(data_type = \"...\")], \
+                 its children follow from the field type"
+            )))
+        }
+        _ => false,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(error(format!("invalid data type {:?}", data_type.value())))
+    }
+}
+
+fn path_ident(expr: &Expr, path: &syn::Path) -> Result<String> {
+    match path.get_ident() {
+        Some(ident) => Ok(ident.to_string()),
+        None => Err(syn::Error::new_spanned(expr, "expected a data type name")),
+    }
+}
+
+fn expr_ident(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Path(path) => path.path.get_ident().map(|ident| ident.to_string()),
+        _ => None,
+    }
+}
+
+fn is_time_unit(expr: &Expr) -> bool {
+    matches!(
+        expr_ident(expr).as_deref(),
+        Some("Second" | "Millisecond" | "Microsecond" | "Nanosecond")
+    )
+}
+
+fn is_optional_str(expr: &Expr) -> bool {
+    match expr {
+        Expr::Call(call) if call.args.len() == 1 => {
+            expr_ident(&call.func).as_deref() == Some("Some")
+                && matches!(
+                    &call.args[0],
+                    Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(_),
+                        ..
+                    })
+                )
+        }
+        _ => expr_ident(expr).as_deref() == Some("None"),
+    }
+}
+
+fn parse_int<N: std::str::FromStr>(expr: &Expr) -> Option<N> {
+    match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_digits().parse().ok(),
+        Expr::Unary(syn::ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match expr.as_ref() {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Int(lit), ..
+            }) => format!("-{}", lit.base10_digits()).parse().ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
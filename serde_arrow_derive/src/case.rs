@@ -0,0 +1,47 @@
+//! The `rename_all` rules of serde
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{LitStr, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    pub fn from_lit(lit: &LitStr) -> Result<Self> {
+        match lit.value().as_str() {
+            "lowercase" => Ok(Self::Lower),
+            "UPPERCASE" => Ok(Self::Upper),
+            "PascalCase" => Ok(Self::Pascal),
+            "camelCase" => Ok(Self::Camel),
+            "snake_case" => Ok(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnake),
+            "kebab-case" => Ok(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Ok(Self::ScreamingKebab),
+            _ => Err(syn::Error::new_spanned(lit, "unknown rename rule")),
+        }
+    }
+
+    /// The corresponding `FieldNames` policy, which implements the conversion
+    pub fn to_field_names(self) -> TokenStream {
+        let variant = match self {
+            Self::Lower => quote!(LowerCase),
+            Self::Upper => quote!(UpperCase),
+            Self::Pascal => quote!(PascalCase),
+            Self::Camel => quote!(CamelCase),
+            Self::Snake => quote!(SnakeCase),
+            Self::ScreamingSnake => quote!(ScreamingSnakeCase),
+            Self::Kebab => quote!(KebabCase),
+            Self::ScreamingKebab => quote!(ScreamingKebabCase),
+        };
+        quote!(::serde_arrow::schema::FieldNames::#variant)
+    }
+}
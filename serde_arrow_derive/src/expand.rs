@@ -0,0 +1,227 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, parse_quote, Data, DeriveInput, Fields, Result, Type};
+
+use crate::{
+    attrs::{ContainerAttrs, FieldAttrs, VariantAttrs},
+    case::RenameRule,
+};
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let attrs = ContainerAttrs::parse(&input.attrs)?;
+
+    let (body, is_record) = match &input.data {
+        Data::Struct(data) if attrs.transparent => (expand_transparent(&data.fields)?, false),
+        Data::Struct(data) => {
+            let body = expand_fields(&data.fields, attrs.rename_all, quote!(name))?;
+            (body, matches!(data.fields, Fields::Named(_)))
+        }
+        Data::Enum(data) => {
+            let mut variants = Vec::new();
+            for variant in &data.variants {
+                let variant_attrs = VariantAttrs::parse(&variant.attrs)?;
+                if variant_attrs.skip {
+                    // keep the type ids in sync with the variant indices used by serde
+                    variants.push(quote!(__derive::unknown_variant()));
+                    continue;
+                }
+                let name = match variant_attrs.rename {
+                    Some(name) => quote!(#name),
+                    None => {
+                        let name = variant.ident.unraw().to_string();
+                        match attrs.rename_all {
+                            Some(rule) => {
+                                let rule = rule.to_field_names();
+                                quote!(&__derive::rename_variant(#rule, #name))
+                            }
+                            None => quote!(#name),
+                        }
+                    }
+                };
+                let body = expand_fields(&variant.fields, variant_attrs.rename_all, name)?;
+                variants.push(quote! {{
+                    let variant: ::serde_arrow::Result<__derive::Field> = { #body };
+                    variant?
+                }});
+            }
+            let body = quote! {
+                let mut variants = ::std::vec::Vec::new();
+                #( variants.push(#variants); )*
+                __derive::union_field(name, variants)
+            };
+            (body, false)
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "ArrowSchema cannot be derived for unions",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param
+            .bounds
+            .push(parse_quote!(::serde_arrow::_impl::derive::ArrowField));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let schema_impl = if is_record {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::serde_arrow::schema::ArrowSchema for #ident #ty_generics #where_clause {
+                fn arrow_schema() -> ::serde_arrow::Result<::serde_arrow::schema::SerdeArrowSchema> {
+                    use ::serde_arrow::_impl::derive as __derive;
+                    __derive::schema_from_field(<Self as __derive::ArrowField>::arrow_field("$")?)
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::serde_arrow::_impl::derive::ArrowField for #ident #ty_generics #where_clause {
+            fn arrow_field(name: &str) -> ::serde_arrow::Result<::serde_arrow::_impl::derive::Field> {
+                use ::serde_arrow::_impl::derive as __derive;
+                #body
+            }
+        }
+
+        #schema_impl
+    })
+}
+
+/// Build the field of a struct or of a non-newtype variant
+fn expand_fields(
+    fields: &Fields,
+    rename_all: Option<RenameRule>,
+    name: TokenStream,
+) -> Result<TokenStream> {
+    match fields {
+        Fields::Unit => Ok(quote!(::std::result::Result::Ok(__derive::null_field(#name)))),
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            let field = &fields.unnamed[0];
+            Ok(field_expr(
+                &field.ty,
+                name,
+                &FieldAttrs::parse(&field.attrs)?,
+            ))
+        }
+        Fields::Unnamed(fields) => {
+            let mut children = Vec::new();
+            for field in &fields.unnamed {
+                let attrs = FieldAttrs::parse(&field.attrs)?;
+                if attrs.skip {
+                    continue;
+                }
+                let child_name = children.len().to_string();
+                let child = field_expr(&field.ty, quote!(#child_name), &attrs);
+                children.push(quote!(#child?));
+            }
+            Ok(quote! {
+                let mut fields = ::std::vec::Vec::new();
+                #( fields.push(#children); )*
+                ::std::result::Result::Ok(__derive::tuple_field(#name, fields))
+            })
+        }
+        Fields::Named(fields) => {
+            let mut statements = Vec::new();
+            for field in &fields.named {
+                let attrs = FieldAttrs::parse(&field.attrs)?;
+                if attrs.skip {
+                    continue;
+                }
+                let ty = &field.ty;
+                if attrs.flatten {
+                    statements.push(quote! {
+                        fields.extend(__derive::flattened_fields(
+                            <#ty as __derive::ArrowField>::arrow_field("")?,
+                        )?);
+                    });
+                    continue;
+                }
+
+                let child_name = match &attrs.rename {
+                    Some(name) => quote!(#name),
+                    None => {
+                        let Some(ident) = &field.ident else {
+                            unreachable!("named fields have an ident");
+                        };
+                        let name = ident.unraw().to_string();
+                        match rename_all {
+                            Some(rule) => {
+                                let rule = rule.to_field_names();
+                                quote!(&__derive::rename_field(#rule, #name))
+                            }
+                            None => quote!(#name),
+                        }
+                    }
+                };
+                let child = field_expr(ty, child_name, &attrs);
+                statements.push(quote!(fields.push(#child?);));
+            }
+            Ok(quote! {
+                let mut fields = ::std::vec::Vec::new();
+                #( #statements )*
+                ::std::result::Result::Ok(__derive::struct_field(#name, fields))
+            })
+        }
+    }
+}
+
+fn expand_transparent(fields: &Fields) -> Result<TokenStream> {
+    for field in fields {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        if !attrs.skip {
+            return Ok(field_expr(&field.ty, quote!(name), &attrs));
+        }
+    }
+    Err(syn::Error::new(
+        proc_macro2::Span::call_site(),
+        "transparent structs require a non-skipped field",
+    ))
+}
+
+/// An expression evaluating to the result of building the field of a struct member
+fn field_expr(ty: &Type, name: TokenStream, attrs: &FieldAttrs) -> TokenStream {
+    let strategy = match &attrs.strategy {
+        Some(strategy) => quote!(::std::option::Option::Some(#strategy)),
+        None => quote!(::std::option::Option::None),
+    };
+    let field = match &attrs.data_type {
+        Some(data_type) => {
+            let nullable = is_option(ty);
+            quote!(__derive::custom_field(#name, #data_type, #nullable, #strategy))
+        }
+        None => match &attrs.strategy {
+            Some(strategy) => quote! {
+                __derive::with_strategy(<#ty as __derive::ArrowField>::arrow_field(#name)?, #strategy)
+            },
+            None => quote!(<#ty as __derive::ArrowField>::arrow_field(#name)),
+        },
+    };
+
+    if attrs.skip_serializing_if {
+        quote!(#field.map(__derive::nullable))
+    } else {
+        field
+    }
+}
+
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty) => ty
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "Option")
+            .unwrap_or_default(),
+        Type::Group(ty) => is_option(&ty.elem),
+        Type::Paren(ty) => is_option(&ty.elem),
+        _ => false,
+    }
+}
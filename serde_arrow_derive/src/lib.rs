@@ -0,0 +1,24 @@
+//! Derive macros for [`serde_arrow`](https://docs.rs/serde_arrow)
+//!
+//! This crate is re-exported by `serde_arrow` with the `derive` feature. See
+//! the docs of `serde_arrow::schema::ArrowSchema` for details.
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod attrs;
+mod case;
+mod expand;
+
+/// Derive the Arrow schema of a type at compile time
+///
+/// Honors the serde attributes that affect the serialized layout (`rename`,
+/// `rename_all`, `skip`, `flatten`, `transparent`, ...) and accepts
+/// `#[arrow(data_type = "...", strategy = "...")]` on fields to overwrite the
+/// data type or to attach a strategy.
+#[proc_macro_derive(ArrowSchema, attributes(arrow))]
+pub fn derive_arrow_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
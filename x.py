@@ -28,6 +28,7 @@ all_arrow_features = [
 ]
 all_arrow2_features = ["arrow2-0-17", "arrow2-0-16"]
 default_features = f"{all_arrow2_features[0]},{all_arrow_features[0]}"
+docs_features = f"{default_features},derive"
 
 CHECKS_PLACEHOLDER = "<<< checks >>>"
 
@@ -161,6 +162,14 @@ def _generate_workflow_check_steps():
         "name": "Test",
         "run": f"cargo test --features {default_features}",
     }
+    yield {
+        "name": "Clippy derive",
+        "run": f"cargo clippy --all-targets --features {docs_features}",
+    }
+    yield {
+        "name": "Test derive",
+        "run": f"cargo test --features {docs_features}",
+    }
 
 
 @cmd(help="Format the code")
@@ -174,7 +183,7 @@ def format():
 def check(all=False):
     check_cargo_toml()
     _sh(f"cargo check --features {default_features}")
-    _sh(f"cargo clippy --features {default_features}")
+    _sh(f"cargo clippy --features {docs_features}")
 
     if all:
         for arrow2_feature in (*all_arrow2_features, *all_arrow_features):
@@ -265,22 +274,31 @@ def check_cargo_toml():
     with open(self_path / "serde_arrow" / "Cargo.toml", "rb") as fobj:
         config = tomli.load(fobj)
 
-    for label, features in [
+    for label, features, expected in [
         (
             "docs.rs configuration",
             config["package"]["metadata"]["docs"]["rs"]["features"],
+            docs_features,
         ),
         *[
-            (f"test {target['name']}", target["required-features"])
+            (
+                f"test {target['name']}",
+                target["required-features"],
+                default_features,
+            )
             for target in config.get("test", [])
         ],
         *[
-            (f"bench {target['name']}", target["required-features"])
+            (
+                f"bench {target['name']}",
+                target["required-features"],
+                default_features,
+            )
             for target in config.get("bench", [])
         ],
     ]:
         actual_features = sorted(features)
-        expected_features = sorted(default_features.split(","))
+        expected_features = sorted(expected.split(","))
 
         if actual_features != expected_features:
             raise ValueError(